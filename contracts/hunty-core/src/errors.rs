@@ -20,6 +20,38 @@ pub enum HuntErrorCode {
     InvalidAddress = 13,
     TooManyClues = 14,
     InvalidQuestion = 15,
+    NotInitialized = 16,
+    AlreadyInitialized = 17,
+    ContractPaused = 18,
+    ContractNotPaused = 19,
+    InvalidAmount = 20,
+    RewardTokenMismatch = 21,
+    InvalidCompletionPolicy = 22,
+    CommitmentNotFound = 23,
+    RevealTooEarly = 24,
+    HuntNotCompleted = 25,
+    RewardAlreadyClaimed = 26,
+    InvalidClueOrder = 27,
+    DuplicateTitle = 28,
+    HuntFull = 29,
+    Disqualified = 30,
+    InvalidEndTime = 31,
+    HuntNotStarted = 32,
+    NoRequiredClues = 33,
+    CreatorCannotPlay = 34,
+    TooManyAnswers = 35,
+    TemplateNotFound = 36,
+    TooManyAttempts = 37,
+    HuntIdSpaceExhausted = 38,
+    RewardsFrozen = 39,
+    PrerequisiteNotMet = 40,
+    PrerequisiteCycle = 41,
+    TieUnresolved = 42,
+    RegistrationClosed = 43,
+    RewardTooSmall = 44,
+    PointsExceedCap = 45,
+    TooFewPlayers = 46,
+    NothingVested = 47,
 }
 
 #[derive(Debug)]
@@ -39,6 +71,38 @@ pub enum HuntError {
     InvalidAddress,
     TooManyClues { hunt_id: u64, limit: u32 },
     InvalidQuestion,
+    NotInitialized,
+    AlreadyInitialized,
+    ContractPaused,
+    ContractNotPaused,
+    InvalidAmount { amount: i128 },
+    RewardTokenMismatch { hunt_id: u64 },
+    InvalidCompletionPolicy,
+    CommitmentNotFound { hunt_id: u64 },
+    RevealTooEarly { hunt_id: u64 },
+    HuntNotCompleted { hunt_id: u64 },
+    RewardAlreadyClaimed { hunt_id: u64 },
+    InvalidClueOrder { hunt_id: u64 },
+    DuplicateTitle { hunt_id: u64 },
+    HuntFull { hunt_id: u64 },
+    Disqualified { hunt_id: u64 },
+    InvalidEndTime { hunt_id: u64 },
+    HuntNotStarted { hunt_id: u64 },
+    NoRequiredClues { hunt_id: u64 },
+    CreatorCannotPlay { hunt_id: u64 },
+    TooManyAnswers { hunt_id: u64, limit: u32 },
+    TemplateNotFound,
+    TooManyAttempts { hunt_id: u64, clue_id: u32, retry_at: u64 },
+    HuntIdSpaceExhausted,
+    RewardsFrozen { hunt_id: u64 },
+    PrerequisiteNotMet { hunt_id: u64, prerequisite: u64 },
+    PrerequisiteCycle { hunt_id: u64 },
+    TieUnresolved { hunt_id: u64 },
+    RegistrationClosed { hunt_id: u64 },
+    RewardTooSmall { hunt_id: u64 },
+    PointsExceedCap { hunt_id: u64, cap: u32 },
+    TooFewPlayers { hunt_id: u64, required: u32 },
+    NothingVested { hunt_id: u64 },
 }
 
 impl fmt::Display for HuntError {
@@ -89,6 +153,110 @@ impl fmt::Display for HuntError {
             HuntError::InvalidQuestion => {
                 write!(f, "Invalid question (empty or exceeds max length)")
             }
+            HuntError::NotInitialized => {
+                write!(f, "Contract not initialized")
+            }
+            HuntError::AlreadyInitialized => {
+                write!(f, "Contract already initialized")
+            }
+            HuntError::ContractPaused => {
+                write!(f, "Contract is paused")
+            }
+            HuntError::ContractNotPaused => {
+                write!(f, "Contract is not paused")
+            }
+            HuntError::InvalidAmount { amount } => {
+                write!(f, "Invalid amount: {}", amount)
+            }
+            HuntError::RewardTokenMismatch { hunt_id } => {
+                write!(f, "Reward token mismatch for hunt {}", hunt_id)
+            }
+            HuntError::InvalidCompletionPolicy => {
+                write!(f, "Invalid completion policy")
+            }
+            HuntError::CommitmentNotFound { hunt_id } => {
+                write!(f, "No answer commitment found for hunt {}", hunt_id)
+            }
+            HuntError::RevealTooEarly { hunt_id } => {
+                write!(f, "Answer reveal too early for hunt {}", hunt_id)
+            }
+            HuntError::HuntNotCompleted { hunt_id } => {
+                write!(f, "Player has not completed hunt {}", hunt_id)
+            }
+            HuntError::RewardAlreadyClaimed { hunt_id } => {
+                write!(f, "Reward already claimed for hunt {}", hunt_id)
+            }
+            HuntError::InvalidClueOrder { hunt_id } => {
+                write!(f, "Invalid clue order for hunt {}", hunt_id)
+            }
+            HuntError::DuplicateTitle { hunt_id } => {
+                write!(f, "Creator already has a hunt with this title (hunt {})", hunt_id)
+            }
+            HuntError::HuntFull { hunt_id } => {
+                write!(f, "Hunt {} is full", hunt_id)
+            }
+            HuntError::Disqualified { hunt_id } => {
+                write!(f, "Player is disqualified from hunt {}", hunt_id)
+            }
+            HuntError::InvalidEndTime { hunt_id } => {
+                write!(f, "End time for hunt {} must be 0 or in the future", hunt_id)
+            }
+            HuntError::HuntNotStarted { hunt_id } => {
+                write!(f, "Hunt {} has not started yet", hunt_id)
+            }
+            HuntError::NoRequiredClues { hunt_id } => {
+                write!(f, "Hunt {} has no required clues and cannot be completed", hunt_id)
+            }
+            HuntError::CreatorCannotPlay { hunt_id } => {
+                write!(f, "Creators and collaborators cannot play hunt {}", hunt_id)
+            }
+            HuntError::TooManyAnswers { hunt_id, limit } => {
+                write!(
+                    f,
+                    "Clue in hunt {} already has the maximum of {} accepted answers",
+                    hunt_id, limit
+                )
+            }
+            HuntError::TemplateNotFound => {
+                write!(f, "Clue template not found")
+            }
+            HuntError::TooManyAttempts { clue_id, hunt_id, retry_at } => {
+                write!(
+                    f,
+                    "Too many wrong answers for clue {} in hunt {}; retry at {}",
+                    clue_id, hunt_id, retry_at
+                )
+            }
+            HuntError::HuntIdSpaceExhausted => {
+                write!(f, "No hunt IDs left to assign")
+            }
+            HuntError::RewardsFrozen { hunt_id } => {
+                write!(f, "Reward claims for hunt {} are frozen", hunt_id)
+            }
+            HuntError::PrerequisiteNotMet { hunt_id, prerequisite } => {
+                write!(f, "Hunt {} requires completing hunt {} first", hunt_id, prerequisite)
+            }
+            HuntError::PrerequisiteCycle { hunt_id } => {
+                write!(f, "Prerequisite would make hunt {} depend on itself", hunt_id)
+            }
+            HuntError::TieUnresolved { hunt_id } => {
+                write!(f, "Hunt {} has an unresolved tie for the last winner slot", hunt_id)
+            }
+            HuntError::RegistrationClosed { hunt_id } => {
+                write!(f, "Registration for hunt {} is paused", hunt_id)
+            }
+            HuntError::RewardTooSmall { hunt_id } => {
+                write!(f, "Reward pool of hunt {} is too small to pay every winner", hunt_id)
+            }
+            HuntError::PointsExceedCap { cap, hunt_id } => {
+                write!(f, "Clue points exceed the cap of {} for hunt {}", cap, hunt_id)
+            }
+            HuntError::TooFewPlayers { hunt_id, required } => {
+                write!(f, "Hunt {} needs at least {} players to pay rewards", hunt_id, required)
+            }
+            HuntError::NothingVested { hunt_id } => {
+                write!(f, "No new reward has vested yet for hunt {}", hunt_id)
+            }
         }
     }
 }
@@ -111,6 +279,38 @@ impl From<HuntError> for HuntErrorCode {
             HuntError::InvalidAddress => HuntErrorCode::InvalidAddress,
             HuntError::TooManyClues { .. } => HuntErrorCode::TooManyClues,
             HuntError::InvalidQuestion => HuntErrorCode::InvalidQuestion,
+            HuntError::NotInitialized => HuntErrorCode::NotInitialized,
+            HuntError::AlreadyInitialized => HuntErrorCode::AlreadyInitialized,
+            HuntError::ContractPaused => HuntErrorCode::ContractPaused,
+            HuntError::ContractNotPaused => HuntErrorCode::ContractNotPaused,
+            HuntError::InvalidAmount { .. } => HuntErrorCode::InvalidAmount,
+            HuntError::RewardTokenMismatch { .. } => HuntErrorCode::RewardTokenMismatch,
+            HuntError::InvalidCompletionPolicy => HuntErrorCode::InvalidCompletionPolicy,
+            HuntError::CommitmentNotFound { .. } => HuntErrorCode::CommitmentNotFound,
+            HuntError::RevealTooEarly { .. } => HuntErrorCode::RevealTooEarly,
+            HuntError::HuntNotCompleted { .. } => HuntErrorCode::HuntNotCompleted,
+            HuntError::RewardAlreadyClaimed { .. } => HuntErrorCode::RewardAlreadyClaimed,
+            HuntError::InvalidClueOrder { .. } => HuntErrorCode::InvalidClueOrder,
+            HuntError::DuplicateTitle { .. } => HuntErrorCode::DuplicateTitle,
+            HuntError::HuntFull { .. } => HuntErrorCode::HuntFull,
+            HuntError::Disqualified { .. } => HuntErrorCode::Disqualified,
+            HuntError::InvalidEndTime { .. } => HuntErrorCode::InvalidEndTime,
+            HuntError::HuntNotStarted { .. } => HuntErrorCode::HuntNotStarted,
+            HuntError::NoRequiredClues { .. } => HuntErrorCode::NoRequiredClues,
+            HuntError::CreatorCannotPlay { .. } => HuntErrorCode::CreatorCannotPlay,
+            HuntError::TooManyAnswers { .. } => HuntErrorCode::TooManyAnswers,
+            HuntError::TemplateNotFound => HuntErrorCode::TemplateNotFound,
+            HuntError::TooManyAttempts { .. } => HuntErrorCode::TooManyAttempts,
+            HuntError::HuntIdSpaceExhausted => HuntErrorCode::HuntIdSpaceExhausted,
+            HuntError::RewardsFrozen { .. } => HuntErrorCode::RewardsFrozen,
+            HuntError::PrerequisiteNotMet { .. } => HuntErrorCode::PrerequisiteNotMet,
+            HuntError::PrerequisiteCycle { .. } => HuntErrorCode::PrerequisiteCycle,
+            HuntError::TieUnresolved { .. } => HuntErrorCode::TieUnresolved,
+            HuntError::RegistrationClosed { .. } => HuntErrorCode::RegistrationClosed,
+            HuntError::RewardTooSmall { .. } => HuntErrorCode::RewardTooSmall,
+            HuntError::PointsExceedCap { .. } => HuntErrorCode::PointsExceedCap,
+            HuntError::TooFewPlayers { .. } => HuntErrorCode::TooFewPlayers,
+            HuntError::NothingVested { .. } => HuntErrorCode::NothingVested,
         }
     }
 }
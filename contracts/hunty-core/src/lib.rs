@@ -1,14 +1,56 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String, Symbol, Vec};
+use soroban_sdk::{
+    contract, contractimpl, events::Topics, token, xdr::ToXdr, Address, Bytes, BytesN, Env,
+    IntoVal, String, Symbol, TryFromVal, Val, Vec,
+};
 use crate::errors::{HuntError, HuntErrorCode};
+use crate::nft::NftRewardClient;
 use crate::storage::Storage;
 use crate::types::{
-    Clue, ClueAddedEvent, ClueInfo, Hunt, HuntCreatedEvent, HuntStatus, RewardConfig,
+    ActivationAbortedEvent, AnswerCommitment, AnswerRejectedEvent, AttemptState, Clue,
+    ClueAddedEvent, ClueCompletedEvent, ClueInfo, ClueInput, ClueWithStatus, CompletionPolicy,
+    CountersRecomputedEvent, CreatorStats, DescriptionRef, Durability, HashAlgo,
+    EmergencyWithdrawEvent, EventLevel, GlobalStats, Hunt, HuntCompletedEvent, HuntCancelledEvent,
+    HuntClosedEvent, HuntCreatedEvent, HuntStatus, HuntStatusChangedEvent, HuntConfig, HuntSummary,
+    IndexesRebuiltEvent, LeaderboardEntry, Location, PlayerDisqualifiedEvent, PlayerProgress,
+    PlayerRegisteredEvent, PlayerUnregisteredEvent, ProgressResetEvent, RegistrationMilestoneEvent,
+    RegistrationPausedEvent, RewardClaimedEvent, RewardConfig, RewardMode, RewardPoolFundedEvent,
+    RewardPoolWithdrawnEvent, RewardTokenSwappedEvent, RewardsExhaustedEvent, RewardsFrozenEvent,
+    TemplateClue, TiePolicy,
 };
 
 const MAX_QUESTION_LENGTH: u32 = 2000;
 const MAX_ANSWER_LENGTH: u32 = 256;
-const MAX_CLUES_PER_HUNT: u32 = 100;
+const MAX_CANCEL_REASON_LENGTH: u32 = 500;
+/// Longest summary accepted with an off-chain description reference.
+const MAX_SUMMARY_LENGTH: u32 = 280;
+/// Clue cap per hunt until the admin sets one with `set_max_clues_per_hunt`.
+const DEFAULT_MAX_CLUES_PER_HUNT: u32 = 100;
+/// Alternative answers a clue can accept on top of its main answer.
+const MAX_ALT_ANSWERS: u32 = 5;
+const MAX_TEMPLATE_NAME_LENGTH: u32 = 64;
+/// Most addresses `get_progress_batch` looks up in one call.
+const MAX_PROGRESS_BATCH: u32 = 50;
+/// Default age after which finished hunts are archive candidates (90 days).
+const DEFAULT_ARCHIVE_AFTER_SECS: u64 = 90 * 24 * 60 * 60;
+/// Most registration milestones a hunt can have.
+const MAX_MILESTONES: u32 = 10;
+/// Most hunts `claim_rewards_multi` claims from in one call.
+const MAX_CLAIM_BATCH: u32 = 20;
+/// Most hunts `get_hunt_summaries` looks up in one call.
+const MAX_SUMMARY_BATCH: u32 = 50;
+/// Most Active hunts `sweep_expired_hunts` inspects in one call.
+const MAX_SWEEP_BATCH: u32 = 20;
+/// Most hashes `verify_clue_hashes` checks in one call.
+const MAX_VERIFY_BATCH: u32 = 100;
+/// Most entries a hunt's cached leaderboard can hold.
+const MAX_LEADERBOARD_CACHE: u32 = 50;
+/// Ledgers that must close between `commit_answer` and `reveal_answer`.
+const MIN_REVEAL_DELAY_LEDGERS: u32 = 1;
+/// Rough serialized sizes used by `estimate_hunt_storage`.
+const EST_HUNT_BASE_BYTES: u64 = 512;
+const EST_CLUE_BYTES: u64 = 320;
+const EST_PLAYER_BYTES: u64 = 240;
 
 #[contract]
 pub struct HuntyCore;
@@ -19,7 +61,7 @@ impl HuntyCore {
     /// 
     /// # Arguments
     /// * `env` - The Soroban environment
-    /// * `creator` - The address of the hunt creator (typically use env.invoker() from the caller)
+    /// * `creator` - The address of the hunt creator; must authorize the call
     /// * `title` - The title of the hunt (max 200 characters)
     /// * `description` - The description of the hunt (max 2000 characters)
     /// * `start_time` - Optional start timestamp (0 means no start time restriction)
@@ -31,87 +73,137 @@ impl HuntyCore {
     /// # Errors
     /// * `InvalidTitle` - If title is empty or exceeds maximum length
     /// * `InvalidDescription` - If description exceeds maximum length
-    /// * `InvalidAddress` - If creator address is invalid
+    /// * `InvalidEndTime` - If `end_time` is set and not after `start_time`
+    /// * `DuplicateTitle` - If the creator already has a hunt with this exact title
+    /// * `HuntIdSpaceExhausted` - If every hunt ID has been used
+    /// * `ContractPaused` - If the contract is globally paused
     pub fn create_hunt(
         env: Env,
         creator: Address,
         title: String,
         description: String,
-        _start_time: Option<u64>,
+        start_time: Option<u64>,
         end_time: Option<u64>,
     ) -> Result<u64, HuntErrorCode> {
-        // Validate creator address - in Soroban, Address is always valid if constructed,
-        // but we ensure it's not a zero/null address pattern if needed
-        // For now, we accept any valid Address type
+        Self::with_diagnostics(&env, 0, || {
+            Self::require_not_paused(&env)?;
+            creator.require_auth();
         
-        // Validate title
-        let title_len = title.len();
-        if title_len == 0 {
-            return Err(HuntErrorCode::InvalidTitle);
-        }
-        const MAX_TITLE_LENGTH: u32 = 200;
-        if title_len > MAX_TITLE_LENGTH {
-            return Err(HuntErrorCode::InvalidTitle);
-        }
+            // Validate title
+            let title_len = title.len();
+            if title_len == 0 {
+                return Err(HuntErrorCode::InvalidTitle);
+            }
+            const MAX_TITLE_LENGTH: u32 = 200;
+            if title_len > MAX_TITLE_LENGTH {
+                return Err(HuntErrorCode::InvalidTitle);
+            }
         
-        // Validate description
-        const MAX_DESCRIPTION_LENGTH: u32 = 2000;
-        if description.len() > MAX_DESCRIPTION_LENGTH {
-            return Err(HuntErrorCode::InvalidDescription);
-        }
+            // Validate description
+            const MAX_DESCRIPTION_LENGTH: u32 = 2000;
+            if description.len() > MAX_DESCRIPTION_LENGTH {
+                return Err(HuntErrorCode::InvalidDescription);
+            }
+
+            // A hunt must end after it starts
+            let start_time = start_time.unwrap_or(0);
+            let end_time = end_time.unwrap_or(0);
+            if end_time != 0 && end_time <= start_time {
+                return Err(HuntErrorCode::InvalidEndTime);
+            }
+
+            // Titles are unique per creator
+            if let Some(existing) = Storage::get_hunt_id_for_title(&env, &creator, &title) {
+                return Err(HuntErrorCode::from(HuntError::DuplicateTitle { hunt_id: existing }));
+            }
         
-        // Get current timestamp
-        let current_time = env.ledger().timestamp();
+            // Get current timestamp
+            let current_time = env.ledger().timestamp();
         
-        // Generate unique hunt ID
-        let hunt_id = Storage::next_hunt_id(&env);
+            // Generate unique hunt ID
+            let hunt_id = Storage::next_hunt_id(&env).map_err(HuntErrorCode::from)?;
         
-        // Initialize reward config with zero pool
-        let reward_config = RewardConfig::new(
-            0,              // xlm_pool: zero initially
-            false,          // nft_enabled: false initially
-            None,           // nft_contract: None initially
-            0,              // max_winners: 0 initially
-        );
+            // Initialize reward config with zero pool
+            let reward_config = RewardConfig::new(
+                0,              // xlm_pool: zero initially
+                false,          // nft_enabled: false initially
+                None,           // nft_contract: None initially
+                0,              // max_winners: 0 initially
+            );
         
-        // Create the hunt with Draft status
-        let hunt = Hunt {
-            hunt_id,
-            creator: creator.clone(),
-            title: title.clone(),
-            description: description.clone(),
-            status: HuntStatus::Draft,
-            created_at: current_time,
-            activated_at: 0,  // Will be set when hunt is activated
-            end_time: end_time.unwrap_or(0),
-            reward_config,
-            total_clues: 0,  // Empty clue list initially
-            required_clues: 0,
-        };
+            // Create the hunt with Draft status
+            let hunt = Hunt {
+                hunt_id,
+                creator: creator.clone(),
+                title: title.clone(),
+                description: description.clone(),
+                status: HuntStatus::Draft,
+                created_at: current_time,
+                activated_at: 0,  // Will be set when hunt is activated
+                start_time,
+                end_time,
+                grace_period_secs: 0,
+                reward_config,
+                total_clues: 0,  // Empty clue list initially
+                required_clues: 0,
+                completion_policy: CompletionPolicy::RequiredCount,
+                ordered: false,
+                entry_fee: 0,
+                max_players: 0,
+                progress_durability: Durability::Persistent,
+                reward_mode: RewardMode::FirstCome,
+                manual_judging: false,
+                cancel_reason: String::from_str(&env, ""),
+                allow_creator_play: false,
+                shuffle_clues: false,
+                closed_at: 0,
+                answer_algo: HashAlgo::Sha256,
+                max_attempts: 0,
+                attempt_cooldown_secs: 0,
+                min_answer_length: 0,
+                prerequisite_hunt: None,
+                tie_policy: TiePolicy::FirstCome,
+                max_points_per_clue: 0,
+                leaderboard_cache_size: 0,
+                min_players_for_rewards: 0,
+            };
         
-        // Store the hunt
-        Storage::save_hunt(&env, &hunt);
+            // Store the hunt
+            Storage::save_hunt(&env, &hunt);
+            Storage::set_hunt_id_for_title(&env, &creator, &title, hunt_id);
+            Storage::add_hunt_to_creator_index(&env, &creator, hunt_id);
+            Storage::move_hunt_status(&env, hunt_id, None, HuntStatus::Draft);
         
-        // Emit HuntCreated event
-        let event = HuntCreatedEvent {
-            hunt_id,
-            creator: creator.clone(),
-            title: title.clone(),
-        };
-        env.events().publish(
-            (Symbol::new(&env, "HuntCreated"), hunt_id),
-            event,
-        );
+            // Emit HuntCreated event
+            Self::publish_event(
+                &env,
+                EventLevel::Minimal,
+                (Symbol::new(&env, "HuntCreated"), hunt_id),
+                || HuntCreatedEvent {
+                    hunt_id,
+                    creator: creator.clone(),
+                    title: title.clone(),
+                    event_seq: Storage::next_event_seq(&env, hunt_id),
+                },
+            );
         
-        Ok(hunt_id)
+            Ok(hunt_id)
+        })
+    }
+
+    /// Looks up a hunt by its creator and exact title. Titles are unique per creator
+    /// (different creators may reuse a title) and stay reserved after cancellation.
+    pub fn get_hunt_id_by_title(env: Env, creator: Address, title: String) -> Option<u64> {
+        Storage::get_hunt_id_for_title(&env, &creator, &title)
     }
 
-    /// Adds a clue to a hunt. Only the hunt creator can add clues.
+    /// Adds a clue to a hunt. The hunt creator or a collaborator can add clues.
     /// Answers are hashed with SHA256 before storage; the hash is never exposed.
     ///
     /// # Arguments
     /// * `env` - The Soroban environment
     /// * `hunt_id` - The hunt to add the clue to
+    /// * `editor` - The creator or a collaborator; must authorize the call
     /// * `question` - The clue question text (max 2000 chars, non-empty)
     /// * `answer` - Plain-text answer; normalized (trimmed, lowercased) then hashed
     /// * `points` - Points awarded for solving this clue
@@ -123,132 +215,4215 @@ impl HuntyCore {
     /// # Errors
     /// * `HuntNotFound` - Hunt does not exist
     /// * `InvalidHuntStatus` - Hunt is not in Draft
-    /// * `Unauthorized` - Caller is not the hunt creator
+    /// * `Unauthorized` - Editor is neither the creator nor a collaborator
     /// * `TooManyClues` - Hunt already has max clues
     /// * `InvalidQuestion` - Question empty or too long
     /// * `InvalidAnswer` - Answer empty or too long
+    /// * `PointsExceedCap` - Points above the hunt's `max_points_per_clue`
+    /// * `ContractPaused` - Contract is globally paused
     pub fn add_clue(
         env: Env,
         hunt_id: u64,
+        editor: Address,
         question: String,
         answer: String,
         points: u32,
         is_required: bool,
     ) -> Result<u32, HuntErrorCode> {
-        let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
-        if hunt.status != HuntStatus::Draft {
-            return Err(HuntErrorCode::InvalidHuntStatus);
-        }
-        hunt.creator.require_auth();
-        if Storage::get_clue_counter(&env, hunt_id) >= MAX_CLUES_PER_HUNT {
-            return Err(HuntErrorCode::from(HuntError::TooManyClues {
-                hunt_id,
-                limit: MAX_CLUES_PER_HUNT,
-            }));
-        }
-        let qlen = question.len();
-        if qlen == 0 || qlen > MAX_QUESTION_LENGTH {
-            return Err(HuntErrorCode::InvalidQuestion);
-        }
-        let answer_hash = Self::normalize_and_hash_answer(&env, &answer)
-            .map_err(HuntErrorCode::from)?;
-        let clue_id = Storage::next_clue_id(&env, hunt_id);
-        let clue = Clue {
-            clue_id,
-            question: question.clone(),
-            answer_hash,
-            points,
-            is_required,
-        };
-        Storage::save_clue(&env, hunt_id, &clue);
-        let mut updated = hunt;
-        updated.total_clues += 1;
-        Storage::save_hunt(&env, &updated);
-        let event = ClueAddedEvent {
-            hunt_id,
-            clue_id,
-            creator: updated.creator.clone(),
-            question,
-            points,
-            is_required,
-        };
-        env.events().publish(
-            (Symbol::new(&env, "ClueAdded"), hunt_id, clue_id),
-            event,
-        );
-        Ok(clue_id)
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_not_paused(&env)?;
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            Self::require_editor(&env, &hunt, &editor)?;
+            Self::require_clue_room(&env, hunt_id, Storage::get_clue_counter(&env, hunt_id), 1)?;
+            let answer_hash = Self::validate_clue(&env, &hunt.answer_algo, &question, &answer)?;
+            Self::check_answer_length(&env, &hunt, &answer)?;
+            if hunt.max_points_per_clue > 0 && points > hunt.max_points_per_clue {
+                return Err(HuntErrorCode::from(HuntError::PointsExceedCap {
+                    hunt_id,
+                    cap: hunt.max_points_per_clue,
+                }));
+            }
+            let answer_hash = Self::salt_answer_hash(&env, hunt_id, answer_hash);
+            let mut updated = hunt;
+            let clue_id =
+                Self::store_clue(&env, &mut updated, question, answer_hash, points, is_required);
+            Storage::save_hunt(&env, &updated);
+            Ok(clue_id)
+        })
     }
 
-    /// Returns clue information for a hunt/clue. Does not expose the answer hash.
-    pub fn get_clue(env: Env, hunt_id: u64, clue_id: u32) -> Result<ClueInfo, HuntErrorCode> {
-        let clue = Storage::get_clue_or_error(&env, hunt_id, clue_id)
-            .map_err(HuntErrorCode::from)?;
-        Ok(ClueInfo {
-            clue_id: clue.clue_id,
-            question: clue.question,
-            points: clue.points,
-            is_required: clue.is_required,
+    /// Creates a hunt and adds all of `clues` in one call, running the same validations
+    /// as `create_hunt` and `add_clue`. Every clue is validated before anything is
+    /// written, so an invalid clue rejects the whole creation. Creator auth is required
+    /// (checked by `create_hunt`).
+    ///
+    /// # Returns
+    /// The new hunt ID
+    ///
+    /// # Errors
+    /// * Any error of `create_hunt`
+    /// * `TooManyClues` - More than the per-hunt clue limit
+    /// * `InvalidQuestion` / `InvalidAnswer` - A clue's question or answer is invalid
+    pub fn create_hunt_with_clues(
+        env: Env,
+        creator: Address,
+        title: String,
+        description: String,
+        end_time: Option<u64>,
+        clues: Vec<ClueInput>,
+    ) -> Result<u64, HuntErrorCode> {
+        Self::with_diagnostics(&env, 0, || {
+            Self::require_clue_room(&env, 0, 0, clues.len())?;
+            let mut hashes = Vec::new(&env);
+            for clue in clues.iter() {
+                let algo = HashAlgo::Sha256;
+                hashes.push_back(Self::validate_clue(&env, &algo, &clue.question, &clue.answer)?);
+            }
+
+            let hunt_id =
+                Self::create_hunt(env.clone(), creator, title, description, None, end_time)?;
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            for (clue, answer_hash) in clues.iter().zip(hashes.iter()) {
+                let ClueInput { question, points, is_required, .. } = clue;
+                Self::store_clue(&env, &mut hunt, question, answer_hash, points, is_required);
+            }
+            Storage::save_hunt(&env, &hunt);
+            Ok(hunt_id)
         })
     }
 
-    /// Returns all clues for a hunt (question, points, required). Answer hashes are not exposed.
-    pub fn list_clues(env: Env, hunt_id: u64) -> Vec<ClueInfo> {
-        let raw = Storage::list_clues_for_hunt(&env, hunt_id);
-        let mut out = Vec::new(&env);
-        for i in 0..raw.len() {
-            let c = raw.get(i).unwrap();
-            out.push_back(ClueInfo {
-                clue_id: c.clue_id,
-                question: c.question,
-                points: c.points,
-                is_required: c.is_required,
-            });
-        }
-        out
+    /// Saves `clues` as a reusable template named `name` owned by `owner`, replacing any
+    /// template of the same name. Clues are validated as in `add_clue` and only their
+    /// answer hashes are stored. Templates are not tied to any hunt.
+    ///
+    /// # Errors
+    /// * `InvalidTitle` - Name is empty or longer than 64 characters
+    /// * `TooManyClues` - More than the per-hunt clue limit
+    /// * `InvalidQuestion` / `InvalidAnswer` - A clue's question or answer is invalid
+    pub fn save_template(
+        env: Env,
+        owner: Address,
+        name: String,
+        clues: Vec<ClueInput>,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, 0, || {
+            owner.require_auth();
+            if name.is_empty() || name.len() > MAX_TEMPLATE_NAME_LENGTH {
+                return Err(HuntErrorCode::InvalidTitle);
+            }
+            Self::require_clue_room(&env, 0, 0, clues.len())?;
+            let mut template = Vec::new(&env);
+            for clue in clues.iter() {
+                template.push_back(TemplateClue {
+                    answer_hash: Self::validate_clue(
+                        &env,
+                        &HashAlgo::Sha256,
+                        &clue.question,
+                        &clue.answer,
+                    )?,
+                    question: clue.question,
+                    points: clue.points,
+                    is_required: clue.is_required,
+                });
+            }
+            Storage::save_template(&env, &owner, &name, &template);
+            Ok(())
+        })
     }
 
-    /// Normalizes answer (trim, lowercase) and returns SHA256 hash as BytesN<32>.
-    fn normalize_and_hash_answer(env: &Env, answer: &String) -> Result<BytesN<32>, HuntError> {
-        let n = answer.len();
-        if n == 0 {
-            return Err(HuntError::InvalidAnswer);
-        }
-        if n > MAX_ANSWER_LENGTH {
-            return Err(HuntError::InvalidAnswer);
-        }
-        let mut buf = [0u8; 256];
-        answer.copy_into_slice(&mut buf[..n as usize]);
-        let mut start = 0usize;
-        let mut end = n as usize;
-        while start < end && Self::is_ascii_space(buf[start]) {
-            start += 1;
-        }
-        while end > start && Self::is_ascii_space(buf[end - 1]) {
-            end -= 1;
-        }
-        if start >= end {
-            return Err(HuntError::InvalidAnswer);
-        }
-        for i in start..end {
-            let b = buf[i];
-            if b >= b'A' && b <= b'Z' {
-                buf[i] = b + (b'a' - b'A');
+    /// Creates a hunt from one of `creator`'s templates, adding the template's clues
+    /// as `create_hunt_with_clues` would.
+    ///
+    /// # Returns
+    /// The new hunt ID
+    ///
+    /// # Errors
+    /// * Any error of `create_hunt`
+    /// * `TemplateNotFound` - `creator` has no template named `template_name`
+    pub fn create_hunt_from_template(
+        env: Env,
+        creator: Address,
+        title: String,
+        description: String,
+        template_name: String,
+    ) -> Result<u64, HuntErrorCode> {
+        Self::with_diagnostics(&env, 0, || {
+            let template = Storage::get_template(&env, &creator, &template_name)
+                .ok_or(HuntErrorCode::TemplateNotFound)?;
+            Self::require_clue_room(&env, 0, 0, template.len())?;
+            let hunt_id = Self::create_hunt(env.clone(), creator, title, description, None, None)?;
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            for clue in template.iter() {
+                let TemplateClue { question, answer_hash, points, is_required } = clue;
+                Self::store_clue(&env, &mut hunt, question, answer_hash, points, is_required);
             }
-        }
-        let normalized = Bytes::from_slice(env, &buf[start..end]);
-        let hash = env.crypto().sha256(&normalized);
-        Ok(hash.to_bytes())
+            Storage::save_hunt(&env, &hunt);
+            Ok(hunt_id)
+        })
     }
 
-    #[inline]
-    fn is_ascii_space(b: u8) -> bool {
-        b == 0x20 || b == 0x09 || b == 0x0a || b == 0x0d
+    /// Returns the names of `owner`'s clue templates, in the order they were first saved.
+    pub fn list_templates(env: Env, owner: Address) -> Vec<String> {
+        Storage::get_template_names(&env, &owner)
     }
-}
 
-mod types;
-mod storage;
-mod errors;
+    /// Chooses how completion is judged for a hunt: all required clues, a percentage of
+    /// all clues, or every clue. Only the creator can change it, and only while the hunt
+    /// is in Draft.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `InvalidCompletionPolicy` - Percentage is 0 or above 100
+    pub fn set_completion_policy(
+        env: Env,
+        hunt_id: u64,
+        policy: CompletionPolicy,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            if let CompletionPolicy::RequiredPercent(percent) = policy {
+                if percent == 0 || percent > 100 {
+                    return Err(HuntErrorCode::InvalidCompletionPolicy);
+                }
+            }
+            hunt.completion_policy = policy;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Turns manual judging on or off. A judged hunt lets the creator mark clues solved
+    /// with custom points through `award_points`. Creator only, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    pub fn set_manual_judging(env: Env, hunt_id: u64, enabled: bool) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.manual_judging = enabled;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Lets the creator and collaborators register as players. Off by default, since they
+    /// know the answers and could farm rewards. Creator only, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    pub fn set_allow_creator_play(env: Env, hunt_id: u64, allow: bool) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.allow_creator_play = allow;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Turns ordered solving on or off. In an ordered hunt a clue can only be solved
+    /// once every clue before it in the clue index is solved. Creator only, Draft only.
+    pub fn set_ordered(env: Env, hunt_id: u64, ordered: bool) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.ordered = ordered;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Sets the player counts at which `register_player` emits a RegistrationMilestone
+    /// event (e.g. 10, 50, 100). Each milestone fires at most once. Creator only, Draft
+    /// only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `InvalidAmount` - A threshold is 0, or more than `MAX_MILESTONES` are given
+    pub fn set_registration_milestones(
+        env: Env,
+        hunt_id: u64,
+        thresholds: Vec<u32>,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            if thresholds.len() > MAX_MILESTONES || thresholds.contains(0) {
+                return Err(HuntErrorCode::InvalidAmount);
+            }
+            Storage::set_milestones(&env, hunt_id, &thresholds);
+            Ok(())
+        })
+    }
+
+    /// Returns a hunt's registration milestones.
+    pub fn get_registration_milestones(env: Env, hunt_id: u64) -> Vec<u32> {
+        Storage::get_milestones(&env, hunt_id)
+    }
+
+    /// Turns per-player clue ordering on or off. It only changes the order returned by
+    /// `get_player_clue_order`; clues are still solved by ID. Creator only, Draft only.
+    pub fn set_shuffle_clues(env: Env, hunt_id: u64, shuffle: bool) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.shuffle_clues = shuffle;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Sets the shortest answer (after trimming) that `add_clue` and `add_accepted_answer`
+    /// accept, so creators cannot ship trivially guessable answers like "1" or "yes" (0
+    /// turns the check off). Answers already added, and hashes passed to `rotate_salt`,
+    /// are not checked.
+    ///
+    /// Those calls already carry the plaintext answer, which the contract hashes and never
+    /// stores, so the check adds no exposure beyond the transaction itself. Creators who
+    /// need answers kept out of transactions altogether must not rely on on-chain hashing.
+    /// Creator only, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `InvalidAmount` - `min_length` is longer than the longest allowed answer
+    pub fn set_min_answer_length(
+        env: Env,
+        hunt_id: u64,
+        min_length: u32,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            if min_length > MAX_ANSWER_LENGTH {
+                return Err(HuntErrorCode::InvalidAmount);
+            }
+            hunt.min_answer_length = min_length;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Caps the base points `add_clue` accepts per clue, so no single clue dominates the
+    /// scoring (0 removes the cap). Clues already added are not rechecked. Creator only,
+    /// Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    pub fn set_max_points_per_clue(
+        env: Env,
+        hunt_id: u64,
+        max_points: u32,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.max_points_per_clue = max_points;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Withholds rewards unless at least `min_players` register (0 removes the minimum).
+    /// Claims wait until the minimum is reached; if the hunt closes short of it, no
+    /// reward is paid and the whole escrow goes back to the creator. Creator only, Draft
+    /// only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `InvalidAmount` - `min_players` is above the hunt's `max_players`
+    pub fn set_min_players_for_rewards(
+        env: Env,
+        hunt_id: u64,
+        min_players: u32,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            if hunt.max_players > 0 && min_players > hunt.max_players {
+                return Err(HuntErrorCode::InvalidAmount);
+            }
+            hunt.min_players_for_rewards = min_players;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Releases token rewards linearly over `vesting_duration` seconds from
+    /// `vesting_start`, so winners claim repeatedly as more vests (0 duration pays at
+    /// once). The claim that takes the last of a reward also mints any NFT. Creator only,
+    /// Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    pub fn set_reward_vesting(
+        env: Env,
+        hunt_id: u64,
+        vesting_start: u64,
+        vesting_duration: u64,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.reward_config.vesting_start = vesting_start;
+            hunt.reward_config.vesting_duration = vesting_duration;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Makes completing `prerequisite` a condition for registering, to chain hunts into a
+    /// campaign; None removes the condition. Creator only, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - The hunt or the prerequisite does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `PrerequisiteCycle` - The prerequisite chain would lead back to this hunt
+    pub fn set_prerequisite_hunt(
+        env: Env,
+        hunt_id: u64,
+        prerequisite: Option<u64>,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            // Chains are acyclic once set, so following one always ends.
+            let mut next = prerequisite;
+            while let Some(ancestor) = next {
+                if ancestor == hunt_id {
+                    return Err(HuntErrorCode::from(HuntError::PrerequisiteCycle { hunt_id }));
+                }
+                next = Storage::get_hunt_or_error(&env, ancestor)
+                    .map_err(HuntErrorCode::from)?
+                    .prerequisite_hunt;
+            }
+            hunt.prerequisite_hunt = prerequisite;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Limits each player to `max_attempts` wrong answers per clue (0 = unlimited). Hitting
+    /// the limit locks the player out of that clue for `cooldown_secs`, doubling with each
+    /// further lockout. Creator only, Draft only.
+    pub fn set_attempt_limit(
+        env: Env,
+        hunt_id: u64,
+        max_attempts: u32,
+        cooldown_secs: u64,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.max_attempts = max_attempts;
+            hunt.attempt_cooldown_secs = cooldown_secs;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Sets the hash function used for this hunt's answers. The algorithm is locked once
+    /// the first clue has been added, since stored hashes depend on it. Creator only,
+    /// Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft or already has clues
+    pub fn set_answer_algo(env: Env, hunt_id: u64, algo: HashAlgo) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft || Storage::get_clue_counter(&env, hunt_id) > 0 {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.answer_algo = algo;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Rewrites the clue index to `new_order`, which must be a permutation of the
+    /// hunt's clue IDs. Ordered hunts are then solved in this order. Creator only,
+    /// Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `InvalidClueOrder` - `new_order` is not a permutation of the existing IDs
+    pub fn reorder_clues(
+        env: Env,
+        hunt_id: u64,
+        editor: Address,
+        new_order: Vec<u32>,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            Self::require_editor(&env, &hunt, &editor)?;
+            let current = Storage::get_clue_ids_for_hunt(&env, hunt_id);
+            if new_order.len() != current.len() {
+                return Err(HuntErrorCode::from(HuntError::InvalidClueOrder { hunt_id }));
+            }
+            for i in 0..new_order.len() {
+                let clue_id = new_order.get(i).unwrap();
+                // Every ID must exist, and appear only once in the new order.
+                if current.first_index_of(clue_id).is_none()
+                    || new_order.first_index_of(clue_id) != Some(i)
+                {
+                    return Err(HuntErrorCode::from(HuntError::InvalidClueOrder { hunt_id }));
+                }
+            }
+            Storage::set_clue_ids_for_hunt(&env, hunt_id, &new_order);
+            Ok(())
+        })
+    }
+
+    /// Points the hunt at a description kept off-chain, for content longer than the inline
+    /// description allows. `content_hash` is the sha256 of the full text, which clients
+    /// verify after fetching it; `summary` is a short on-chain teaser. The inline
+    /// description is left as is. Creator only, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `InvalidDescription` - Summary is longer than `MAX_SUMMARY_LENGTH`
+    pub fn set_description_ref(
+        env: Env,
+        hunt_id: u64,
+        content_hash: BytesN<32>,
+        summary: String,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            if summary.len() > MAX_SUMMARY_LENGTH {
+                return Err(HuntErrorCode::InvalidDescription);
+            }
+            Storage::set_description_ref(&env, hunt_id, &DescriptionRef { content_hash, summary });
+            Ok(())
+        })
+    }
+
+    /// Replaces the hunt's answer salt with `new_salt` and rewrites every clue's hashes,
+    /// e.g. after answer hashes leak. The contract never keeps plaintext answers, so the
+    /// creator computes `sha256(new_salt || answer_hash)` off-chain for each answer and
+    /// passes one `(clue_id, answer_hash, alt_answer_hashes)` entry per clue; the hashes
+    /// are stored as given. A clue's alternatives must be re-salted too, one for each it
+    /// already has. Creator only, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `InvalidClueOrder` - `new_clue_hashes` does not name every clue exactly once
+    /// * `InvalidAnswer` - An entry's alternatives do not match the clue's alternatives
+    pub fn rotate_salt(
+        env: Env,
+        hunt_id: u64,
+        new_salt: BytesN<32>,
+        new_clue_hashes: Vec<(u32, BytesN<32>, Vec<BytesN<32>>)>,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            let clue_ids = Storage::get_clue_ids_for_hunt(&env, hunt_id);
+            if new_clue_hashes.len() != clue_ids.len() {
+                return Err(HuntErrorCode::from(HuntError::InvalidClueOrder { hunt_id }));
+            }
+            let mut seen = Vec::new(&env);
+            let mut clues = Vec::new(&env);
+            for (clue_id, answer_hash, alt_answer_hashes) in new_clue_hashes.iter() {
+                if clue_ids.first_index_of(clue_id).is_none()
+                    || seen.first_index_of(clue_id).is_some()
+                {
+                    return Err(HuntErrorCode::from(HuntError::InvalidClueOrder { hunt_id }));
+                }
+                seen.push_back(clue_id);
+                let mut clue = Storage::get_clue_or_error(&env, hunt_id, clue_id)
+                    .map_err(HuntErrorCode::from)?;
+                if alt_answer_hashes.len() != clue.alt_answer_hashes.len() {
+                    return Err(HuntErrorCode::InvalidAnswer);
+                }
+                clue.answer_hash = answer_hash;
+                clue.alt_answer_hashes = alt_answer_hashes;
+                clues.push_back(clue);
+            }
+
+            Storage::set_answer_salt(&env, hunt_id, &new_salt);
+            for clue in clues.iter() {
+                Storage::save_clue(&env, hunt_id, &clue);
+            }
+            Ok(())
+        })
+    }
+
+    /// Sets how many players can claim a share of the reward pool. Creator only, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `RewardTooSmall` - The funded pool would pay each winner nothing
+    pub fn set_max_winners(env: Env, hunt_id: u64, max_winners: u32) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.reward_config.max_winners = max_winners;
+            if !hunt.reward_config.pays_every_winner() {
+                return Err(HuntErrorCode::from(HuntError::RewardTooSmall { hunt_id }));
+            }
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Chooses where player progress is stored. `Temporary` suits short event-style hunts:
+    /// entries cost less rent and expire a few hours after `end_time`. Creator only,
+    /// Draft only, so it is fixed before any progress exists.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    pub fn set_progress_durability(
+        env: Env,
+        hunt_id: u64,
+        durability: Durability,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.progress_durability = durability;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Marks a clue required or optional and keeps `hunt.required_clues` in step. Only the
+    /// `RequiredCount` policy reads the flag; `RequiredPercent` counts every clue either
+    /// way. Creator or collaborator, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` / `ClueNotFound` - Hunt or clue does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `Unauthorized` - Editor is neither the creator nor a collaborator
+    pub fn set_clue_required(
+        env: Env,
+        hunt_id: u64,
+        editor: Address,
+        clue_id: u32,
+        required: bool,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            Self::require_editor(&env, &hunt, &editor)?;
+            let mut clue = Storage::get_clue_or_error(&env, hunt_id, clue_id)
+                .map_err(HuntErrorCode::from)?;
+            if clue.is_required == required {
+                return Ok(());
+            }
+            clue.is_required = required;
+            hunt.required_clues = if required {
+                hunt.required_clues + 1
+            } else {
+                hunt.required_clues.saturating_sub(1)
+            };
+            Storage::save_clue(&env, hunt_id, &clue);
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Accepts `answer` as an alternative correct answer for a clue (a synonym or another
+    /// spelling), normalized and hashed like the main answer. Adding an answer that is
+    /// already accepted does nothing. Creator or collaborator, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` / `ClueNotFound` - Hunt or clue does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `Unauthorized` - Editor is neither the creator nor a collaborator
+    /// * `InvalidAnswer` - Answer is empty or too long
+    /// * `TooManyAnswers` - The clue already has the maximum number of alternatives
+    pub fn add_accepted_answer(
+        env: Env,
+        hunt_id: u64,
+        editor: Address,
+        clue_id: u32,
+        answer: String,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let (hunt, mut clue) = Self::load_clue_for_edit(&env, hunt_id, &editor, clue_id)?;
+            let answer_hash = Self::normalize_and_hash_answer(&env, &hunt.answer_algo, &answer)
+                .map_err(HuntErrorCode::from)?;
+            Self::check_answer_length(&env, &hunt, &answer)?;
+            let answer_hash = Self::salt_answer_hash(&env, hunt_id, answer_hash);
+            if clue.accepts(&answer_hash) {
+                return Ok(());
+            }
+            if clue.alt_answer_hashes.len() >= MAX_ALT_ANSWERS {
+                return Err(HuntErrorCode::from(HuntError::TooManyAnswers {
+                    hunt_id,
+                    limit: MAX_ALT_ANSWERS,
+                }));
+            }
+            clue.alt_answer_hashes.push_back(answer_hash);
+            Storage::save_clue(&env, hunt_id, &clue);
+            Ok(())
+        })
+    }
+
+    /// Stops accepting an alternative answer added with `add_accepted_answer`. The main
+    /// answer cannot be removed this way. Creator or collaborator, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` / `ClueNotFound` - Hunt or clue does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `Unauthorized` - Editor is neither the creator nor a collaborator
+    /// * `InvalidAnswer` - Answer is not one of the clue's alternatives
+    pub fn remove_accepted_answer(
+        env: Env,
+        hunt_id: u64,
+        editor: Address,
+        clue_id: u32,
+        answer: String,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let (hunt, mut clue) = Self::load_clue_for_edit(&env, hunt_id, &editor, clue_id)?;
+            let answer_hash = Self::normalize_and_hash_answer(&env, &hunt.answer_algo, &answer)
+                .map_err(HuntErrorCode::from)?;
+            let answer_hash = Self::salt_answer_hash(&env, hunt_id, answer_hash);
+            let index = clue
+                .alt_answer_hashes
+                .first_index_of(&answer_hash)
+                .ok_or(HuntErrorCode::InvalidAnswer)?;
+            clue.alt_answer_hashes.remove(index);
+            Storage::save_clue(&env, hunt_id, &clue);
+            Ok(())
+        })
+    }
+
+    /// Awards `time_bonus` extra points to players who solve a clue within
+    /// `time_limit_secs` of starting it (0 disables the bonus). Creator or collaborator,
+    /// Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` / `ClueNotFound` - Hunt or clue does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    pub fn set_clue_time_bonus(
+        env: Env,
+        hunt_id: u64,
+        editor: Address,
+        clue_id: u32,
+        time_limit_secs: u32,
+        time_bonus: u32,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let (_, mut clue) = Self::load_clue_for_edit(&env, hunt_id, &editor, clue_id)?;
+            clue.time_limit_secs = time_limit_secs;
+            clue.time_bonus = time_bonus;
+            Storage::save_clue(&env, hunt_id, &clue);
+            Ok(())
+        })
+    }
+
+    /// Awards `bonus` extra points to the first player who solves a clue (0 disables it).
+    /// Creator or collaborator, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` / `ClueNotFound` - Hunt or clue does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    pub fn set_clue_first_solve_bonus(
+        env: Env,
+        hunt_id: u64,
+        editor: Address,
+        clue_id: u32,
+        bonus: u32,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let (_, mut clue) = Self::load_clue_for_edit(&env, hunt_id, &editor, clue_id)?;
+            clue.first_solve_bonus = bonus;
+            Storage::save_clue(&env, hunt_id, &clue);
+            Ok(())
+        })
+    }
+
+    /// Returns how many players have solved a clue (0 for a missing clue).
+    pub fn get_clue_solve_count(env: Env, hunt_id: u64, clue_id: u32) -> u32 {
+        Storage::get_clue_solves(&env, hunt_id, clue_id)
+    }
+
+    /// Returns a page of `(clue_id, players_who_solved_it)` in clue order, a drop-off
+    /// funnel showing where players give up. Counts are solves recorded since activation,
+    /// so a player whose progress was reset and who solves a clue again counts twice.
+    pub fn get_clue_funnel(env: Env, hunt_id: u64, start: u32, limit: u32) -> Vec<(u32, u32)> {
+        let clue_ids = Storage::get_clue_ids_for_hunt(&env, hunt_id);
+        let page = Self::paginate(&env, clue_ids, start, limit);
+        let mut funnel = Vec::new(&env);
+        for clue_id in page.iter() {
+            funnel.push_back((clue_id, Storage::get_clue_solves(&env, hunt_id, clue_id)));
+        }
+        funnel
+    }
+
+    /// Pins a clue to a map location, or removes the pin with None. Creator or
+    /// collaborator, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` / `ClueNotFound` - Hunt or clue does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `InvalidAmount` - Latitude or longitude is out of range
+    pub fn set_clue_location(
+        env: Env,
+        hunt_id: u64,
+        editor: Address,
+        clue_id: u32,
+        location: Option<Location>,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let (_, mut clue) = Self::load_clue_for_edit(&env, hunt_id, &editor, clue_id)?;
+            if let Some(location) = &location {
+                for (value, limit) in [(location.latitude, 90), (location.longitude, 180)] {
+                    if value.abs() > limit * 1_000_000 {
+                        return Err(HuntErrorCode::from(HuntError::InvalidAmount {
+                            amount: value as i128,
+                        }));
+                    }
+                }
+            }
+            clue.has_location = location.is_some();
+            clue.location = location.unwrap_or_default();
+            Storage::save_clue(&env, hunt_id, &clue);
+            Ok(())
+        })
+    }
+
+    /// Sets or clears (0) the hunt's deadline so it can be scheduled after authoring
+    /// clues. Creator only, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `InvalidEndTime` - `end_time` is neither 0 nor after both now and `start_time`
+    pub fn set_end_time(env: Env, hunt_id: u64, end_time: u64) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            if end_time != 0 && end_time <= env.ledger().timestamp().max(hunt.start_time) {
+                return Err(HuntErrorCode::from(HuntError::InvalidEndTime { hunt_id }));
+            }
+            hunt.end_time = end_time;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Keeps accepting answers for `grace_period_secs` after `end_time`, so a solve sent
+    /// just before the deadline still lands if the network is slow. Registration still
+    /// closes at `end_time`. Creator only, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    pub fn set_grace_period(
+        env: Env,
+        hunt_id: u64,
+        grace_period_secs: u64,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.grace_period_secs = grace_period_secs;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Caps how many players can register for a hunt (0 = unlimited). Creator only,
+    /// Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `InvalidAmount` - `max_players` is below the hunt's `min_players_for_rewards`
+    pub fn set_max_players(env: Env, hunt_id: u64, max_players: u32) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            if max_players > 0 && max_players < hunt.min_players_for_rewards {
+                return Err(HuntErrorCode::InvalidAmount);
+            }
+            hunt.max_players = max_players;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Returns how many registration slots remain, or None if the hunt does not exist
+    /// or has no player cap.
+    pub fn get_remaining_slots(env: Env, hunt_id: u64) -> Option<u32> {
+        let hunt = Storage::get_hunt(&env, hunt_id)?;
+        if hunt.max_players == 0 {
+            return None;
+        }
+        let registered = Storage::get_player_count(&env, hunt_id);
+        Some(hunt.max_players.saturating_sub(registered))
+    }
+
+    /// Sets the contract that mints winner NFTs (see `nft::NftReward`). `None` turns NFT
+    /// rewards off. Creator only, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    pub fn set_nft_contract(
+        env: Env,
+        hunt_id: u64,
+        nft_contract: Option<Address>,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.reward_config.nft_enabled = nft_contract.is_some();
+            hunt.reward_config.nft_contract = nft_contract;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Chooses how winner slots are handed out: first to complete, or a raffle drawn
+    /// after `end_time`. Creator only, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    pub fn set_reward_mode(env: Env, hunt_id: u64, mode: RewardMode) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.reward_mode = mode;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Chooses what happens when players complete in the same ledger as the player who
+    /// took the last winner slot (see `TiePolicy`). Creator only, Draft only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    pub fn set_tie_policy(env: Env, hunt_id: u64, policy: TiePolicy) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            hunt.tie_policy = policy;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Charges `amount` of `token` to each player on registration. Fees join the reward
+    /// pool and are refundable if the hunt is cancelled. The token must match the reward
+    /// token if one is already set. Creator only, Draft only.
+    ///
+    /// # Errors
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `InvalidAmount` - Amount is negative
+    /// * `RewardTokenMismatch` - Token differs from the hunt's reward token
+    pub fn set_entry_fee(
+        env: Env,
+        hunt_id: u64,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            if amount < 0 {
+                return Err(HuntErrorCode::InvalidAmount);
+            }
+            match &hunt.reward_config.reward_token {
+                Some(existing) if *existing != token => {
+                    return Err(HuntErrorCode::from(HuntError::RewardTokenMismatch { hunt_id }));
+                }
+                _ => {}
+            }
+            Self::fix_reward_token(&env, &mut hunt, &token);
+            hunt.entry_fee = amount;
+            Storage::save_hunt(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Lets `collaborator` edit the hunt's clues. Ownership actions such as cancelling or
+    /// managing collaborators stay with the creator. Creator only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    pub fn add_collaborator(
+        env: Env,
+        hunt_id: u64,
+        collaborator: Address,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            hunt.creator.require_auth();
+            Storage::add_collaborator(&env, hunt_id, &collaborator);
+            Ok(())
+        })
+    }
+
+    /// Revokes a collaborator's clue-editing rights. Creator only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    pub fn remove_collaborator(
+        env: Env,
+        hunt_id: u64,
+        collaborator: Address,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            hunt.creator.require_auth();
+            Storage::remove_collaborator(&env, hunt_id, &collaborator);
+            Ok(())
+        })
+    }
+
+    /// Returns whether `addr` is a collaborator on the hunt (the creator is not listed).
+    pub fn is_collaborator(env: Env, hunt_id: u64, addr: Address) -> bool {
+        Storage::is_collaborator(&env, hunt_id, &addr)
+    }
+
+    /// Lets `helper` claim rewards for players who cannot transact themselves (see
+    /// `claim_reward_for`). Creator only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    pub fn add_claim_helper(env: Env, hunt_id: u64, helper: Address) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            hunt.creator.require_auth();
+            Storage::add_claim_helper(&env, hunt_id, &helper);
+            Ok(())
+        })
+    }
+
+    /// Withdraws a claim helper's approval. Creator only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    pub fn remove_claim_helper(
+        env: Env,
+        hunt_id: u64,
+        helper: Address,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            hunt.creator.require_auth();
+            Storage::remove_claim_helper(&env, hunt_id, &helper);
+            Ok(())
+        })
+    }
+
+    /// Returns whether `addr` is an approved claim helper on the hunt.
+    pub fn is_claim_helper(env: Env, hunt_id: u64, addr: Address) -> bool {
+        Storage::is_claim_helper(&env, hunt_id, &addr)
+    }
+
+    /// Moves a hunt from Draft to Active; play opens once its `start_time` (if any) has
+    /// passed. Only the creator can activate, and the hunt must have at least one clue.
+    /// A hunt with winner slots must have escrow covering every slot, and an NFT hunt
+    /// must name its NFT contract. Under `RequiredCount` at least one clue must be required,
+    /// or nobody could ever complete; use `Participation` for a hunt without completion.
+    ///
+    /// Every check runs again on each activation, so a hunt that was deactivated, edited
+    /// or drained in Draft is validated afresh and `activated_at` is reset. The clue set
+    /// commitment (see `get_clue_set_commitment`) is taken again too.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft, has no clues, or counts more
+    ///   required clues than clues
+    /// * `NoRequiredClues` - `RequiredCount` policy with no required clues
+    /// * `InsufficientRewardPool` - Escrow is short of `max_winners * reward_per_winner`,
+    ///   or NFT rewards are enabled without an NFT contract
+    pub fn activate_hunt(env: Env, hunt_id: u64) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_not_paused(&env)?;
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft || hunt.total_clues == 0 {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            if hunt.required_clues > hunt.total_clues {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            if hunt.completion_policy == CompletionPolicy::RequiredCount && hunt.required_clues == 0
+            {
+                return Err(HuntErrorCode::from(HuntError::NoRequiredClues { hunt_id }));
+            }
+            let rewards = &hunt.reward_config;
+            if rewards.max_winners > 0 {
+                let required = rewards.reward_per_winner() * rewards.max_winners as i128;
+                let available = Storage::get_escrow_balance(&env, hunt_id);
+                if required > available {
+                    return Err(HuntErrorCode::from(HuntError::InsufficientRewardPool {
+                        required,
+                        available,
+                    }));
+                }
+            }
+            if rewards.nft_enabled && rewards.nft_contract.is_none() {
+                return Err(HuntErrorCode::InsufficientRewardPool);
+            }
+            hunt.status = HuntStatus::Active;
+            hunt.activated_at = env.ledger().timestamp();
+            Storage::save_hunt(&env, &hunt);
+            Storage::set_clue_set_commitment(&env, hunt_id, &Self::commit_clue_set(&env, hunt_id));
+            Storage::adjust_active_hunts(&env, 1);
+            Self::record_status_change(&env, hunt_id, HuntStatus::Draft, HuntStatus::Active);
+            Ok(())
+        })
+    }
+
+    /// Moves an Active hunt back to Draft so its clues and settings can be edited. Only
+    /// possible before any player has registered. Creator only. The clue set commitment
+    /// taken at activation is dropped, since the clues may change before the next one.
+    /// Published as an ordinary HuntStatusChanged from Active to Draft.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not Active, or players have already registered
+    pub fn deactivate_hunt(env: Env, hunt_id: u64) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::return_to_draft(&env, hunt_id)?;
+            Self::record_status_change(&env, hunt_id, HuntStatus::Active, HuntStatus::Draft);
+            Ok(())
+        })
+    }
+
+    /// Voids an activation the creator regrets before anyone has joined. The hunt ends up
+    /// in Draft exactly as with `deactivate_hunt`, but instead of a status change the
+    /// contract publishes ActivationAborted, so indexers can drop the activation from the
+    /// hunt's history rather than show it as having gone live and been pulled. Creator
+    /// only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not Active, or players have already registered
+    pub fn abort_activation(env: Env, hunt_id: u64) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let activated_at = Self::return_to_draft(&env, hunt_id)?;
+            Storage::move_hunt_status(&env, hunt_id, Some(HuntStatus::Active), HuntStatus::Draft);
+            Self::publish_event(
+                &env,
+                EventLevel::Minimal,
+                (Symbol::new(&env, "ActivationAborted"), hunt_id),
+                || ActivationAbortedEvent {
+                    hunt_id,
+                    activated_at,
+                    event_seq: Storage::next_event_seq(&env, hunt_id),
+                },
+            );
+            Ok(())
+        })
+    }
+
+    /// Registers `player` for an active hunt, collecting the entry fee if one is set.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `HuntNotStarted` - Hunt is active but its start time has not passed
+    /// * `HuntNotActive` - Hunt is not active or has ended
+    /// * `DuplicateRegistration` - Player is already registered
+    /// * `PrerequisiteNotMet` - Player has not completed the hunt's prerequisite hunt
+    /// * `HuntFull` - The hunt's player cap has been reached
+    /// * `CreatorCannotPlay` - Player is the creator or a collaborator and the hunt does
+    ///   not allow creator play
+    pub fn register_player(env: Env, hunt_id: u64, player: Address) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_not_paused(&env)?;
+            player.require_auth();
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            let now = env.ledger().timestamp();
+            Self::require_playable(&hunt, now)?;
+            if !hunt.allow_creator_play
+                && (player == hunt.creator || Storage::is_collaborator(&env, hunt_id, &player))
+            {
+                return Err(HuntErrorCode::from(HuntError::CreatorCannotPlay { hunt_id }));
+            }
+            if Storage::get_player_progress(&env, hunt_id, &player).is_some() {
+                return Err(HuntErrorCode::from(HuntError::DuplicateRegistration { hunt_id }));
+            }
+            if Storage::is_registration_paused(&env, hunt_id) {
+                return Err(HuntErrorCode::from(HuntError::RegistrationClosed { hunt_id }));
+            }
+            if let Some(prerequisite) = hunt.prerequisite_hunt {
+                let completed = Storage::get_player_progress(&env, prerequisite, &player)
+                    .is_some_and(|progress| progress.is_completed);
+                if !completed {
+                    return Err(HuntErrorCode::from(HuntError::PrerequisiteNotMet {
+                        hunt_id,
+                        prerequisite,
+                    }));
+                }
+            }
+            let registered = Storage::get_player_count(&env, hunt_id);
+            if hunt.max_players > 0 && registered >= hunt.max_players {
+                return Err(HuntErrorCode::from(HuntError::HuntFull { hunt_id }));
+            }
+            if hunt.entry_fee > 0 {
+                Self::collect_entry_fee(&env, hunt.clone(), &player);
+            }
+            let progress = PlayerProgress::new(&env, player.clone(), hunt_id, now);
+            Storage::save_player_progress(&env, &progress);
+            Self::bump_leaderboard_cache(&env, &hunt, &progress);
+            Storage::add_hunt_to_player_index(&env, &player, hunt_id);
+            Storage::adjust_total_players(&env, 1);
+            Self::publish_event(
+                &env,
+                EventLevel::Full,
+                (Symbol::new(&env, "PlayerRegistered"), hunt_id),
+                || PlayerRegisteredEvent {
+                    hunt_id,
+                    player,
+                    event_seq: Storage::next_event_seq(&env, hunt_id),
+                },
+            );
+            Self::emit_registration_milestone(&env, hunt_id, registered + 1);
+            Ok(())
+        })
+    }
+
+    /// Withdraws `player` from a hunt, discarding their progress. A player who has
+    /// already completed the hunt cannot unregister.
+    ///
+    /// # Errors
+    /// * `PlayerNotRegistered` - Player is not registered for the hunt
+    /// * `InvalidHuntStatus` - Player has already completed the hunt
+    pub fn unregister_player(env: Env, hunt_id: u64, player: Address) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            player.require_auth();
+            let progress = Storage::get_player_progress_or_error(&env, hunt_id, &player)
+                .map_err(HuntErrorCode::from)?;
+            if progress.is_completed {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            Storage::remove_player_progress(&env, hunt_id, &player);
+            Storage::remove_hunt_from_player_index(&env, &player, hunt_id);
+            Storage::adjust_total_players(&env, -1);
+            if let Some(hunt) = Storage::get_hunt(&env, hunt_id) {
+                Self::refresh_leaderboard_cache(&env, &hunt);
+            }
+            Self::publish_event(
+                &env,
+                EventLevel::Full,
+                (Symbol::new(&env, "PlayerUnregistered"), hunt_id),
+                || PlayerUnregisteredEvent {
+                    hunt_id,
+                    player,
+                    event_seq: Storage::next_event_seq(&env, hunt_id),
+                },
+            );
+            Ok(())
+        })
+    }
+
+    /// Disqualifies a player caught cheating. They can no longer submit answers or claim,
+    /// and any winner slot they reserved is released for the next completer. A reward
+    /// already claimed cannot be clawed back; the event records that it was paid.
+    /// Creator only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `PlayerNotRegistered` - Player is not registered for the hunt
+    /// * `Disqualified` - Player is already disqualified
+    pub fn disqualify_player(env: Env, hunt_id: u64, player: Address) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            hunt.creator.require_auth();
+            let mut progress = Storage::get_player_progress_or_error(&env, hunt_id, &player)
+                .map_err(HuntErrorCode::from)?;
+            if progress.disqualified {
+                return Err(HuntErrorCode::from(HuntError::Disqualified { hunt_id }));
+            }
+            progress.disqualified = true;
+            if progress.winner_slot && !progress.reward_claimed {
+                Self::release_winner_slot(&env, &mut hunt, &mut progress);
+                Storage::save_hunt(&env, &hunt);
+            }
+            let reward_claimed = progress.reward_claimed;
+            Storage::save_player_progress(&env, &progress);
+            Self::publish_event(
+                &env,
+                EventLevel::Full,
+                (Symbol::new(&env, "PlayerDisqualified"), hunt_id),
+                || PlayerDisqualifiedEvent {
+                    hunt_id,
+                    player,
+                    reward_claimed,
+                    event_seq: Storage::next_event_seq(&env, hunt_id),
+                },
+            );
+            Ok(())
+        })
+    }
+
+    /// Clears a player's solved clues, score and completion so they can retry after a
+    /// bug, keeping them registered. A winner slot they reserved is released. Refused
+    /// once the player has claimed a reward, so nobody is paid twice. Creator only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `PlayerNotRegistered` - Player is not registered for the hunt
+    /// * `InvalidHuntStatus` - Player already claimed a reward, or part of a vesting one
+    pub fn reset_player_progress(
+        env: Env,
+        hunt_id: u64,
+        player: Address,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            hunt.creator.require_auth();
+            let mut progress = Storage::get_player_progress_or_error(&env, hunt_id, &player)
+                .map_err(HuntErrorCode::from)?;
+            if progress.reward_claimed || Storage::get_vested_claimed(&env, hunt_id, &player) > 0 {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            if progress.winner_slot {
+                Self::release_winner_slot(&env, &mut hunt, &mut progress);
+                Storage::save_hunt(&env, &hunt);
+            }
+            if progress.is_completed {
+                Storage::adjust_completions(&env, hunt_id, -1);
+            }
+            progress.completed_clues = Vec::new(&env);
+            progress.score_breakdown = Vec::new(&env);
+            progress.total_score = 0;
+            progress.completed_at = 0;
+            progress.is_completed = false;
+            progress.reward_claimed = false;
+            Storage::save_player_progress(&env, &progress);
+            Self::refresh_leaderboard_cache(&env, &hunt);
+            Self::publish_event(
+                &env,
+                EventLevel::Full,
+                (Symbol::new(&env, "ProgressReset"), hunt_id),
+                || ProgressResetEvent {
+                    hunt_id,
+                    player,
+                    event_seq: Storage::next_event_seq(&env, hunt_id),
+                },
+            );
+            Ok(())
+        })
+    }
+
+    /// Draws up to `max_winners` raffle winners from the players who completed the hunt
+    /// (excluding disqualified ones) and reserves their winner slots. Only they can then
+    /// claim. The seed is `sha256(ledger sequence || hunt_id)`, which is predictable and
+    /// only weakly random: fine for casual hunts, not for high-stakes rewards. Creator
+    /// only, once, after `end_time` and any grace period.
+    ///
+    /// # Returns
+    /// The drawn winners, in draw order
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Not a raffle hunt, not Active, no `end_time` or not yet
+    ///   reached, or winners were already drawn
+    pub fn draw_winners(env: Env, hunt_id: u64) -> Result<Vec<Address>, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_not_paused(&env)?;
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            hunt.creator.require_auth();
+            let now = env.ledger().timestamp();
+            if hunt.reward_mode != RewardMode::Raffle
+                || hunt.status != HuntStatus::Active
+                || hunt.end_time == 0
+                || now < hunt.closes_at()
+                || Storage::get_raffle_winners(&env, hunt_id).is_some()
+            {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+
+            let mut pool = Vec::new(&env);
+            for progress in Storage::get_hunt_players(&env, hunt_id).iter() {
+                if progress.is_completed && !progress.disqualified {
+                    pool.push_back(progress);
+                }
+            }
+
+            let mut seed = Bytes::from_array(&env, &env.ledger().sequence().to_be_bytes());
+            seed.extend_from_array(&hunt_id.to_be_bytes());
+            let seed: BytesN<32> = env.crypto().sha256(&seed).to_bytes();
+
+            let mut winners = Vec::new(&env);
+            let mut draw: u32 = 0;
+            while draw < hunt.reward_config.max_winners && !pool.is_empty() {
+                let mut preimage = Bytes::from_array(&env, &seed.to_array());
+                preimage.extend_from_array(&draw.to_be_bytes());
+                let digest = env.crypto().sha256(&preimage).to_array();
+                let mut head = [0u8; 8];
+                head.copy_from_slice(&digest[..8]);
+                let index = (u64::from_be_bytes(head) % pool.len() as u64) as u32;
+
+                let mut progress = pool.get_unchecked(index);
+                pool.remove_unchecked(index);
+                progress.winner_slot = true;
+                Storage::save_player_progress(&env, &progress);
+                winners.push_back(progress.player);
+                draw += 1;
+            }
+
+            hunt.reward_config.reserved_winners = winners.len();
+            Storage::save_hunt(&env, &hunt);
+            Storage::set_raffle_winners(&env, hunt_id, &winners);
+            Ok(winners)
+        })
+    }
+
+    /// Returns the drawn raffle winners, or an empty list before the draw.
+    pub fn get_raffle_winners(env: Env, hunt_id: u64) -> Vec<Address> {
+        Storage::get_raffle_winners(&env, hunt_id).unwrap_or(Vec::new(&env))
+    }
+
+    /// Returns a page of the hunt's payouts: each claim paid, in claim order, with the
+    /// winner and the token amount paid to them. Recorded as claims happen, so it is an
+    /// audit trail rather than a recomputation. A vesting reward claimed in installments
+    /// appears once per installment, so a winner may have several rows.
+    pub fn get_distribution_record(
+        env: Env,
+        hunt_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> Vec<(Address, i128)> {
+        let end = start
+            .saturating_add(limit)
+            .min(Storage::get_distribution_count(&env, hunt_id));
+        let mut page = Vec::new(&env);
+        for index in start..end {
+            if let Some(row) = Storage::get_distribution(&env, hunt_id, index) {
+                page.push_back(row);
+            }
+        }
+        page
+    }
+
+    /// Returns progress for each of `players`, aligned to the input order, with None for
+    /// addresses not registered for the hunt. Only the first `MAX_PROGRESS_BATCH`
+    /// addresses are looked up.
+    pub fn get_progress_batch(
+        env: Env,
+        hunt_id: u64,
+        players: Vec<Address>,
+    ) -> Vec<Option<PlayerProgress>> {
+        let mut out = Vec::new(&env);
+        for player in players.iter().take(MAX_PROGRESS_BATCH as usize) {
+            out.push_back(Storage::get_player_progress(&env, hunt_id, &player));
+        }
+        out
+    }
+
+    /// Returns the top `limit` players of a hunt, ranked by:
+    /// 1. `total_score`, highest first;
+    /// 2. `difficulty_score` (sum of squared clue points), highest first, so of two
+    ///    equal scores the one built from harder clues wins;
+    /// 3. `completed_at`, earliest first, with players yet to complete ranked last.
+    ///
+    /// Hunts with a leaderboard cache (see `set_leaderboard_cache_size`) answer from it
+    /// when `limit` fits; otherwise every player is scanned.
+    pub fn get_leaderboard(env: Env, hunt_id: u64, limit: u32) -> Vec<LeaderboardEntry> {
+        let cache_size = Storage::get_hunt(&env, hunt_id).map_or(0, |h| h.leaderboard_cache_size);
+        if cache_size > 0 && limit <= cache_size {
+            return Self::paginate(&env, Storage::get_leaderboard_cache(&env, hunt_id), 0, limit);
+        }
+        Self::paginate(&env, Self::compute_leaderboard(&env, hunt_id), 0, limit)
+    }
+
+    /// Keeps the top `size` leaderboard entries cached so `get_leaderboard` need not scan
+    /// every player of a busy hunt (0 drops the cache). The cache is built right away and
+    /// then kept up to date as players register and solve clues. Creator only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidAmount` - `size` is above `MAX_LEADERBOARD_CACHE`
+    pub fn set_leaderboard_cache_size(
+        env: Env,
+        hunt_id: u64,
+        size: u32,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            hunt.creator.require_auth();
+            if size > MAX_LEADERBOARD_CACHE {
+                return Err(HuntErrorCode::InvalidAmount);
+            }
+            hunt.leaderboard_cache_size = size;
+            Storage::save_hunt(&env, &hunt);
+            Self::refresh_leaderboard_cache(&env, &hunt);
+            Ok(())
+        })
+    }
+
+    /// Rebuilds a hunt's cached leaderboard from every player's progress, in case it has
+    /// drifted. Anyone may call this; it only rewrites data derived from stored progress.
+    ///
+    /// # Returns
+    /// The number of cached entries (0 if the hunt keeps no cache)
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    pub fn recompute_leaderboard_cache(env: Env, hunt_id: u64) -> Result<u32, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            Ok(Self::refresh_leaderboard_cache(&env, &hunt))
+        })
+    }
+
+    /// Returns the points `player` earned for each solved clue as `(clue_id, points)`, in
+    /// solve order, bonuses included. Empty if they are not registered.
+    pub fn get_score_breakdown(env: Env, hunt_id: u64, player: Address) -> Vec<(u32, u32)> {
+        Storage::get_player_progress(&env, hunt_id, &player)
+            .map(|progress| progress.score_breakdown)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Returns when `player` last answered a clue correctly in the hunt (their registration
+    /// time before any solve), or None if they are not registered.
+    pub fn get_player_last_activity(env: Env, hunt_id: u64, player: Address) -> Option<u64> {
+        Storage::get_player_progress(&env, hunt_id, &player).map(|p| p.last_activity_at)
+    }
+
+    /// Returns a page of hunt IDs `player` has joined, in join order.
+    pub fn get_player_hunts(env: Env, player: Address, start: u32, limit: u32) -> Vec<u64> {
+        let hunt_ids = Storage::get_player_hunt_ids(&env, &player);
+        Self::paginate(&env, hunt_ids, start, limit)
+    }
+
+    /// Submits a plain-text answer for a clue. The answer is normalized and hashed the
+    /// same way as at clue creation. On the solve that meets the hunt's completion
+    /// policy the player is marked completed.
+    ///
+    /// # Returns
+    /// The points earned for the clue
+    ///
+    /// # Errors
+    /// * `HuntNotFound` / `ClueNotFound` - Hunt or clue does not exist
+    /// * `HuntNotStarted` - Hunt is active but its start time has not passed
+    /// * `HuntNotActive` - Hunt is not active, or is past `end_time` and its grace period
+    /// * `PlayerNotRegistered` - Player is not registered for the hunt
+    /// * `Disqualified` - Player was disqualified by the creator
+    /// * `ClueAlreadyCompleted` - Player already solved this clue
+    /// * `InvalidClueOrder` - Ordered hunt and an earlier clue is unsolved
+    /// * `TooManyAttempts` - Player is locked out of this clue until a cooldown ends
+    /// * `InvalidAnswer` - Answer does not match
+    ///
+    /// In hunts with an attempt limit a wrong answer returns `Ok(0)` instead of
+    /// `InvalidAnswer`, since a failed call's storage writes are discarded and the attempt
+    /// would otherwise never be counted.
+    pub fn submit_answer(
+        env: Env,
+        hunt_id: u64,
+        clue_id: u32,
+        player: Address,
+        answer: String,
+    ) -> Result<u32, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_not_paused(&env)?;
+            player.require_auth();
+            Self::check_answer(&env, hunt_id, clue_id, &player, &answer)
+        })
+    }
+
+    /// Submits an answer on behalf of `player`, authorized by the player's ed25519
+    /// signature instead of `require_auth`, so a relayer can send (and pay for) the
+    /// transaction while `player` is credited. The signature must cover the XDR of
+    /// `(contract address, hunt_id, clue_id, answer, nonce)`, where `nonce` is
+    /// `get_nonce(hunt_id, player)`; each accepted submission bumps the nonce, so a
+    /// signature cannot be replayed. Only account (G...) addresses can sign.
+    ///
+    /// # Returns
+    /// The points earned for the clue
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `player` is a contract address
+    /// * Plus the errors of `submit_answer`
+    ///
+    /// A signature that does not verify (wrong key, message or nonce) aborts the call.
+    pub fn submit_answer_signed(
+        env: Env,
+        hunt_id: u64,
+        clue_id: u32,
+        player: Address,
+        answer: String,
+        signature: BytesN<64>,
+    ) -> Result<u32, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_not_paused(&env)?;
+            let public_key = Self::account_public_key(&env, &player)?;
+            let nonce = Storage::get_nonce(&env, hunt_id, &player);
+            let contract = env.current_contract_address();
+            let message = (contract, hunt_id, clue_id, answer.clone(), nonce).to_xdr(&env);
+            env.crypto().ed25519_verify(&public_key, &message, &signature);
+            Storage::set_nonce(&env, hunt_id, &player, nonce + 1);
+            Self::check_answer(&env, hunt_id, clue_id, &player, &answer)
+        })
+    }
+
+    /// Returns the nonce `player`'s next `submit_answer_signed` signature must cover.
+    pub fn get_nonce(env: Env, hunt_id: u64, player: Address) -> u64 {
+        Storage::get_nonce(&env, hunt_id, &player)
+    }
+
+    /// Marks a clue solved for `player` with `points` chosen by the creator instead of the
+    /// clue's own value (and any time bonus). Only for hunts with manual judging on; the
+    /// same play checks as `submit_answer` apply. Creator only.
+    ///
+    /// # Returns
+    /// The points awarded
+    ///
+    /// # Errors
+    /// * `InvalidHuntStatus` - Manual judging is off for the hunt
+    /// * Otherwise the same as `submit_answer`, except `InvalidAnswer`
+    pub fn award_points(
+        env: Env,
+        hunt_id: u64,
+        player: Address,
+        clue_id: u32,
+        points: u32,
+    ) -> Result<u32, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_not_paused(&env)?;
+            let (hunt, progress, clue) = Self::load_submission(&env, hunt_id, clue_id, &player)?;
+            if !hunt.manual_judging {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            Ok(Self::record_solve(&env, hunt, progress, &clue, points))
+        })
+    }
+
+    /// First half of commit-reveal submission: stores `commitment`, which must equal
+    /// `sha256(answer_bytes || salt || player.to_xdr())`. Keeping the plain-text answer
+    /// out of this transaction stops observers from copying it before it lands.
+    ///
+    /// # Errors
+    /// Same as `submit_answer`, except `InvalidAnswer`.
+    pub fn commit_answer(
+        env: Env,
+        hunt_id: u64,
+        clue_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_not_paused(&env)?;
+            player.require_auth();
+            Self::load_submission(&env, hunt_id, clue_id, &player)?;
+            let entry = AnswerCommitment {
+                commitment,
+                committed_ledger: env.ledger().sequence(),
+            };
+            Storage::save_commitment(&env, hunt_id, clue_id, &player, &entry);
+            Ok(())
+        })
+    }
+
+    /// Second half of commit-reveal submission. Checks the revealed answer and salt
+    /// against the stored commitment, then verifies the answer like `submit_answer`.
+    /// Must be called at least `MIN_REVEAL_DELAY_LEDGERS` ledgers after the commit.
+    ///
+    /// # Errors
+    /// * `CommitmentNotFound` - No commitment stored for this player and clue
+    /// * `RevealTooEarly` - Reveal is in the same ledger as the commit
+    /// * `InvalidAnswer` - Reveal does not match the commitment, or the answer is wrong
+    /// * Plus the errors of `submit_answer`
+    pub fn reveal_answer(
+        env: Env,
+        hunt_id: u64,
+        clue_id: u32,
+        player: Address,
+        answer: String,
+        salt: BytesN<32>,
+    ) -> Result<u32, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_not_paused(&env)?;
+            player.require_auth();
+            let (hunt, progress, clue) = Self::load_submission(&env, hunt_id, clue_id, &player)?;
+            let entry = Storage::get_commitment(&env, hunt_id, clue_id, &player)
+                .ok_or(HuntErrorCode::from(HuntError::CommitmentNotFound { hunt_id }))?;
+            if env.ledger().sequence() < entry.committed_ledger + MIN_REVEAL_DELAY_LEDGERS {
+                return Err(HuntErrorCode::from(HuntError::RevealTooEarly { hunt_id }));
+            }
+
+            let mut preimage = Self::string_to_bytes(&env, &answer).map_err(HuntErrorCode::from)?;
+            preimage.append(&Bytes::from_array(&env, &salt.to_array()));
+            preimage.append(&player.clone().to_xdr(&env));
+            let expected: BytesN<32> = env.crypto().sha256(&preimage).to_bytes();
+            if expected != entry.commitment {
+                return Err(HuntErrorCode::InvalidAnswer);
+            }
+
+            let attempts = Self::load_attempts(&env, &hunt, clue_id, &player)?;
+            let answer_hash = Self::normalize_and_hash_answer(&env, &hunt.answer_algo, &answer)
+                .map_err(HuntErrorCode::from)?;
+            if !clue.accepts(&Self::salt_answer_hash(&env, hunt_id, answer_hash)) {
+                return Self::reject_answer(&env, &hunt, clue_id, &player, attempts);
+            }
+            Storage::remove_commitment(&env, hunt_id, clue_id, &player);
+            let points = Self::points_for_solve(&env, &progress, &clue);
+            Ok(Self::record_solve(&env, hunt, progress, &clue, points))
+        })
+    }
+
+    /// Pays a player who completed the hunt their share of the reward pool
+    /// (`xlm_pool / max_winners`). Only players who reserved a winner slot on
+    /// completion can claim, once each. Eligibility is checked against `player`, but the
+    /// reward is sent to `payout_address` when given (e.g. a cold wallet). NFT hunts also
+    /// mint the recipient a token tagged with their claim position (`claimed_count`
+    /// including this claim), so every winner's NFT is distinct. With reward vesting
+    /// (see `set_reward_vesting`) each call pays what has vested since the last one, and
+    /// the claim completes once the whole share has vested.
+    ///
+    /// # Returns
+    /// The token amount transferred
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is Draft or Cancelled
+    /// * `RewardsFrozen` - The admin froze reward claims on this hunt
+    /// * `TooFewPlayers` - Hunt is short of its `min_players_for_rewards`
+    /// * `PlayerNotRegistered` - Player is not registered for the hunt
+    /// * `Disqualified` - Player was disqualified by the creator
+    /// * `HuntNotCompleted` - Player has not completed the hunt
+    /// * `RewardAlreadyClaimed` - Player already claimed
+    /// * `InsufficientRewardPool` - Player completed without a winner slot, or escrow is short
+    /// * `NothingVested` - Nothing new has vested since the player's last claim
+    pub fn claim_reward(
+        env: Env,
+        hunt_id: u64,
+        player: Address,
+        payout_address: Option<Address>,
+    ) -> Result<i128, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_not_paused(&env)?;
+            player.require_auth();
+            let (hunt, progress) = Self::load_claim(&env, hunt_id, &player)?;
+            let recipient = payout_address.unwrap_or(player.clone());
+            Ok(Self::pay_claim(&env, hunt, progress, &recipient))
+        })
+    }
+
+    /// Claims `player`'s reward on their behalf, for players who cannot transact.
+    /// `caller` must be the creator or an approved claim helper and authorizes the call;
+    /// eligibility is checked against `player` as in `claim_reward`, and the reward
+    /// always goes to `player`.
+    ///
+    /// # Returns
+    /// The token amount transferred
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `caller` is neither the creator nor an approved helper
+    /// * Any error `claim_reward` returns for `player`
+    pub fn claim_reward_for(
+        env: Env,
+        hunt_id: u64,
+        player: Address,
+        caller: Address,
+    ) -> Result<i128, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_not_paused(&env)?;
+            caller.require_auth();
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if caller != hunt.creator && !Storage::is_claim_helper(&env, hunt_id, &caller) {
+                return Err(HuntErrorCode::Unauthorized);
+            }
+            let (hunt, progress) = Self::load_claim(&env, hunt_id, &player)?;
+            Ok(Self::pay_claim(&env, hunt, progress, &player))
+        })
+    }
+
+    /// Claims `player`'s reward from each of `hunt_ids` in one transaction. Hunts where
+    /// the player cannot claim (see `can_claim_reward`) are skipped rather than failing
+    /// the batch. Only the first `MAX_CLAIM_BATCH` hunts are attempted.
+    ///
+    /// # Returns
+    /// The amount claimed from each attempted hunt, in input order (0 for skipped hunts)
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is globally paused
+    pub fn claim_rewards_multi(
+        env: Env,
+        player: Address,
+        hunt_ids: Vec<u64>,
+    ) -> Result<Vec<i128>, HuntErrorCode> {
+        Self::with_diagnostics(&env, 0, || {
+            Self::require_not_paused(&env)?;
+            player.require_auth();
+            let mut amounts = Vec::new(&env);
+            for hunt_id in hunt_ids.iter().take(MAX_CLAIM_BATCH as usize) {
+                let amount = match Self::load_claim(&env, hunt_id, &player) {
+                    Ok((hunt, progress)) => Self::pay_claim(&env, hunt, progress, &player),
+                    Err(_) => 0,
+                };
+                amounts.push_back(amount);
+            }
+            Ok(amounts)
+        })
+    }
+
+    /// Returns true if the hunt exists, is Active and has not passed its `end_time`.
+    pub fn is_hunt_active(env: Env, hunt_id: u64) -> bool {
+        Storage::get_hunt(&env, hunt_id)
+            .map(|hunt| hunt.is_active(env.ledger().timestamp()))
+            .unwrap_or(false)
+    }
+
+    /// Returns the seconds left until the hunt stops accepting answers, and that effective
+    /// close time (`end_time` plus the grace period). Both are 0 for a missing hunt or one
+    /// without an end time; the remaining time is 0 once the hunt has closed.
+    pub fn get_hunt_time_remaining(env: Env, hunt_id: u64) -> (u64, u64) {
+        let closes_at = match Storage::get_hunt(&env, hunt_id) {
+            Some(hunt) => hunt.closes_at(),
+            None => return (0, 0),
+        };
+        (closes_at.saturating_sub(env.ledger().timestamp()), closes_at)
+    }
+
+    /// Returns the number of hunts ever created.
+    pub fn get_total_hunts(env: Env) -> u64 {
+        Storage::get_hunt_counter(&env)
+    }
+
+    /// Returns contract-wide totals for hunts, active hunts, registrations and payouts.
+    pub fn get_global_stats(env: Env) -> GlobalStats {
+        GlobalStats {
+            total_hunts: Storage::get_hunt_counter(&env),
+            active_hunts: Storage::get_active_hunts(&env),
+            total_players: Storage::get_total_players(&env),
+            total_rewards_distributed: Storage::get_rewards_distributed(&env),
+        }
+    }
+
+    /// Returns totals across every hunt `creator` has created. Built from each hunt's
+    /// running counters via the creator's hunt index, so the cost grows with the number
+    /// of hunts, not players.
+    pub fn get_creator_stats(env: Env, creator: Address) -> CreatorStats {
+        let hunt_ids = Storage::get_creator_hunt_ids(&env, &creator);
+        let mut stats = CreatorStats {
+            hunts: hunt_ids.len(),
+            total_players: 0,
+            total_completions: 0,
+            total_rewards_distributed: 0,
+        };
+        for hunt_id in hunt_ids.iter() {
+            stats.total_players += Storage::get_player_count(&env, hunt_id) as u64;
+            stats.total_completions += Storage::get_completions(&env, hunt_id) as u64;
+            stats.total_rewards_distributed += Storage::get_hunt_rewards_distributed(&env, hunt_id);
+        }
+        stats
+    }
+
+    /// Reports whether `player` could claim their reward right now, so a UI can enable
+    /// or disable its claim button. The code is 0 when eligible, otherwise the
+    /// `HuntErrorCode` value `claim_reward` would fail with.
+    pub fn can_claim_reward(env: Env, hunt_id: u64, player: Address) -> (bool, u32) {
+        let eligible = Self::require_not_paused(&env)
+            .and_then(|_| Self::load_claim(&env, hunt_id, &player));
+        match eligible {
+            Ok(_) => (true, 0),
+            Err(code) => (false, code as u32),
+        }
+    }
+
+    /// Finalizes an Active hunt: it moves to Completed and `closed_at` is recorded, after
+    /// which no answers, registrations or edits are accepted. Winners who already hold a
+    /// slot can still claim; the escrow backing every other slot, including entry fees,
+    /// goes back to the creator. Unlike `cancel_hunt` nothing is refunded to players.
+    /// Raffle hunts must draw their winners first. Creator only.
+    ///
+    /// # Returns
+    /// The amount returned to the creator
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not Active, or is a raffle without drawn winners
+    pub fn close_hunt(env: Env, hunt_id: u64) -> Result<i128, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Active
+                || (hunt.reward_mode == RewardMode::Raffle
+                    && Storage::get_raffle_winners(&env, hunt_id).is_none())
+            {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            Ok(Self::finalize_hunt(&env, hunt))
+        })
+    }
+
+    /// Closes every Active hunt in a window of the Active status index whose answers
+    /// stopped being accepted (`end_time` plus grace) as if by `close_hunt`. Raffle hunts
+    /// still waiting on `draw_winners` are left alone. Anyone may call this, so an
+    /// off-chain keeper can batch expirations; at most `MAX_SWEEP_BATCH` hunts are
+    /// inspected. Closed hunts leave the index, so a keeper paging through it should
+    /// advance `status_index_start` by the window size minus the returned count.
+    ///
+    /// # Returns
+    /// The number of hunts closed
+    pub fn sweep_expired_hunts(env: Env, status_index_start: u32, limit: u32) -> u32 {
+        let active = Storage::get_hunt_ids_by_status(&env, &HuntStatus::Active);
+        let window = Self::paginate(&env, active, status_index_start, limit.min(MAX_SWEEP_BATCH));
+        let now = env.ledger().timestamp();
+        let mut closed = 0;
+        for hunt_id in window.iter() {
+            let Some(hunt) = Storage::get_hunt(&env, hunt_id) else {
+                continue;
+            };
+            let expired = hunt.end_time > 0 && now >= hunt.closes_at();
+            let awaiting_draw = hunt.reward_mode == RewardMode::Raffle
+                && Storage::get_raffle_winners(&env, hunt_id).is_none();
+            if expired && !awaiting_draw {
+                Self::finalize_hunt(&env, hunt);
+                closed += 1;
+            }
+        }
+        closed
+    }
+
+    /// Cancels a Draft or Active hunt. The creator is refunded the escrow minus entry
+    /// fees, which stay behind for players to reclaim with `claim_cancellation_refund`.
+    /// `reason` (may be empty) is stored on the hunt and published in HuntCancelled.
+    ///
+    /// # Returns
+    /// The amount refunded to the creator
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is already Completed or Cancelled
+    /// * `InvalidDescription` - Reason is longer than 500 characters
+    pub fn cancel_hunt(env: Env, hunt_id: u64, reason: String) -> Result<i128, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            let old_status = hunt.status.clone();
+            if old_status != HuntStatus::Draft && old_status != HuntStatus::Active {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            if reason.len() > MAX_CANCEL_REASON_LENGTH {
+                return Err(HuntErrorCode::InvalidDescription);
+            }
+
+            let escrow = Storage::get_escrow_balance(&env, hunt_id);
+            let fees_held = Storage::get_fees_held(&env, hunt_id).min(escrow);
+            let creator_refund = escrow - fees_held;
+
+            hunt.status = HuntStatus::Cancelled;
+            hunt.cancel_reason = reason.clone();
+            Storage::save_hunt(&env, &hunt);
+            Storage::set_escrow_balance(&env, hunt_id, fees_held);
+            Storage::set_fees_held(&env, hunt_id, fees_held);
+            if old_status == HuntStatus::Active {
+                Storage::adjust_active_hunts(&env, -1);
+            }
+
+            if creator_refund > 0 {
+                if let Some(reward_token) = &hunt.reward_config.reward_token {
+                    token::Client::new(&env, reward_token).transfer(
+                        &env.current_contract_address(),
+                        &hunt.creator,
+                        &creator_refund,
+                    );
+                }
+            }
+            Self::record_status_change(&env, hunt_id, old_status, HuntStatus::Cancelled);
+            Self::publish_event(
+                &env,
+                EventLevel::Minimal,
+                (Symbol::new(&env, "HuntCancelled"), hunt_id),
+                || HuntCancelledEvent {
+                    hunt_id,
+                    reason,
+                    event_seq: Storage::next_event_seq(&env, hunt_id),
+                },
+            );
+            Ok(creator_refund)
+        })
+    }
+
+    /// Returns the entry fee `player` paid for a cancelled hunt. Players who never paid
+    /// (or were already refunded) receive 0.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not Cancelled
+    pub fn claim_cancellation_refund(
+        env: Env,
+        hunt_id: u64,
+        player: Address,
+    ) -> Result<i128, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            player.require_auth();
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Cancelled {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            let escrow = Storage::get_escrow_balance(&env, hunt_id);
+            let refund = Storage::get_entry_fee_paid(&env, hunt_id, &player).min(escrow);
+            if refund == 0 {
+                return Ok(0);
+            }
+
+            Storage::set_entry_fee_paid(&env, hunt_id, &player, 0);
+            Storage::set_escrow_balance(&env, hunt_id, escrow - refund);
+            let fees_held = Storage::get_fees_held(&env, hunt_id);
+            Storage::set_fees_held(&env, hunt_id, (fees_held - refund).max(0));
+
+            if let Some(reward_token) = &hunt.reward_config.reward_token {
+                token::Client::new(&env, reward_token).transfer(
+                    &env.current_contract_address(),
+                    &player,
+                    &refund,
+                );
+            }
+            Ok(refund)
+        })
+    }
+
+    /// Approximates the bytes a hunt occupies in storage: the hunt record (including its
+    /// title and description) plus per-clue and per-player averages taken from the index
+    /// lengths. Records are not loaded. Returns 0 for a missing hunt.
+    pub fn estimate_hunt_storage(env: Env, hunt_id: u64) -> u64 {
+        let hunt = match Storage::get_hunt(&env, hunt_id) {
+            Some(hunt) => hunt,
+            None => return 0,
+        };
+        let clues = Storage::get_clue_ids_for_hunt(&env, hunt_id).len() as u64;
+        let players = Storage::get_player_count(&env, hunt_id) as u64;
+        EST_HUNT_BASE_BYTES
+            + hunt.title.len() as u64
+            + hunt.description.len() as u64
+            + clues * EST_CLUE_BYTES
+            + players * EST_PLAYER_BYTES
+    }
+
+    /// Returns clue information for a hunt/clue. Does not expose the answer hash.
+    pub fn get_clue(env: Env, hunt_id: u64, clue_id: u32) -> Result<ClueInfo, HuntErrorCode> {
+        let clue = Storage::get_clue_or_error(&env, hunt_id, clue_id)
+            .map_err(HuntErrorCode::from)?;
+        Ok(clue.to_info())
+    }
+
+    /// Returns how many events the hunt has emitted. Each hunt event carries its position
+    /// in this sequence as `event_seq`, so an indexer can detect missed events.
+    pub fn get_event_seq(env: Env, hunt_id: u64) -> u64 {
+        Storage::get_event_seq(&env, hunt_id)
+    }
+
+    /// Returns a listing card for each of `hunt_ids` that exists, in input order; missing
+    /// IDs are skipped. Only the first `MAX_SUMMARY_BATCH` IDs are looked up.
+    pub fn get_hunt_summaries(env: Env, hunt_ids: Vec<u64>) -> Vec<HuntSummary> {
+        let mut out = Vec::new(&env);
+        for hunt_id in hunt_ids.iter().take(MAX_SUMMARY_BATCH as usize) {
+            if let Some(hunt) = Storage::get_hunt(&env, hunt_id) {
+                out.push_back(HuntSummary {
+                    hunt_id,
+                    player_count: Storage::get_player_count(&env, hunt_id),
+                    title: hunt.title,
+                    status: hunt.status,
+                    total_clues: hunt.total_clues,
+                    reward_pool: hunt.reward_config.xlm_pool,
+                });
+            }
+        }
+        out
+    }
+
+    /// Returns a page of the IDs of hunts currently in `status`, in the order they
+    /// entered it.
+    pub fn get_hunts_by_status(env: Env, status: HuntStatus, start: u32, limit: u32) -> Vec<u64> {
+        let ids = Storage::get_hunt_ids_by_status(&env, &status);
+        Self::paginate(&env, ids, start, limit)
+    }
+
+    /// Returns all clues for a hunt (question, points, required). Answer hashes are not exposed.
+    pub fn list_clues(env: Env, hunt_id: u64) -> Vec<ClueInfo> {
+        let raw = Storage::list_clues_for_hunt(&env, hunt_id);
+        let mut out = Vec::new(&env);
+        for i in 0..raw.len() {
+            out.push_back(raw.get(i).unwrap().to_info());
+        }
+        out
+    }
+
+    /// Returns the commitment to the hunt's answers taken when it was last activated, or
+    /// None if it never was. It is the sha256 over every clue, in clue order, of
+    /// `clue_id || answer_hash || alt_answer_hashes`, so players can check that the
+    /// puzzles they are solving are still the ones the rewards were funded for.
+    pub fn get_clue_set_commitment(env: Env, hunt_id: u64) -> Option<BytesN<32>> {
+        Storage::get_clue_set_commitment(&env, hunt_id)
+    }
+
+    /// Returns a page of a hunt's required clues (`required_only`) or of its optional
+    /// ones, in clue order. Like `list_clues`, answer hashes are not exposed.
+    pub fn list_clues_filtered(
+        env: Env,
+        hunt_id: u64,
+        required_only: bool,
+        start: u32,
+        limit: u32,
+    ) -> Vec<ClueInfo> {
+        let mut matching = Vec::new(&env);
+        for clue in Storage::list_clues_for_hunt(&env, hunt_id).iter() {
+            if clue.is_required == required_only {
+                matching.push_back(clue.to_info());
+            }
+        }
+        Self::paginate(&env, matching, start, limit)
+    }
+
+    /// Returns a clue to a registered player and, on their first view, starts the clock
+    /// for its time bonus.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` / `ClueNotFound` - Hunt or clue does not exist
+    /// * `HuntNotActive` - Hunt is not active or has ended
+    /// * `PlayerNotRegistered` - Player is not registered for the hunt
+    pub fn view_clue(
+        env: Env,
+        hunt_id: u64,
+        clue_id: u32,
+        player: Address,
+    ) -> Result<ClueInfo, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            player.require_auth();
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            let now = env.ledger().timestamp();
+            Self::require_playable(&hunt, now)?;
+            Storage::get_player_progress_or_error(&env, hunt_id, &player)
+                .map_err(HuntErrorCode::from)?;
+            let clue = Storage::get_clue_or_error(&env, hunt_id, clue_id)
+                .map_err(HuntErrorCode::from)?;
+            if Storage::get_clue_viewed_at(&env, hunt_id, clue_id, &player).is_none() {
+                Storage::set_clue_viewed_at(&env, hunt_id, clue_id, &player, now);
+            }
+            Ok(clue.to_info())
+        })
+    }
+
+    /// Returns a player's wrong answers on a clue since their last lockout ended, and when
+    /// they may answer it again (0 if never locked out). An expired lockout shows 0
+    /// attempts, as the next submission starts afresh.
+    pub fn get_attempt_state(
+        env: Env,
+        hunt_id: u64,
+        clue_id: u32,
+        player: Address,
+    ) -> (u32, u64) {
+        let mut state = Storage::get_attempt_state(&env, hunt_id, clue_id, &player);
+        if let Some(hunt) = Storage::get_hunt(&env, hunt_id) {
+            state.refresh(hunt.max_attempts, env.ledger().timestamp());
+        }
+        (state.attempts, state.next_allowed_at)
+    }
+
+    /// Returns the hunt's off-chain description reference, if one was set.
+    pub fn get_description_ref(env: Env, hunt_id: u64) -> Option<DescriptionRef> {
+        Storage::get_description_ref(&env, hunt_id)
+    }
+
+    /// Returns the hash function a hunt uses for its answers, or `None` if it does not exist.
+    pub fn get_answer_algo(env: Env, hunt_id: u64) -> Option<HashAlgo> {
+        Storage::get_hunt(&env, hunt_id).map(|hunt| hunt.answer_algo)
+    }
+
+    /// Returns all of a hunt's flags and modes in one read, or `None` if it does not exist.
+    pub fn get_hunt_config(env: Env, hunt_id: u64) -> Option<HuntConfig> {
+        let hunt = Storage::get_hunt(&env, hunt_id)?;
+        Some(HuntConfig {
+            ordered: hunt.ordered,
+            completion_policy: hunt.completion_policy,
+            reward_mode: hunt.reward_mode,
+            tie_policy: hunt.tie_policy,
+            progress_durability: hunt.progress_durability,
+            answer_algo: hunt.answer_algo,
+            manual_judging: hunt.manual_judging,
+            allow_creator_play: hunt.allow_creator_play,
+            shuffle_clues: hunt.shuffle_clues,
+            nft_enabled: hunt.reward_config.nft_enabled,
+            registration_paused: Storage::is_registration_paused(&env, hunt_id),
+            rewards_frozen: Storage::are_rewards_frozen(&env, hunt_id),
+            max_points_per_clue: hunt.max_points_per_clue,
+            min_players_for_rewards: hunt.min_players_for_rewards,
+        })
+    }
+
+    /// Returns whether a hunt exists, checking its storage key without loading it.
+    pub fn hunt_exists(env: Env, hunt_id: u64) -> bool {
+        Storage::has_hunt(&env, hunt_id)
+    }
+
+    /// Returns whether a clue exists, checking its storage key without loading it.
+    pub fn clue_exists(env: Env, hunt_id: u64, clue_id: u32) -> bool {
+        Storage::has_clue(&env, hunt_id, clue_id)
+    }
+
+    /// Returns the order in which `player` should be shown the hunt's clues. For hunts with
+    /// shuffling on this is a permutation of the clue IDs seeded by
+    /// `sha256(player || hunt salt || hunt_id)`, stable for the player and different
+    /// between players, so answers cannot be passed along by position. Other hunts, and
+    /// ordered hunts (which must be solved in index order), return the clue index as is.
+    pub fn get_player_clue_order(env: Env, hunt_id: u64, player: Address) -> Vec<u32> {
+        let mut order = Storage::get_clue_ids_for_hunt(&env, hunt_id);
+        match Storage::get_hunt(&env, hunt_id) {
+            Some(hunt) if hunt.shuffle_clues && !hunt.ordered => {}
+            _ => return order,
+        }
+
+        let mut seed = player.to_xdr(&env);
+        if let Some(salt) = Storage::get_answer_salt(&env, hunt_id) {
+            seed.extend_from_array(&salt.to_array());
+        }
+        seed.extend_from_array(&hunt_id.to_be_bytes());
+        let seed: BytesN<32> = env.crypto().sha256(&seed).to_bytes();
+
+        // Fisher-Yates, drawing each swap position from sha256(seed || step).
+        let mut i = order.len();
+        while i > 1 {
+            i -= 1;
+            let mut preimage = Bytes::from_array(&env, &seed.to_array());
+            preimage.extend_from_array(&i.to_be_bytes());
+            let digest = env.crypto().sha256(&preimage).to_array();
+            let mut head = [0u8; 8];
+            head.copy_from_slice(&digest[..8]);
+            let j = (u64::from_be_bytes(head) % (i as u64 + 1)) as u32;
+            let (a, b) = (order.get_unchecked(i), order.get_unchecked(j));
+            order.set(i, b);
+            order.set(j, a);
+        }
+        order
+    }
+
+    /// Returns the hunt's clue IDs in index order, without loading the clues.
+    pub fn get_clue_ids(env: Env, hunt_id: u64) -> Vec<u32> {
+        Storage::get_clue_ids_for_hunt(&env, hunt_id)
+    }
+
+    /// Returns only a clue's map pin, so map layers need not load questions. None if the
+    /// clue does not exist or has no location.
+    pub fn get_clue_location(env: Env, hunt_id: u64, clue_id: u32) -> Option<Location> {
+        Storage::get_clue(&env, hunt_id, clue_id)
+            .filter(|clue| clue.has_location)
+            .map(|clue| clue.location)
+    }
+
+    /// Returns the IDs of the hunt's required clues in ascending order, without loading
+    /// the clues themselves. Empty for a missing hunt.
+    pub fn get_required_clue_ids(env: Env, hunt_id: u64) -> Vec<u32> {
+        Storage::get_required_clue_ids(&env, hunt_id)
+    }
+
+    /// Checks client-side answer hashing against the contract. Each input pairs a clue ID
+    /// with the hex-encoded hash of its normalized answer under the hunt's `HashAlgo`; the
+    /// hunt's salt, if any, is applied before comparing with the stored `answer_hash`.
+    /// Returns one flag per input, false for unknown clues and malformed hex. Only the
+    /// first `MAX_VERIFY_BATCH` inputs are checked.
+    pub fn verify_clue_hashes(env: Env, hunt_id: u64, hashes: Vec<(u32, String)>) -> Vec<bool> {
+        let mut out = Vec::new(&env);
+        for (clue_id, hash) in hashes.iter().take(MAX_VERIFY_BATCH as usize) {
+            let clue = Storage::get_clue(&env, hunt_id, clue_id);
+            let matches = match (clue, Self::parse_hash(&env, &hash)) {
+                (Some(clue), Some(hash)) => {
+                    clue.answer_hash == Self::salt_answer_hash(&env, hunt_id, hash)
+                }
+                _ => false,
+            };
+            out.push_back(matches);
+        }
+        out
+    }
+
+    /// Returns a page of the hunt's clues in index order, each flagged with whether
+    /// `player` has solved it, so a hunt screen needs one call. An unregistered player
+    /// sees every clue unsolved.
+    pub fn get_hunt_view(
+        env: Env,
+        hunt_id: u64,
+        player: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<ClueWithStatus> {
+        let all_ids = Storage::get_clue_ids_for_hunt(&env, hunt_id);
+        let clue_ids = Self::paginate(&env, all_ids, start, limit);
+        let progress = Storage::get_player_progress(&env, hunt_id, &player);
+        let mut out = Vec::new(&env);
+        for clue_id in clue_ids.iter() {
+            if let Some(clue) = Storage::get_clue(&env, hunt_id, clue_id) {
+                let solved = progress
+                    .as_ref()
+                    .is_some_and(|p| p.has_completed_clue(clue_id));
+                out.push_back(ClueWithStatus {
+                    clue: clue.to_info(),
+                    solved,
+                });
+            }
+        }
+        out
+    }
+
+    /// Returns the clue IDs `player` has not solved yet, in index order. An unregistered
+    /// player has solved nothing, so they get every clue ID rather than an error.
+    pub fn get_unsolved_clues(env: Env, hunt_id: u64, player: Address) -> Vec<u32> {
+        let clue_ids = Storage::get_clue_ids_for_hunt(&env, hunt_id);
+        let progress = match Storage::get_player_progress(&env, hunt_id, &player) {
+            Some(progress) => progress,
+            None => return clue_ids,
+        };
+        let mut unsolved = Vec::new(&env);
+        for clue_id in clue_ids.iter() {
+            if !progress.has_completed_clue(clue_id) {
+                unsolved.push_back(clue_id);
+            }
+        }
+        unsolved
+    }
+
+    /// Returns the score and completion status `player` would have after also solving
+    /// `clue_ids` now, scored as `submit_answer` would (time and first-solve bonuses
+    /// included). Nothing is written. Solved or unknown clues are ignored; a missing hunt
+    /// or unregistered player gives `(0, false)`.
+    pub fn simulate_completion(
+        env: Env,
+        hunt_id: u64,
+        player: Address,
+        clue_ids: Vec<u32>,
+    ) -> (u32, bool) {
+        let (Some(hunt), Some(mut progress)) = (
+            Storage::get_hunt(&env, hunt_id),
+            Storage::get_player_progress(&env, hunt_id, &player),
+        ) else {
+            return (0, false);
+        };
+        for clue_id in clue_ids.iter() {
+            if progress.has_completed_clue(clue_id) {
+                continue;
+            }
+            let Some(clue) = Storage::get_clue(&env, hunt_id, clue_id) else {
+                continue;
+            };
+            let mut points = Self::points_for_solve(&env, &progress, &clue);
+            if Storage::get_clue_solves(&env, hunt_id, clue_id) == 0 {
+                points = points.saturating_add(clue.first_solve_bonus);
+            }
+            progress.complete_clue(&env, clue_id, points);
+        }
+        let completed = progress.is_completed || Self::is_completion_met(&env, &hunt, &progress);
+        (progress.total_score, completed)
+    }
+
+    /// Sets the contract admin. Can only be called once.
+    ///
+    /// # Errors
+    /// * `AlreadyInitialized` - An admin has already been set
+    pub fn initialize(env: Env, admin: Address) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, 0, || {
+            if Storage::get_admin(&env).is_some() {
+                return Err(HuntErrorCode::AlreadyInitialized);
+            }
+            admin.require_auth();
+            Storage::set_admin(&env, &admin);
+            Ok(())
+        })
+    }
+
+    /// Returns the contract admin, if initialized.
+    pub fn get_admin(env: Env) -> Option<Address> {
+        Storage::get_admin(&env)
+    }
+
+    /// Proposes `new_admin` as the next admin, replacing any earlier proposal. The current
+    /// admin stays in charge until `new_admin` calls `accept_admin`. Admin only.
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, 0, || {
+            Self::require_admin(&env)?;
+            Storage::set_pending_admin(&env, &new_admin);
+            Ok(())
+        })
+    }
+
+    /// Completes a pending admin transfer. Must be authorized by the proposed admin.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - No transfer is pending
+    pub fn accept_admin(env: Env) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, 0, || {
+            let new_admin = Storage::get_pending_admin(&env).ok_or(HuntErrorCode::Unauthorized)?;
+            new_admin.require_auth();
+            Storage::set_admin(&env, &new_admin);
+            Storage::remove_pending_admin(&env);
+            Ok(())
+        })
+    }
+
+    /// Withdraws a pending admin proposal. Admin only.
+    pub fn cancel_admin_proposal(env: Env) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, 0, || {
+            Self::require_admin(&env)?;
+            Storage::remove_pending_admin(&env);
+            Ok(())
+        })
+    }
+
+    /// Returns the proposed admin awaiting acceptance, if any.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        Storage::get_pending_admin(&env)
+    }
+
+    /// Globally pauses the contract. Admin only.
+    pub fn pause(env: Env) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, 0, || {
+            Self::require_admin(&env)?;
+            Storage::set_paused(&env, true);
+            Self::publish_event(
+                &env,
+                EventLevel::Minimal,
+                (Symbol::new(&env, "Paused"),),
+                || true,
+            );
+            Ok(())
+        })
+    }
+
+    /// Lifts a global pause. Admin only.
+    pub fn unpause(env: Env) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, 0, || {
+            Self::require_admin(&env)?;
+            Storage::set_paused(&env, false);
+            Self::publish_event(
+                &env,
+                EventLevel::Minimal,
+                (Symbol::new(&env, "Paused"),),
+                || false,
+            );
+            Ok(())
+        })
+    }
+
+    /// Returns whether the contract is globally paused.
+    pub fn is_paused(env: Env) -> bool {
+        Storage::is_paused(&env)
+    }
+
+    /// Sets how old (since creation) a Completed or Cancelled hunt must be before
+    /// `is_archive_candidate` reports it. Admin only.
+    pub fn set_archive_after_secs(env: Env, secs: u64) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, 0, || {
+            Self::require_admin(&env)?;
+            Storage::set_archive_after_secs(&env, secs);
+            Ok(())
+        })
+    }
+
+    /// Sets the most clues a hunt may have (0 = unlimited). Hunts already over a lowered
+    /// limit keep their clues but cannot add more. Admin only.
+    pub fn set_max_clues_per_hunt(env: Env, max_clues: u32) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, 0, || {
+            Self::require_admin(&env)?;
+            Storage::set_max_clues_per_hunt(&env, max_clues);
+            Ok(())
+        })
+    }
+
+    /// Returns the clue cap per hunt (0 = unlimited), `DEFAULT_MAX_CLUES_PER_HUNT` unless
+    /// the admin changed it.
+    pub fn get_max_clues_per_hunt(env: Env) -> u32 {
+        Storage::get_max_clues_per_hunt(&env).unwrap_or(DEFAULT_MAX_CLUES_PER_HUNT)
+    }
+
+    /// Sets how many events the contract publishes: `None` silences all events, `Minimal`
+    /// keeps hunt-level ones (lifecycle, funding, admin actions) and `Full` adds per-player
+    /// and per-clue events. Admin only.
+    pub fn set_event_level(env: Env, level: EventLevel) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, 0, || {
+            Self::require_admin(&env)?;
+            Storage::set_event_level(&env, &level);
+            Ok(())
+        })
+    }
+
+    /// Returns the event level, `Full` unless the admin changed it.
+    pub fn get_event_level(env: Env) -> EventLevel {
+        Storage::get_event_level(&env).unwrap_or(EventLevel::Full)
+    }
+
+    /// Returns the archive age threshold, `DEFAULT_ARCHIVE_AFTER_SECS` unless the admin
+    /// changed it.
+    pub fn get_archive_after_secs(env: Env) -> u64 {
+        Storage::get_archive_after_secs(&env).unwrap_or(DEFAULT_ARCHIVE_AFTER_SECS)
+    }
+
+    /// Returns the seconds since the hunt was created, or 0 if it does not exist.
+    pub fn get_hunt_age(env: Env, hunt_id: u64) -> u64 {
+        Storage::get_hunt(&env, hunt_id)
+            .map(|hunt| env.ledger().timestamp().saturating_sub(hunt.created_at))
+            .unwrap_or(0)
+    }
+
+    /// Returns true for a Completed or Cancelled hunt older than the archive threshold, so
+    /// off-chain janitors can find stale hunts without scanning their details.
+    pub fn is_archive_candidate(env: Env, hunt_id: u64) -> bool {
+        let Some(hunt) = Storage::get_hunt(&env, hunt_id) else {
+            return false;
+        };
+        let age = env.ledger().timestamp().saturating_sub(hunt.created_at);
+        matches!(hunt.status, HuntStatus::Completed | HuntStatus::Cancelled)
+            && age > Self::get_archive_after_secs(env)
+    }
+
+    /// Stops new players registering for a hunt. Registered players keep playing and
+    /// claiming as normal. Creator only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    pub fn pause_registration(env: Env, hunt_id: u64) -> Result<(), HuntErrorCode> {
+        Self::set_registration_paused(&env, hunt_id, true)
+    }
+
+    /// Lets new players register again after `pause_registration`. Creator only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    pub fn resume_registration(env: Env, hunt_id: u64) -> Result<(), HuntErrorCode> {
+        Self::set_registration_paused(&env, hunt_id, false)
+    }
+
+    /// Returns whether the hunt accepts new players right now: it is playable, not full
+    /// and its registration is not paused. Per-player checks such as prerequisites are
+    /// not considered.
+    pub fn is_registration_open(env: Env, hunt_id: u64) -> bool {
+        let Some(hunt) = Storage::get_hunt(&env, hunt_id) else {
+            return false;
+        };
+        let full = hunt.max_players > 0
+            && Storage::get_player_count(&env, hunt_id) >= hunt.max_players;
+        !full
+            && !Storage::is_paused(&env)
+            && !Storage::is_registration_paused(&env, hunt_id)
+            && Self::require_playable(&hunt, env.ledger().timestamp()).is_ok()
+    }
+
+    /// Returns whether the hunt can pay rewards as far as its `min_players_for_rewards`
+    /// goes: for a closed hunt, whether it had enough players when it closed; otherwise
+    /// whether it has enough now. False if the hunt does not exist.
+    pub fn rewards_active(env: Env, hunt_id: u64) -> bool {
+        Storage::get_hunt(&env, hunt_id).is_some_and(|hunt| Self::meets_min_players(&env, &hunt))
+    }
+
+    /// Returns how much of `player`'s token reward has vested so far, claimed or not
+    /// (see `set_reward_vesting`). 0 if they hold no winner slot.
+    pub fn get_vested_amount(env: Env, hunt_id: u64, player: Address) -> i128 {
+        let Some(hunt) = Storage::get_hunt(&env, hunt_id) else {
+            return 0;
+        };
+        match Storage::get_player_progress(&env, hunt_id, &player) {
+            Some(progress) if progress.winner_slot => {
+                let total = Self::claim_amount(&hunt, &progress);
+                hunt.reward_config.vested_amount(total, env.ledger().timestamp())
+            }
+            _ => 0,
+        }
+    }
+
+    /// Halts reward claims on one hunt, e.g. while suspected fraud is investigated. Play
+    /// carries on as normal. Admin only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    pub fn freeze_hunt_rewards(env: Env, hunt_id: u64) -> Result<(), HuntErrorCode> {
+        Self::set_rewards_frozen(&env, hunt_id, true)
+    }
+
+    /// Lets reward claims on a hunt resume after `freeze_hunt_rewards`. Admin only.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    pub fn unfreeze_hunt_rewards(env: Env, hunt_id: u64) -> Result<(), HuntErrorCode> {
+        Self::set_rewards_frozen(&env, hunt_id, false)
+    }
+
+    /// Returns whether reward claims on a hunt are frozen.
+    pub fn are_rewards_frozen(env: Env, hunt_id: u64) -> bool {
+        Storage::are_rewards_frozen(&env, hunt_id)
+    }
+
+    /// Adds a winner slot for each player who tied for the last one on an `ExpandSlots`
+    /// hunt. The admin escrows one winner's reward per added slot, so every winner is
+    /// still paid the same. Players disqualified or reset since are skipped. Admin only.
+    ///
+    /// # Returns
+    /// The number of slots added
+    ///
+    /// # Errors
+    /// * `NotInitialized` - No admin is set
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not Active
+    pub fn approve_tie_slots(env: Env, hunt_id: u64) -> Result<u32, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let admin = Self::require_admin(&env)?;
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Active {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            let mut added = 0u32;
+            for player in Storage::get_pending_ties(&env, hunt_id).iter() {
+                let Some(mut progress) = Storage::get_player_progress(&env, hunt_id, &player)
+                else {
+                    continue;
+                };
+                if progress.disqualified || !progress.is_completed || progress.winner_slot {
+                    continue;
+                }
+                progress.winner_slot = true;
+                Storage::save_player_progress(&env, &progress);
+                added += 1;
+            }
+            Storage::remove_pending_ties(&env, hunt_id);
+            if added == 0 {
+                return Ok(0);
+            }
+
+            let extra = hunt.reward_config.reward_per_winner() * added as i128;
+            hunt.reward_config.max_winners += added;
+            hunt.reward_config.reserved_winners += added;
+            if extra > 0 {
+                if let Some(token) = hunt.reward_config.reward_token.clone() {
+                    token::Client::new(&env, &token).transfer(
+                        &admin,
+                        &env.current_contract_address(),
+                        &extra,
+                    );
+                    hunt.reward_config.xlm_pool += extra;
+                    let escrow = Storage::get_escrow_balance(&env, hunt_id);
+                    Storage::set_escrow_balance(&env, hunt_id, escrow + extra);
+                    let total_pool = hunt.reward_config.xlm_pool;
+                    Self::publish_event(
+                        &env,
+                        EventLevel::Minimal,
+                        (Symbol::new(&env, "RewardPoolFunded"), hunt_id),
+                        || RewardPoolFundedEvent {
+                            hunt_id,
+                            token,
+                            amount: extra,
+                            total_pool,
+                            event_seq: Storage::next_event_seq(&env, hunt_id),
+                        },
+                    );
+                }
+            }
+            Storage::save_hunt(&env, &hunt);
+            Ok(added)
+        })
+    }
+
+    /// Returns the players on an `ExpandSlots` hunt waiting on `approve_tie_slots`.
+    pub fn get_pending_ties(env: Env, hunt_id: u64) -> Vec<Address> {
+        Storage::get_pending_ties(&env, hunt_id)
+    }
+
+    /// Escrows `amount` of `token` from the creator into the hunt's reward pool.
+    /// The first funding call fixes the hunt's reward token.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is Completed or Cancelled
+    /// * `InvalidAmount` - Amount is not positive
+    /// * `RewardTokenMismatch` - Token differs from the one already escrowed
+    /// * `RewardTooSmall` - The pool would pay each winner less than one base unit
+    /// * `ContractPaused` - Contract is globally paused
+    pub fn fund_reward_pool(
+        env: Env,
+        hunt_id: u64,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_not_paused(&env)?;
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft && hunt.status != HuntStatus::Active {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            if amount <= 0 {
+                return Err(HuntErrorCode::InvalidAmount);
+            }
+            match &hunt.reward_config.reward_token {
+                Some(existing) if *existing != token => {
+                    return Err(HuntErrorCode::from(HuntError::RewardTokenMismatch { hunt_id }));
+                }
+                _ => {}
+            }
+            hunt.reward_config.xlm_pool += amount;
+            if !hunt.reward_config.pays_every_winner() {
+                return Err(HuntErrorCode::from(HuntError::RewardTooSmall { hunt_id }));
+            }
+
+            token::Client::new(&env, &token).transfer(
+                &hunt.creator,
+                &env.current_contract_address(),
+                &amount,
+            );
+
+            Self::fix_reward_token(&env, &mut hunt, &token);
+            Storage::save_hunt(&env, &hunt);
+            let escrow = Storage::get_escrow_balance(&env, hunt_id);
+            Storage::set_escrow_balance(&env, hunt_id, escrow + amount);
+
+            let total_pool = hunt.reward_config.xlm_pool;
+            Self::publish_event(
+                &env,
+                EventLevel::Minimal,
+                (Symbol::new(&env, "RewardPoolFunded"), hunt_id),
+                || RewardPoolFundedEvent {
+                    hunt_id,
+                    token,
+                    amount,
+                    total_pool,
+                    event_seq: Storage::next_event_seq(&env, hunt_id),
+                },
+            );
+            Ok(total_pool)
+        })
+    }
+
+    /// Returns `amount` of the escrowed reward pool to the creator, e.g. after funding too
+    /// much. Creator only, Draft only.
+    ///
+    /// # Returns
+    /// The reward pool remaining after the withdrawal
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft
+    /// * `InvalidAmount` - Amount is not positive
+    /// * `InsufficientRewardPool` - Amount exceeds the funded pool
+    /// * `RewardTooSmall` - The pool left would pay each winner nothing
+    pub fn withdraw_reward_pool(
+        env: Env,
+        hunt_id: u64,
+        amount: i128,
+    ) -> Result<i128, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            if amount <= 0 {
+                return Err(HuntErrorCode::InvalidAmount);
+            }
+            let escrow = Storage::get_escrow_balance(&env, hunt_id);
+            let available = hunt
+                .reward_config
+                .xlm_pool
+                .min(escrow - Storage::get_fees_held(&env, hunt_id));
+            if amount > available {
+                return Err(HuntErrorCode::from(HuntError::InsufficientRewardPool {
+                    required: amount,
+                    available,
+                }));
+            }
+            // A positive pool means the hunt was funded, which fixed the reward token.
+            let token = hunt.reward_config.reward_token.clone().unwrap();
+
+            hunt.reward_config.xlm_pool -= amount;
+            if !hunt.reward_config.pays_every_winner() {
+                return Err(HuntErrorCode::from(HuntError::RewardTooSmall { hunt_id }));
+            }
+            Storage::save_hunt(&env, &hunt);
+            Storage::set_escrow_balance(&env, hunt_id, escrow - amount);
+            token::Client::new(&env, &token).transfer(
+                &env.current_contract_address(),
+                &hunt.creator,
+                &amount,
+            );
+
+            let total_pool = hunt.reward_config.xlm_pool;
+            Self::publish_event(
+                &env,
+                EventLevel::Minimal,
+                (Symbol::new(&env, "RewardPoolWithdrawn"), hunt_id),
+                || RewardPoolWithdrawnEvent {
+                    hunt_id,
+                    token,
+                    amount,
+                    total_pool,
+                    event_seq: Storage::next_event_seq(&env, hunt_id),
+                },
+            );
+            Ok(total_pool)
+        })
+    }
+
+    /// Switches a Draft hunt's reward token to `new_token`, recording its decimals. The
+    /// whole pool escrowed in the old token is returned to the creator and the pool is
+    /// cleared; fund it again in the new token with `fund_reward_pool`. An entry fee is
+    /// priced in the old token, so it must be cleared with `set_entry_fee` first and set
+    /// again in the new token afterwards. Creator only, Draft only.
+    ///
+    /// # Returns
+    /// The amount of the old token refunded
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `InvalidHuntStatus` - Hunt is not in Draft, has a non-zero entry fee, or still
+    ///   holds entry fees in the old token
+    pub fn swap_reward_token(
+        env: Env,
+        hunt_id: u64,
+        new_token: Address,
+    ) -> Result<i128, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            if hunt.status != HuntStatus::Draft
+                || hunt.entry_fee > 0
+                || Storage::get_fees_held(&env, hunt_id) > 0
+            {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+            hunt.creator.require_auth();
+            let escrow = Storage::get_escrow_balance(&env, hunt_id);
+            let refunded = hunt.reward_config.xlm_pool.min(escrow);
+            let old_token = hunt.reward_config.reward_token.replace(new_token.clone());
+            hunt.reward_config.reward_decimals = token::Client::new(&env, &new_token).decimals();
+
+            hunt.reward_config.xlm_pool = 0;
+            Storage::save_hunt(&env, &hunt);
+            Storage::set_escrow_balance(&env, hunt_id, escrow - refunded);
+            if refunded > 0 {
+                if let Some(old_token) = &old_token {
+                    token::Client::new(&env, old_token).transfer(
+                        &env.current_contract_address(),
+                        &hunt.creator,
+                        &refunded,
+                    );
+                }
+            }
+
+            Self::publish_event(
+                &env,
+                EventLevel::Minimal,
+                (Symbol::new(&env, "RewardTokenSwapped"), hunt_id),
+                || RewardTokenSwappedEvent {
+                    hunt_id,
+                    old_token,
+                    new_token,
+                    refunded,
+                    event_seq: Storage::next_event_seq(&env, hunt_id),
+                },
+            );
+            Ok(refunded)
+        })
+    }
+
+    /// Repairs a hunt's `total_clues` and `required_clues` by recounting the clues in its
+    /// clue index, then emits CountersRecomputed. A safety valve for drifted bookkeeping;
+    /// `caller` must be the hunt creator or the contract admin.
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `Unauthorized` - Caller is neither the creator nor the admin
+    pub fn recompute_required_clues(
+        env: Env,
+        hunt_id: u64,
+        caller: Address,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            Self::require_creator_or_admin(&env, &hunt, &caller)?;
+
+            let mut total_clues = 0u32;
+            let mut required_clues = 0u32;
+            for clue_id in Storage::get_clue_ids_for_hunt(&env, hunt_id).iter() {
+                if let Some(clue) = Storage::get_clue(&env, hunt_id, clue_id) {
+                    total_clues += 1;
+                    if clue.is_required {
+                        required_clues += 1;
+                    }
+                }
+            }
+            hunt.total_clues = total_clues;
+            hunt.required_clues = required_clues;
+            Storage::save_hunt(&env, &hunt);
+
+            Self::publish_event(
+                &env,
+                EventLevel::Minimal,
+                (Symbol::new(&env, "CountersRecomputed"), hunt_id),
+                || CountersRecomputedEvent {
+                    hunt_id,
+                    total_clues,
+                    required_clues,
+                    event_seq: Storage::next_event_seq(&env, hunt_id),
+                },
+            );
+            Ok(())
+        })
+    }
+
+    /// Deletes a cancelled hunt's stored data to stop it consuming rent: up to
+    /// `clue_limit` clues and `player_limit` players per call, so a large hunt can be
+    /// cleared over several transactions. Each player's progress goes together with their
+    /// answer commitments, attempt counts, clue view times and signing nonce for the hunt.
+    /// The hunt record itself is kept. Once no clues or players remain the hunt is marked
+    /// purged (see `is_hunt_purged`).
+    /// `caller` must be the hunt creator or the contract admin.
+    ///
+    /// # Returns
+    /// Whether the hunt is now fully purged
+    ///
+    /// # Errors
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `Unauthorized` - Caller is neither the creator nor the admin
+    /// * `InvalidHuntStatus` - Hunt is not Cancelled, or entry fees are still unrefunded
+    pub fn purge_cancelled_hunt(
+        env: Env,
+        hunt_id: u64,
+        caller: Address,
+        clue_limit: u32,
+        player_limit: u32,
+    ) -> Result<bool, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            Self::require_creator_or_admin(&env, &hunt, &caller)?;
+            if hunt.status != HuntStatus::Cancelled || Storage::get_fees_held(&env, hunt_id) > 0 {
+                return Err(HuntErrorCode::InvalidHuntStatus);
+            }
+
+            for clue_id in Storage::get_clue_ids_for_hunt(&env, hunt_id)
+                .iter()
+                .take(clue_limit as usize)
+            {
+                Storage::remove_clue(&env, hunt_id, clue_id);
+            }
+            // Clue IDs are never reused, so this covers clues already purged or removed.
+            let clue_count = Storage::get_clue_counter(&env, hunt_id);
+            for player in Storage::get_player_addresses_for_hunt(&env, hunt_id)
+                .iter()
+                .take(player_limit as usize)
+            {
+                for clue_id in 1..=clue_count {
+                    Storage::remove_commitment(&env, hunt_id, clue_id, &player);
+                    Storage::remove_attempt_state(&env, hunt_id, clue_id, &player);
+                    Storage::remove_clue_viewed_at(&env, hunt_id, clue_id, &player);
+                }
+                Storage::remove_nonce(&env, hunt_id, &player);
+                Storage::remove_player_progress(&env, hunt_id, &player);
+                Storage::remove_hunt_from_player_index(&env, &player, hunt_id);
+            }
+            Storage::remove_leaderboard_cache(&env, hunt_id);
+
+            let purged = Storage::get_clue_ids_for_hunt(&env, hunt_id).is_empty()
+                && Storage::get_player_count(&env, hunt_id) == 0;
+            if purged {
+                Storage::set_hunt_purged(&env, hunt_id);
+            }
+            Ok(purged)
+        })
+    }
+
+    /// Returns whether `purge_cancelled_hunt` has deleted all of a hunt's clues and
+    /// per-player data.
+    pub fn is_hunt_purged(env: Env, hunt_id: u64) -> bool {
+        Storage::is_hunt_purged(&env, hunt_id)
+    }
+
+    /// Recovery path for drifted indexes: overwrites the hunt's clue index (and the
+    /// required clue index derived from it) and its player list with the given
+    /// authoritative sets, after checking every entry exists. Each player's own hunt index
+    /// is brought in line, adding the hunt for listed players and removing it for players
+    /// dropped from the list. Clue order is kept as given.
+    /// Counters are left alone; follow up with `recompute_required_clues`. Admin only.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - No admin has been set
+    /// * `HuntNotFound` - Hunt does not exist
+    /// * `ClueNotFound` - A listed clue has no stored entry
+    /// * `InvalidClueOrder` - A clue is listed twice
+    /// * `PlayerNotRegistered` - A listed player has no stored progress
+    /// * `DuplicateRegistration` - A player is listed twice
+    pub fn rebuild_indexes(
+        env: Env,
+        hunt_id: u64,
+        clue_ids: Vec<u32>,
+        players: Vec<Address>,
+    ) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_admin(&env)?;
+            let hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+
+            let mut required_ids: Vec<u32> = Vec::new(&env);
+            for (index, clue_id) in clue_ids.iter().enumerate() {
+                if clue_ids.first_index_of(clue_id) != Some(index as u32) {
+                    return Err(HuntErrorCode::from(HuntError::InvalidClueOrder { hunt_id }));
+                }
+                let clue = Storage::get_clue_or_error(&env, hunt_id, clue_id)
+                    .map_err(HuntErrorCode::from)?;
+                if clue.is_required {
+                    let slot = required_ids.binary_search(clue_id).unwrap_err();
+                    required_ids.insert(slot, clue_id);
+                }
+            }
+            for (index, player) in players.iter().enumerate() {
+                if players.first_index_of(&player) != Some(index as u32) {
+                    return Err(HuntErrorCode::from(HuntError::DuplicateRegistration { hunt_id }));
+                }
+                Storage::get_player_progress_or_error(&env, hunt_id, &player)
+                    .map_err(HuntErrorCode::from)?;
+                Storage::add_hunt_to_player_index(&env, &player, hunt_id);
+            }
+
+            // Players dropped from the list lose the hunt from their reverse index too.
+            for player in Storage::get_player_addresses_for_hunt(&env, hunt_id).iter() {
+                if players.first_index_of(&player).is_none() {
+                    Storage::remove_hunt_from_player_index(&env, &player, hunt_id);
+                }
+            }
+            Storage::set_clue_ids_for_hunt(&env, hunt_id, &clue_ids);
+            Storage::set_required_clue_ids(&env, hunt_id, &required_ids);
+            Storage::set_player_addresses_for_hunt(&env, hunt_id, &players);
+            Self::refresh_leaderboard_cache(&env, &hunt);
+            Self::publish_event(
+                &env,
+                EventLevel::Minimal,
+                (Symbol::new(&env, "IndexesRebuilt"), hunt_id),
+                || IndexesRebuiltEvent {
+                    hunt_id,
+                    clue_count: clue_ids.len(),
+                    player_count: players.len(),
+                    event_seq: Storage::next_event_seq(&env, hunt_id),
+                },
+            );
+            Ok(())
+        })
+    }
+
+    /// Returns the hunt's reward pool in base units together with the reward token's
+    /// decimals, so front-ends can show whole tokens. Returns `(0, 0)` for a missing hunt;
+    /// decimals stay 0 until a reward token is set.
+    pub fn get_reward_display(env: Env, hunt_id: u64) -> (i128, u32) {
+        match Storage::get_hunt(&env, hunt_id) {
+            Some(hunt) => (hunt.reward_config.xlm_pool, hunt.reward_config.reward_decimals),
+            None => (0, 0),
+        }
+    }
+
+    /// Returns the tokens still escrowed for a hunt, i.e. funding plus entry fees minus
+    /// claims and withdrawals. Unlike `reward_config.xlm_pool`, which is the configured
+    /// total, this drops with every claim. Returns 0 for a missing or unfunded hunt.
+    pub fn get_hunt_pool_balance(env: Env, hunt_id: u64) -> i128 {
+        Storage::get_escrow_balance(&env, hunt_id)
+    }
+
+    /// Recovery path for a broken contract: moves a hunt's entire escrowed pool to `to`
+    /// and zeroes it. Admin only, and only while the contract is globally paused.
+    ///
+    /// # Returns
+    /// The amount withdrawn
+    ///
+    /// # Errors
+    /// * `NotInitialized` - No admin has been set
+    /// * `ContractNotPaused` - Contract is not paused
+    /// * `HuntNotFound` - Hunt does not exist
+    pub fn emergency_withdraw(env: Env, hunt_id: u64, to: Address) -> Result<i128, HuntErrorCode> {
+        Self::with_diagnostics(&env, hunt_id, || {
+            Self::require_admin(&env)?;
+            if !Storage::is_paused(&env) {
+                return Err(HuntErrorCode::ContractNotPaused);
+            }
+            let mut hunt = Storage::get_hunt_or_error(&env, hunt_id).map_err(HuntErrorCode::from)?;
+            let amount = Storage::get_escrow_balance(&env, hunt_id);
+
+            // Zero the pool before the transfer so a re-entrant call sees nothing left.
+            hunt.reward_config.xlm_pool = 0;
+            Storage::save_hunt(&env, &hunt);
+            Storage::set_escrow_balance(&env, hunt_id, 0);
+
+            if amount > 0 {
+                if let Some(reward_token) = &hunt.reward_config.reward_token {
+                    token::Client::new(&env, reward_token).transfer(
+                        &env.current_contract_address(),
+                        &to,
+                        &amount,
+                    );
+                }
+            }
+
+            Self::publish_event(
+                &env,
+                EventLevel::Minimal,
+                (Symbol::new(&env, "EmergencyWithdraw"), hunt_id),
+                || EmergencyWithdrawEvent {
+                    hunt_id,
+                    to,
+                    amount,
+                    event_seq: Storage::next_event_seq(&env, hunt_id),
+                },
+            );
+            Ok(amount)
+        })
+    }
+
+    /// Loads and checks everything an answer submission needs: an active hunt, a
+    /// registered player, and a clue the player has not solved yet.
+    fn load_submission(
+        env: &Env,
+        hunt_id: u64,
+        clue_id: u32,
+        player: &Address,
+    ) -> Result<(Hunt, PlayerProgress, Clue), HuntErrorCode> {
+        let hunt = Storage::get_hunt_or_error(env, hunt_id).map_err(HuntErrorCode::from)?;
+        let now = env.ledger().timestamp();
+        if hunt.status == HuntStatus::Active && !hunt.has_started(now) {
+            return Err(HuntErrorCode::from(HuntError::HuntNotStarted { hunt_id }));
+        }
+        if !hunt.accepts_answers(now) {
+            return Err(HuntErrorCode::from(HuntError::HuntNotActive { hunt_id }));
+        }
+        let progress = Storage::get_player_progress_or_error(env, hunt_id, player)
+            .map_err(HuntErrorCode::from)?;
+        if progress.disqualified {
+            return Err(HuntErrorCode::from(HuntError::Disqualified { hunt_id }));
+        }
+        let clue = Storage::get_clue_or_error(env, hunt_id, clue_id)
+            .map_err(HuntErrorCode::from)?;
+        if progress.has_completed_clue(clue_id) {
+            return Err(HuntErrorCode::from(HuntError::ClueAlreadyCompleted { hunt_id }));
+        }
+        if hunt.ordered {
+            // Every clue ahead of this one in the index must already be solved.
+            for earlier in Storage::get_clue_ids_for_hunt(env, hunt_id).iter() {
+                if earlier == clue_id {
+                    break;
+                }
+                if !progress.has_completed_clue(earlier) {
+                    return Err(HuntErrorCode::from(HuntError::InvalidClueOrder { hunt_id }));
+                }
+            }
+        }
+        Ok((hunt, progress, clue))
+    }
+
+    /// Verifies a plain-text answer for an authorized player and credits the solve. Shared
+    /// by `submit_answer` and `submit_answer_signed`.
+    fn check_answer(
+        env: &Env,
+        hunt_id: u64,
+        clue_id: u32,
+        player: &Address,
+        answer: &String,
+    ) -> Result<u32, HuntErrorCode> {
+        let (hunt, progress, clue) = Self::load_submission(env, hunt_id, clue_id, player)?;
+        let attempts = Self::load_attempts(env, &hunt, clue_id, player)?;
+        let answer_hash = Self::normalize_and_hash_answer(env, &hunt.answer_algo, answer)
+            .map_err(HuntErrorCode::from)?;
+        if !clue.accepts(&Self::salt_answer_hash(env, hunt_id, answer_hash)) {
+            return Self::reject_answer(env, &hunt, clue_id, player, attempts);
+        }
+        let points = Self::points_for_solve(env, &progress, &clue);
+        Ok(Self::record_solve(env, hunt, progress, &clue, points))
+    }
+
+    /// Returns the ed25519 public key of an account (G...) address, read from its XDR:
+    /// the ScVal and ScAddress tags, the key type, then the 32 key bytes.
+    fn account_public_key(env: &Env, address: &Address) -> Result<BytesN<32>, HuntErrorCode> {
+        const ACCOUNT_XDR_PREFIX: [u8; 12] = [0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0];
+        let xdr = address.clone().to_xdr(env);
+        if xdr.len() != 44 || xdr.slice(0..12) != Bytes::from_array(env, &ACCOUNT_XDR_PREFIX) {
+            return Err(HuntErrorCode::Unauthorized);
+        }
+        let mut key = [0u8; 32];
+        xdr.slice(12..44).copy_into_slice(&mut key);
+        Ok(BytesN::from_array(env, &key))
+    }
+
+    /// Returns the player's attempt state for the clue, or `None` if the hunt has no
+    /// attempt limit. A lockout that has run out starts a fresh round of attempts.
+    fn load_attempts(
+        env: &Env,
+        hunt: &Hunt,
+        clue_id: u32,
+        player: &Address,
+    ) -> Result<Option<AttemptState>, HuntErrorCode> {
+        if hunt.max_attempts == 0 {
+            return Ok(None);
+        }
+        let now = env.ledger().timestamp();
+        let mut state = Storage::get_attempt_state(env, hunt.hunt_id, clue_id, player);
+        if state.is_locked(hunt.max_attempts, now) {
+            return Err(HuntErrorCode::from(HuntError::TooManyAttempts {
+                hunt_id: hunt.hunt_id,
+                clue_id,
+                retry_at: state.next_allowed_at,
+            }));
+        }
+        state.refresh(hunt.max_attempts, now);
+        Ok(Some(state))
+    }
+
+    /// Handles a wrong answer. Without an attempt limit it is an `InvalidAnswer` error;
+    /// with one, the attempt is recorded (starting a cooldown if it hits the limit) and
+    /// the call succeeds with 0 points so the record is kept.
+    ///
+    /// Either way an `AnswerRejected` event is published, though like any event of a
+    /// failed call it only reaches the ledger in hunts with an attempt limit.
+    fn reject_answer(
+        env: &Env,
+        hunt: &Hunt,
+        clue_id: u32,
+        player: &Address,
+        attempts: Option<AttemptState>,
+    ) -> Result<u32, HuntErrorCode> {
+        let hunt_id = hunt.hunt_id;
+        Self::publish_event(
+            env,
+            EventLevel::Full,
+            (Symbol::new(env, "AnswerRejected"), hunt_id, clue_id),
+            || AnswerRejectedEvent {
+                hunt_id,
+                clue_id,
+                player: player.clone(),
+                event_seq: Storage::next_event_seq(env, hunt_id),
+            },
+        );
+        let Some(mut state) = attempts else {
+            return Err(HuntErrorCode::InvalidAnswer);
+        };
+        let now = env.ledger().timestamp();
+        state.record_wrong(hunt.max_attempts, hunt.attempt_cooldown_secs, now);
+        Storage::save_attempt_state(env, hunt.hunt_id, clue_id, player, &state);
+        Ok(0)
+    }
+
+    /// Credits a verified solve to the player (adding the clue's first-solve bonus if no one
+    /// solved it before), marks the hunt completed for them if the completion policy is
+    /// now met, and emits the matching events. Returns the points awarded.
+    fn record_solve(
+        env: &Env,
+        mut hunt: Hunt,
+        mut progress: PlayerProgress,
+        clue: &Clue,
+        mut points: u32,
+    ) -> u32 {
+        let hunt_id = hunt.hunt_id;
+        if hunt.max_attempts > 0 {
+            Storage::remove_attempt_state(env, hunt_id, clue.clue_id, &progress.player);
+        }
+        // The count goes up before scoring so only the very first solver sees 1.
+        if Storage::increment_clue_solves(env, hunt_id, clue.clue_id) == 1 {
+            points = points.saturating_add(clue.first_solve_bonus);
+        }
+        progress.complete_clue(env, clue.clue_id, points);
+        progress.last_activity_at = env.ledger().timestamp();
+        Self::publish_event(
+            env,
+            EventLevel::Full,
+            (Symbol::new(env, "ClueCompleted"), hunt_id, clue.clue_id),
+            || ClueCompletedEvent {
+                hunt_id,
+                player: progress.player.clone(),
+                clue_id: clue.clue_id,
+                points_earned: points,
+                event_seq: Storage::next_event_seq(env, hunt_id),
+            },
+        );
+
+        if !progress.is_completed && Self::is_completion_met(env, &hunt, &progress) {
+            let now = env.ledger().timestamp();
+            progress.is_completed = true;
+            progress.completed_at = now;
+            Storage::adjust_completions(env, hunt_id, 1);
+            // Reserve a winner slot now so a slow claimer keeps their place. Raffle
+            // hunts hand out slots in `draw_winners` instead.
+            if hunt.reward_mode == RewardMode::FirstCome {
+                Self::reserve_winner_slot(env, &mut hunt, &mut progress, now);
+            }
+            Self::publish_event(
+                env,
+                EventLevel::Full,
+                (Symbol::new(env, "HuntCompleted"), hunt_id),
+                || HuntCompletedEvent {
+                    hunt_id,
+                    player: progress.player.clone(),
+                    total_score: progress.total_score,
+                    completion_time: now,
+                    event_seq: Storage::next_event_seq(env, hunt_id),
+                },
+            );
+        }
+
+        Storage::save_player_progress(env, &progress);
+        Self::bump_leaderboard_cache(env, &hunt, &progress);
+        points
+    }
+
+    /// Gives a player who just completed a winner slot if one is left. Otherwise, if they
+    /// completed in the same ledger as the player who took the last slot, applies the
+    /// hunt's tie policy.
+    fn reserve_winner_slot(env: &Env, hunt: &mut Hunt, progress: &mut PlayerProgress, now: u64) {
+        let has_slot = hunt.has_unreserved_winner_slots();
+        let rewards = &mut hunt.reward_config;
+        if has_slot {
+            rewards.reserved_winners += 1;
+            progress.winner_slot = true;
+            // A shared last slot keeps its cutoff until every sharer has been released.
+            if rewards.reserved_winners == rewards.max_winners && rewards.tie_sharers == 0 {
+                rewards.cutoff_at = now;
+                if hunt.tie_policy == TiePolicy::SplitEqually {
+                    progress.shared_slot = true;
+                    rewards.tie_sharers = 1;
+                }
+            }
+            Storage::save_hunt(env, hunt);
+            return;
+        }
+        if rewards.max_winners == 0 || rewards.cutoff_at != now {
+            return;
+        }
+        match hunt.tie_policy {
+            TiePolicy::FirstCome => {}
+            TiePolicy::SplitEqually => {
+                if rewards.tie_sharers > 0 {
+                    rewards.tie_sharers += 1;
+                    progress.winner_slot = true;
+                    progress.shared_slot = true;
+                    Storage::save_hunt(env, hunt);
+                }
+            }
+            TiePolicy::ExpandSlots => Storage::add_pending_tie(env, hunt.hunt_id, &progress.player),
+        }
+    }
+
+    /// Takes back an unclaimed winner slot. A shared last slot is only freed once its
+    /// last sharer is released; until then the remaining sharers split it.
+    fn release_winner_slot(env: &Env, hunt: &mut Hunt, progress: &mut PlayerProgress) {
+        // Vested payouts already taken stay with the player; the slot no longer owes them.
+        let vested = Storage::get_vested_claimed(env, hunt.hunt_id, &progress.player);
+        if vested > 0 {
+            Storage::set_vested_claimed(env, hunt.hunt_id, &progress.player, 0);
+            Storage::adjust_vested_paid(env, hunt.hunt_id, -vested);
+        }
+        let rewards = &mut hunt.reward_config;
+        if progress.shared_slot {
+            rewards.tie_sharers -= 1;
+            if rewards.tie_sharers == 0 {
+                rewards.reserved_winners -= 1;
+                rewards.tie_claims = 0;
+            } else if rewards.tie_claims == rewards.tie_sharers {
+                rewards.claimed_count += 1;
+            }
+        } else {
+            rewards.reserved_winners -= 1;
+        }
+        progress.winner_slot = false;
+        progress.shared_slot = false;
+    }
+
+    /// Fixes `token` as the hunt's reward token if none is set yet, recording its decimals
+    /// for `get_reward_display`. Callers check that any token already set matches.
+    fn fix_reward_token(env: &Env, hunt: &mut Hunt, token: &Address) {
+        if hunt.reward_config.reward_token.is_none() {
+            hunt.reward_config.reward_decimals = token::Client::new(env, token).decimals();
+            hunt.reward_config.reward_token = Some(token.clone());
+        }
+    }
+
+    /// Token amount a claim by `progress` pays: one winner's share, split between the
+    /// players sharing the last slot if it is shared.
+    fn claim_amount(hunt: &Hunt, progress: &PlayerProgress) -> i128 {
+        let amount = hunt.reward_config.reward_per_winner();
+        if progress.shared_slot && hunt.reward_config.tie_sharers > 0 {
+            amount / hunt.reward_config.tie_sharers as i128
+        } else {
+            amount
+        }
+    }
+
+    /// Token amount `progress`'s player can claim now: what has vested of their reward,
+    /// less what they already took.
+    fn vested_due(env: &Env, hunt: &Hunt, progress: &PlayerProgress) -> i128 {
+        let total = Self::claim_amount(hunt, progress);
+        let vested = hunt.reward_config.vested_amount(total, env.ledger().timestamp());
+        vested - Storage::get_vested_claimed(env, hunt.hunt_id, &progress.player)
+    }
+
+    /// Returns whether `progress` meets the hunt's completion policy.
+    fn is_completion_met(env: &Env, hunt: &Hunt, progress: &PlayerProgress) -> bool {
+        let required_completed = match hunt.completion_policy {
+            CompletionPolicy::RequiredCount => Self::count_required_completed(env, progress),
+            CompletionPolicy::RequiredPercent(_)
+            | CompletionPolicy::AllClues
+            | CompletionPolicy::Participation => 0,
+        };
+        hunt.is_completion_met(progress.completed_clues.len(), required_completed)
+    }
+
+    /// Points for solving `clue` now: base points, plus the time bonus when solved within
+    /// its limit. The clock starts at the player's first `view_clue`, else registration.
+    fn points_for_solve(env: &Env, progress: &PlayerProgress, clue: &Clue) -> u32 {
+        if clue.time_limit_secs == 0 {
+            return clue.points;
+        }
+        let started =
+            Storage::get_clue_viewed_at(env, progress.hunt_id, clue.clue_id, &progress.player)
+                .unwrap_or(progress.started_at);
+        let elapsed = env.ledger().timestamp().saturating_sub(started);
+        if elapsed <= clue.time_limit_secs as u64 {
+            clue.points.saturating_add(clue.time_bonus)
+        } else {
+            clue.points
+        }
+    }
+
+    /// Counts how many of the player's solved clues are marked required.
+    fn count_required_completed(env: &Env, progress: &PlayerProgress) -> u32 {
+        let mut count = 0;
+        for clue_id in progress.completed_clues.iter() {
+            if let Some(clue) = Storage::get_clue(env, progress.hunt_id, clue_id) {
+                if clue.is_required {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// Returns `TooManyClues` if adding `adding` clues to a hunt that has `existing` would
+    /// exceed the clue cap.
+    fn require_clue_room(
+        env: &Env,
+        hunt_id: u64,
+        existing: u32,
+        adding: u32,
+    ) -> Result<(), HuntErrorCode> {
+        let limit = Self::get_max_clues_per_hunt(env.clone());
+        if limit > 0 && existing.saturating_add(adding) > limit {
+            return Err(HuntErrorCode::from(HuntError::TooManyClues { hunt_id, limit }));
+        }
+        Ok(())
+    }
+
+    /// Rejects a new answer whose normalized length is below the hunt's minimum.
+    fn check_answer_length(env: &Env, hunt: &Hunt, answer: &String) -> Result<(), HuntErrorCode> {
+        if hunt.min_answer_length == 0 {
+            return Ok(());
+        }
+        let normalized = Self::normalize_answer(env, answer).map_err(HuntErrorCode::from)?;
+        if normalized.len() < hunt.min_answer_length {
+            return Err(HuntErrorCode::InvalidAnswer);
+        }
+        Ok(())
+    }
+
+    /// Checks a clue's question and answer, returning the answer hash to store.
+    fn validate_clue(
+        env: &Env,
+        algo: &HashAlgo,
+        question: &String,
+        answer: &String,
+    ) -> Result<BytesN<32>, HuntErrorCode> {
+        let qlen = question.len();
+        if qlen == 0 || qlen > MAX_QUESTION_LENGTH {
+            return Err(HuntErrorCode::InvalidQuestion);
+        }
+        Self::normalize_and_hash_answer(env, algo, answer).map_err(HuntErrorCode::from)
+    }
+
+    /// Decodes a 64-character hex string (either case) into a 32-byte hash.
+    fn parse_hash(env: &Env, hex: &String) -> Option<BytesN<32>> {
+        if hex.len() != 64 {
+            return None;
+        }
+        let mut text = [0u8; 64];
+        hex.copy_into_slice(&mut text);
+        let nibble = |c: u8| match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            _ => None,
+        };
+        let mut hash = [0u8; 32];
+        for (byte, pair) in hash.iter_mut().zip(text.chunks(2)) {
+            *byte = (nibble(pair[0])? << 4) | nibble(pair[1])?;
+        }
+        Some(BytesN::from_array(env, &hash))
+    }
+
+    /// Binds an answer hash to the hunt's salt, if `rotate_salt` has set one. Hunts without
+    /// a salt keep the plain answer hash.
+    fn salt_answer_hash(env: &Env, hunt_id: u64, answer_hash: BytesN<32>) -> BytesN<32> {
+        match Storage::get_answer_salt(env, hunt_id) {
+            Some(salt) => {
+                let mut preimage = Bytes::from_array(env, &salt.to_array());
+                preimage.extend_from_array(&answer_hash.to_array());
+                env.crypto().sha256(&preimage).to_bytes()
+            }
+            None => answer_hash,
+        }
+    }
+
+    /// Saves a validated clue, updates the hunt's clue counts (the caller saves the hunt)
+    /// and emits ClueAdded.
+    fn store_clue(
+        env: &Env,
+        hunt: &mut Hunt,
+        question: String,
+        answer_hash: BytesN<32>,
+        points: u32,
+        is_required: bool,
+    ) -> u32 {
+        let hunt_id = hunt.hunt_id;
+        let clue_id = Storage::next_clue_id(env, hunt_id);
+        let clue = Clue {
+            clue_id,
+            question: question.clone(),
+            answer_hash,
+            points,
+            is_required,
+            time_limit_secs: 0,
+            time_bonus: 0,
+            first_solve_bonus: 0,
+            alt_answer_hashes: Vec::new(env),
+            has_location: false,
+            location: Location::default(),
+        };
+        Storage::save_clue(env, hunt_id, &clue);
+        hunt.total_clues += 1;
+        if is_required {
+            hunt.required_clues += 1;
+        }
+        Self::publish_event(
+            env,
+            EventLevel::Full,
+            (Symbol::new(env, "ClueAdded"), hunt_id, clue_id),
+            || ClueAddedEvent {
+                hunt_id,
+                clue_id,
+                creator: hunt.creator.clone(),
+                question,
+                points,
+                is_required,
+                event_seq: Storage::next_event_seq(env, hunt_id),
+            },
+        );
+        clue_id
+    }
+
+    /// Copies a string's raw bytes (up to MAX_ANSWER_LENGTH) into a `Bytes`.
+    fn string_to_bytes(env: &Env, value: &String) -> Result<Bytes, HuntError> {
+        let n = value.len();
+        if n == 0 || n > MAX_ANSWER_LENGTH {
+            return Err(HuntError::InvalidAnswer);
+        }
+        let mut buf = [0u8; MAX_ANSWER_LENGTH as usize];
+        value.copy_into_slice(&mut buf[..n as usize]);
+        Ok(Bytes::from_slice(env, &buf[..n as usize]))
+    }
+
+    /// Moves a hunt's entry fee from `player` into escrow and records it as refundable.
+    fn collect_entry_fee(env: &Env, mut hunt: Hunt, player: &Address) {
+        let hunt_id = hunt.hunt_id;
+        let fee = hunt.entry_fee;
+        if let Some(reward_token) = &hunt.reward_config.reward_token {
+            token::Client::new(env, reward_token).transfer(
+                player,
+                &env.current_contract_address(),
+                &fee,
+            );
+        }
+        hunt.reward_config.xlm_pool += fee;
+        Storage::save_hunt(env, &hunt);
+        Storage::set_escrow_balance(env, hunt_id, Storage::get_escrow_balance(env, hunt_id) + fee);
+        Storage::set_fees_held(env, hunt_id, Storage::get_fees_held(env, hunt_id) + fee);
+        Storage::set_entry_fee_paid(env, hunt_id, player, fee);
+    }
+
+    /// Hashes the hunt's current clue set as described in `get_clue_set_commitment`.
+    fn commit_clue_set(env: &Env, hunt_id: u64) -> BytesN<32> {
+        let mut preimage = Bytes::new(env);
+        for clue in Storage::list_clues_for_hunt(env, hunt_id).iter() {
+            preimage.extend_from_array(&clue.clue_id.to_be_bytes());
+            preimage.extend_from_array(&clue.answer_hash.to_array());
+            for alt in clue.alt_answer_hashes.iter() {
+                preimage.extend_from_array(&alt.to_array());
+            }
+        }
+        env.crypto().sha256(&preimage).to_bytes()
+    }
+
+    /// Moves an Active hunt to Completed for `close_hunt` and `sweep_expired_hunts`,
+    /// returning to the creator the escrow not owed to slot holders. Returns that amount.
+    fn finalize_hunt(env: &Env, mut hunt: Hunt) -> i128 {
+        let hunt_id = hunt.hunt_id;
+        let rewards = &hunt.reward_config;
+        let unclaimed = rewards.reserved_winners.saturating_sub(rewards.claimed_count);
+        // Short of the player minimum, no slot is honoured and everything is reclaimed.
+        let owed = if Self::meets_min_players(env, &hunt) {
+            rewards.reward_per_winner() * unclaimed as i128 - Storage::get_vested_paid(env, hunt_id)
+        } else {
+            Storage::set_rewards_void(env, hunt_id);
+            0
+        };
+        let escrow = Storage::get_escrow_balance(env, hunt_id);
+        let reclaimed = (escrow - owed).max(0);
+
+        let closed_at = env.ledger().timestamp();
+        hunt.status = HuntStatus::Completed;
+        hunt.closed_at = closed_at;
+        Storage::save_hunt(env, &hunt);
+        Storage::set_escrow_balance(env, hunt_id, escrow - reclaimed);
+        Storage::set_fees_held(env, hunt_id, 0);
+        Storage::adjust_active_hunts(env, -1);
+
+        if reclaimed > 0 {
+            if let Some(reward_token) = &hunt.reward_config.reward_token {
+                token::Client::new(env, reward_token).transfer(
+                    &env.current_contract_address(),
+                    &hunt.creator,
+                    &reclaimed,
+                );
+            }
+        }
+        Self::record_status_change(env, hunt_id, HuntStatus::Active, HuntStatus::Completed);
+        Self::publish_event(
+            env,
+            EventLevel::Minimal,
+            (Symbol::new(env, "HuntClosed"), hunt_id),
+            || HuntClosedEvent {
+                hunt_id,
+                closed_at,
+                reclaimed,
+                event_seq: Storage::next_event_seq(env, hunt_id),
+            },
+        );
+        reclaimed
+    }
+
+    /// Whether the hunt has the players its `min_players_for_rewards` asks for. A closed
+    /// hunt keeps the answer it had at close time.
+    fn meets_min_players(env: &Env, hunt: &Hunt) -> bool {
+        if hunt.status == HuntStatus::Completed {
+            return !Storage::are_rewards_void(env, hunt.hunt_id);
+        }
+        Storage::get_player_count(env, hunt.hunt_id) >= hunt.min_players_for_rewards
+    }
+
+    /// Shared body of `deactivate_hunt` and `abort_activation`: returns an Active hunt
+    /// nobody has joined to Draft, undoing what `activate_hunt` stored, and returns the
+    /// old `activated_at`. The caller moves the status index and publishes the event.
+    fn return_to_draft(env: &Env, hunt_id: u64) -> Result<u64, HuntErrorCode> {
+        let mut hunt = Storage::get_hunt_or_error(env, hunt_id).map_err(HuntErrorCode::from)?;
+        if hunt.status != HuntStatus::Active || Storage::get_player_count(env, hunt_id) > 0 {
+            return Err(HuntErrorCode::InvalidHuntStatus);
+        }
+        hunt.creator.require_auth();
+        let activated_at = hunt.activated_at;
+        hunt.status = HuntStatus::Draft;
+        hunt.activated_at = 0;
+        Storage::save_hunt(env, &hunt);
+        Storage::remove_clue_set_commitment(env, hunt_id);
+        Storage::adjust_active_hunts(env, -1);
+        Ok(activated_at)
+    }
+
+    /// Moves the hunt to its new status bucket and publishes HuntStatusChanged. Every status
+    /// transition goes through here so the status index stays consistent, except
+    /// `abort_activation`, which moves the index itself and publishes ActivationAborted.
+    fn record_status_change(
+        env: &Env,
+        hunt_id: u64,
+        old_status: HuntStatus,
+        new_status: HuntStatus,
+    ) {
+        Storage::move_hunt_status(env, hunt_id, Some(old_status.clone()), new_status.clone());
+        Self::publish_event(
+            env,
+            EventLevel::Minimal,
+            (Symbol::new(env, "HuntStatusChanged"), hunt_id),
+            || HuntStatusChangedEvent {
+                hunt_id,
+                old_status,
+                new_status,
+                event_seq: Storage::next_event_seq(env, hunt_id),
+            },
+        );
+    }
+
+    /// Publishes an event if the configured event level includes `level`. The payload is
+    /// built only when the event is published, so suppressed events use no `event_seq`.
+    fn publish_event<T, D>(env: &Env, level: EventLevel, topics: T, data: impl FnOnce() -> D)
+    where
+        T: Topics,
+        D: IntoVal<Env, Val>,
+    {
+        let enabled = match Self::get_event_level(env.clone()) {
+            EventLevel::None => false,
+            EventLevel::Minimal => level == EventLevel::Minimal,
+            EventLevel::Full => true,
+        };
+        if enabled {
+            env.events().publish(topics, data());
+        }
+    }
+
+    /// Returns `items[start..start + limit]`, clamped to the vector's bounds.
+    fn paginate<T>(env: &Env, items: Vec<T>, start: u32, limit: u32) -> Vec<T>
+    where
+        T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+    {
+        let end = start.saturating_add(limit).min(items.len());
+        if start >= end {
+            return Vec::new(env);
+        }
+        items.slice(start..end)
+    }
+
+    /// Runs a mutating entry point and, when the `error-events` feature is enabled,
+    /// publishes an `ErrorEvent` if it fails. `context` is the hunt ID where one applies.
+    ///
+    /// The event is only visible in simulation and diagnostic traces: it is published by
+    /// the failing invocation, so the host rolls it back with everything else and it
+    /// never reaches the ledger's event stream. The Cargo feature is its only switch; the
+    /// event level does not apply.
+    fn with_diagnostics<T>(
+        env: &Env,
+        context: u64,
+        f: impl FnOnce() -> Result<T, HuntErrorCode>,
+    ) -> Result<T, HuntErrorCode> {
+        let result = f();
+        #[cfg(feature = "error-events")]
+        if let Err(code) = &result {
+            let code = *code as u32;
+            env.events().publish(
+                (Symbol::new(env, "Error"), code),
+                crate::types::ErrorEvent { code, context },
+            );
+        }
+        #[cfg(not(feature = "error-events"))]
+        let _ = (env, context);
+        result
+    }
+
+    /// Requires the hunt to be open for play at `now`: Active, past its start time and
+    /// before its end time.
+    fn require_playable(hunt: &Hunt, now: u64) -> Result<(), HuntErrorCode> {
+        let hunt_id = hunt.hunt_id;
+        if hunt.status == HuntStatus::Active && !hunt.has_started(now) {
+            return Err(HuntErrorCode::from(HuntError::HuntNotStarted { hunt_id }));
+        }
+        if !hunt.is_active(now) {
+            return Err(HuntErrorCode::from(HuntError::HuntNotActive { hunt_id }));
+        }
+        Ok(())
+    }
+
+    /// Sum of the squared points of every clue the player solved. A `u32` squared always
+    /// fits in `u64`, but the sum saturates rather than overflowing for high-value clues.
+    fn difficulty_score(env: &Env, hunt_id: u64, progress: &PlayerProgress) -> u64 {
+        let mut total = 0u64;
+        for clue_id in progress.completed_clues.iter() {
+            if let Some(clue) = Storage::get_clue(env, hunt_id, clue_id) {
+                total = total.saturating_add((clue.points as u64) * (clue.points as u64));
+            }
+        }
+        total
+    }
+
+    /// Ranks every player of the hunt; see `get_leaderboard`.
+    fn compute_leaderboard(env: &Env, hunt_id: u64) -> Vec<LeaderboardEntry> {
+        let mut ranked: Vec<LeaderboardEntry> = Vec::new(env);
+        for progress in Storage::get_hunt_players(env, hunt_id).iter() {
+            let entry = Self::leaderboard_entry(env, &progress);
+            let mut pos = ranked.len();
+            while pos > 0 && Self::ranks_before(&entry, &ranked.get_unchecked(pos - 1)) {
+                pos -= 1;
+            }
+            ranked.insert(pos, entry);
+        }
+        ranked
+    }
+
+    fn leaderboard_entry(env: &Env, progress: &PlayerProgress) -> LeaderboardEntry {
+        LeaderboardEntry {
+            difficulty_score: Self::difficulty_score(env, progress.hunt_id, progress),
+            player: progress.player.clone(),
+            total_score: progress.total_score,
+            completed_at: progress.completed_at,
+        }
+    }
+
+    /// Replaces the hunt's cached leaderboard with a fresh top `leaderboard_cache_size`,
+    /// or drops it if the hunt keeps none. Returns the number of entries cached.
+    fn refresh_leaderboard_cache(env: &Env, hunt: &Hunt) -> u32 {
+        if hunt.leaderboard_cache_size == 0 {
+            Storage::remove_leaderboard_cache(env, hunt.hunt_id);
+            return 0;
+        }
+        let ranked = Self::compute_leaderboard(env, hunt.hunt_id);
+        let top = Self::paginate(env, ranked, 0, hunt.leaderboard_cache_size);
+        Storage::set_leaderboard_cache(env, hunt.hunt_id, &top);
+        top.len()
+    }
+
+    /// Moves `progress`'s player to their new place in the hunt's cached leaderboard.
+    /// Only valid when their rank cannot have dropped (a registration or a solve); any
+    /// other change to progress must call `refresh_leaderboard_cache` instead.
+    fn bump_leaderboard_cache(env: &Env, hunt: &Hunt, progress: &PlayerProgress) {
+        let size = hunt.leaderboard_cache_size;
+        if size == 0 {
+            return;
+        }
+        let mut cache = Storage::get_leaderboard_cache(env, hunt.hunt_id);
+        if let Some(index) = cache.iter().position(|entry| entry.player == progress.player) {
+            cache.remove(index as u32);
+        }
+        let entry = Self::leaderboard_entry(env, progress);
+        let mut pos = cache.len();
+        while pos > 0 && Self::ranks_before(&entry, &cache.get_unchecked(pos - 1)) {
+            pos -= 1;
+        }
+        if pos < size {
+            cache.insert(pos, entry);
+            while cache.len() > size {
+                cache.pop_back();
+            }
+        }
+        Storage::set_leaderboard_cache(env, hunt.hunt_id, &cache);
+    }
+
+    /// Leaderboard ordering; see `get_leaderboard`.
+    fn ranks_before(a: &LeaderboardEntry, b: &LeaderboardEntry) -> bool {
+        if a.total_score != b.total_score {
+            return a.total_score > b.total_score;
+        }
+        if a.difficulty_score != b.difficulty_score {
+            return a.difficulty_score > b.difficulty_score;
+        }
+        let finish = |e: &LeaderboardEntry| if e.completed_at == 0 { u64::MAX } else { e.completed_at };
+        finish(a) < finish(b)
+    }
+
+    /// Requires `editor` to be the hunt creator or a collaborator, and to authorize.
+    fn require_editor(env: &Env, hunt: &Hunt, editor: &Address) -> Result<(), HuntErrorCode> {
+        if *editor == hunt.creator {
+            hunt.creator.require_auth();
+        } else if Storage::is_collaborator(env, hunt.hunt_id, editor) {
+            editor.require_auth();
+        } else {
+            return Err(HuntErrorCode::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Loads a clue of a Draft hunt for editing by the creator or a collaborator.
+    fn load_clue_for_edit(
+        env: &Env,
+        hunt_id: u64,
+        editor: &Address,
+        clue_id: u32,
+    ) -> Result<(Hunt, Clue), HuntErrorCode> {
+        let hunt = Storage::get_hunt_or_error(env, hunt_id).map_err(HuntErrorCode::from)?;
+        if hunt.status != HuntStatus::Draft {
+            return Err(HuntErrorCode::InvalidHuntStatus);
+        }
+        Self::require_editor(env, &hunt, editor)?;
+        let clue = Storage::get_clue_or_error(env, hunt_id, clue_id).map_err(HuntErrorCode::from)?;
+        Ok((hunt, clue))
+    }
+
+    /// Loads the hunt and progress for a reward claim, checking every eligibility rule of
+    /// `claim_reward` except pausing and auth.
+    fn load_claim(
+        env: &Env,
+        hunt_id: u64,
+        player: &Address,
+    ) -> Result<(Hunt, PlayerProgress), HuntErrorCode> {
+        let hunt = Storage::get_hunt_or_error(env, hunt_id).map_err(HuntErrorCode::from)?;
+        if hunt.status != HuntStatus::Active && hunt.status != HuntStatus::Completed {
+            return Err(HuntErrorCode::InvalidHuntStatus);
+        }
+        if Storage::are_rewards_frozen(env, hunt_id) {
+            return Err(HuntErrorCode::from(HuntError::RewardsFrozen { hunt_id }));
+        }
+        if !Self::meets_min_players(env, &hunt) {
+            return Err(HuntErrorCode::from(HuntError::TooFewPlayers {
+                hunt_id,
+                required: hunt.min_players_for_rewards,
+            }));
+        }
+        let progress = Storage::get_player_progress_or_error(env, hunt_id, player)
+            .map_err(HuntErrorCode::from)?;
+        if progress.disqualified {
+            return Err(HuntErrorCode::from(HuntError::Disqualified { hunt_id }));
+        }
+        if !progress.is_completed {
+            return Err(HuntErrorCode::from(HuntError::HuntNotCompleted { hunt_id }));
+        }
+        if progress.reward_claimed {
+            return Err(HuntErrorCode::from(HuntError::RewardAlreadyClaimed { hunt_id }));
+        }
+        if !progress.winner_slot || !hunt.has_rewards_available() {
+            return Err(HuntErrorCode::InsufficientRewardPool);
+        }
+        // Shares are only known once no one else can complete in the cutoff ledger.
+        if progress.shared_slot && env.ledger().timestamp() <= hunt.reward_config.cutoff_at {
+            return Err(HuntErrorCode::from(HuntError::TieUnresolved { hunt_id }));
+        }
+        let amount = Self::vested_due(env, &hunt, &progress);
+        if amount == 0 && !hunt.reward_config.is_fully_vested(env.ledger().timestamp()) {
+            return Err(HuntErrorCode::from(HuntError::NothingVested { hunt_id }));
+        }
+        let escrow = Storage::get_escrow_balance(env, hunt_id);
+        if amount > escrow {
+            return Err(HuntErrorCode::from(HuntError::InsufficientRewardPool {
+                required: amount,
+                available: escrow,
+            }));
+        }
+        Ok((hunt, progress))
+    }
+
+    /// Pays out a claim already checked by `load_claim`: records it, then transfers the
+    /// vested token reward and mints any NFT to `recipient`. Until the reward is fully
+    /// vested the claim is only partial: the slot stays unclaimed and no NFT is minted.
+    /// Returns the token amount paid.
+    fn pay_claim(
+        env: &Env,
+        mut hunt: Hunt,
+        mut progress: PlayerProgress,
+        recipient: &Address,
+    ) -> i128 {
+        let hunt_id = hunt.hunt_id;
+        let amount = Self::vested_due(env, &hunt, &progress);
+        let vested_claimed = Storage::get_vested_claimed(env, hunt_id, &progress.player);
+        let fully_vested = hunt.reward_config.is_fully_vested(env.ledger().timestamp());
+        let escrow = Storage::get_escrow_balance(env, hunt_id);
+        let winner_index = hunt.reward_config.claimed_count + 1;
+
+        // Effects before the token transfer.
+        if fully_vested {
+            progress.reward_claimed = true;
+            Storage::save_player_progress(env, &progress);
+            // A shared slot counts as claimed once every sharer has taken their share.
+            let rewards = &mut hunt.reward_config;
+            if progress.shared_slot {
+                rewards.tie_claims += 1;
+                if rewards.tie_claims == rewards.tie_sharers {
+                    rewards.claimed_count += 1;
+                }
+            } else {
+                rewards.claimed_count += 1;
+            }
+            Storage::save_hunt(env, &hunt);
+            if vested_claimed > 0 {
+                Storage::set_vested_claimed(env, hunt_id, &progress.player, 0);
+                Storage::adjust_vested_paid(env, hunt_id, -vested_claimed);
+            }
+        } else {
+            Storage::set_vested_claimed(env, hunt_id, &progress.player, vested_claimed + amount);
+            Storage::adjust_vested_paid(env, hunt_id, amount);
+        }
+        Storage::set_escrow_balance(env, hunt_id, escrow - amount);
+        Storage::add_rewards_distributed(env, amount);
+        Storage::add_hunt_rewards_distributed(env, hunt_id, amount);
+        Storage::add_distribution(env, hunt_id, &progress.player, amount);
+
+        if amount > 0 {
+            if let Some(reward_token) = &hunt.reward_config.reward_token {
+                token::Client::new(env, reward_token).transfer(
+                    &env.current_contract_address(),
+                    recipient,
+                    &amount,
+                );
+            }
+        }
+        // Any NFT reward is minted to the same recipient.
+        let mut nft_awarded = false;
+        if fully_vested && hunt.reward_config.nft_enabled {
+            if let Some(nft_contract) = &hunt.reward_config.nft_contract {
+                NftRewardClient::new(env, nft_contract).mint_reward(
+                    recipient,
+                    &hunt_id,
+                    &winner_index,
+                );
+                nft_awarded = true;
+            }
+        }
+
+        Self::publish_event(
+            env,
+            EventLevel::Full,
+            (Symbol::new(env, "RewardClaimed"), hunt_id),
+            || RewardClaimedEvent {
+                hunt_id,
+                player: progress.player.clone(),
+                payout_address: recipient.clone(),
+                xlm_amount: amount,
+                nft_awarded,
+                event_seq: Storage::next_event_seq(env, hunt_id),
+            },
+        );
+        if fully_vested && hunt.reward_config.claimed_count == hunt.reward_config.max_winners {
+            Self::publish_event(
+                env,
+                EventLevel::Minimal,
+                (Symbol::new(env, "RewardsExhausted"), hunt_id),
+                || RewardsExhaustedEvent {
+                    hunt_id,
+                    final_claimer: progress.player,
+                    event_seq: Storage::next_event_seq(env, hunt_id),
+                },
+            );
+        }
+        amount
+    }
+
+    /// Emits a RegistrationMilestone event if `count` is one of the hunt's milestones and
+    /// has not been reached before (players can unregister and push the count back).
+    fn emit_registration_milestone(env: &Env, hunt_id: u64, count: u32) {
+        if !Storage::get_milestones(env, hunt_id).contains(count)
+            || Storage::get_fired_milestones(env, hunt_id).contains(count)
+        {
+            return;
+        }
+        Storage::add_fired_milestone(env, hunt_id, count);
+        Self::publish_event(
+            env,
+            EventLevel::Minimal,
+            (Symbol::new(env, "RegistrationMilestone"), hunt_id),
+            || RegistrationMilestoneEvent {
+                hunt_id,
+                count,
+                event_seq: Storage::next_event_seq(env, hunt_id),
+            },
+        );
+    }
+
+    /// Shared body of `freeze_hunt_rewards` and `unfreeze_hunt_rewards`.
+    fn set_rewards_frozen(env: &Env, hunt_id: u64, frozen: bool) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(env, hunt_id, || {
+            Self::require_admin(env)?;
+            if !Storage::has_hunt(env, hunt_id) {
+                return Err(HuntErrorCode::from(HuntError::HuntNotFound { hunt_id }));
+            }
+            Storage::set_rewards_frozen(env, hunt_id, frozen);
+            Self::publish_event(
+                env,
+                EventLevel::Minimal,
+                (Symbol::new(env, "RewardsFrozen"), hunt_id),
+                || RewardsFrozenEvent {
+                    hunt_id,
+                    frozen,
+                    event_seq: Storage::next_event_seq(env, hunt_id),
+                },
+            );
+            Ok(())
+        })
+    }
+
+    /// Shared body of `pause_registration` and `resume_registration`.
+    fn set_registration_paused(env: &Env, hunt_id: u64, paused: bool) -> Result<(), HuntErrorCode> {
+        Self::with_diagnostics(env, hunt_id, || {
+            let hunt = Storage::get_hunt_or_error(env, hunt_id).map_err(HuntErrorCode::from)?;
+            hunt.creator.require_auth();
+            Storage::set_registration_paused(env, hunt_id, paused);
+            Self::publish_event(
+                env,
+                EventLevel::Minimal,
+                (Symbol::new(env, "RegistrationPaused"), hunt_id),
+                || RegistrationPausedEvent {
+                    hunt_id,
+                    paused,
+                    event_seq: Storage::next_event_seq(env, hunt_id),
+                },
+            );
+            Ok(())
+        })
+    }
+
+    /// Requires `caller` to be the hunt creator or the contract admin, and to authorize.
+    fn require_creator_or_admin(
+        env: &Env,
+        hunt: &Hunt,
+        caller: &Address,
+    ) -> Result<(), HuntErrorCode> {
+        if *caller == hunt.creator {
+            hunt.creator.require_auth();
+        } else if Storage::get_admin(env) == Some(caller.clone()) {
+            caller.require_auth();
+        } else {
+            return Err(HuntErrorCode::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Loads the admin and requires its authorization.
+    fn require_admin(env: &Env) -> Result<Address, HuntErrorCode> {
+        let admin = Storage::get_admin(env).ok_or(HuntErrorCode::NotInitialized)?;
+        admin.require_auth();
+        Ok(admin)
+    }
+
+    /// Returns `ContractPaused` while the contract is globally paused.
+    fn require_not_paused(env: &Env) -> Result<(), HuntErrorCode> {
+        if Storage::is_paused(env) {
+            return Err(HuntErrorCode::ContractPaused);
+        }
+        Ok(())
+    }
+
+    /// Normalizes answer (trim, lowercase) and hashes it with `algo`.
+    fn normalize_and_hash_answer(
+        env: &Env,
+        algo: &HashAlgo,
+        answer: &String,
+    ) -> Result<BytesN<32>, HuntError> {
+        let normalized = Self::normalize_answer(env, answer)?;
+        let hash = match algo {
+            HashAlgo::Sha256 => env.crypto().sha256(&normalized),
+            HashAlgo::Keccak256 => env.crypto().keccak256(&normalized),
+        };
+        Ok(hash.to_bytes())
+    }
+
+    /// Trims surrounding whitespace and lowercases ASCII letters, rejecting empty or
+    /// overlong answers.
+    fn normalize_answer(env: &Env, answer: &String) -> Result<Bytes, HuntError> {
+        let n = answer.len();
+        if n == 0 {
+            return Err(HuntError::InvalidAnswer);
+        }
+        if n > MAX_ANSWER_LENGTH {
+            return Err(HuntError::InvalidAnswer);
+        }
+        let mut buf = [0u8; 256];
+        answer.copy_into_slice(&mut buf[..n as usize]);
+        let mut start = 0usize;
+        let mut end = n as usize;
+        while start < end && Self::is_ascii_space(buf[start]) {
+            start += 1;
+        }
+        while end > start && Self::is_ascii_space(buf[end - 1]) {
+            end -= 1;
+        }
+        if start >= end {
+            return Err(HuntError::InvalidAnswer);
+        }
+        for b in &mut buf[start..end] {
+            if b.is_ascii_uppercase() {
+                *b += b'a' - b'A';
+            }
+        }
+        Ok(Bytes::from_slice(env, &buf[start..end]))
+    }
+
+    #[inline]
+    fn is_ascii_space(b: u8) -> bool {
+        b == 0x20 || b == 0x09 || b == 0x0a || b == 0x0d
+    }
+}
+
+mod types;
+mod storage;
+mod errors;
+mod nft;
+
+#[cfg(test)]
+mod test_utils;
 
 #[cfg(test)]
 mod test;
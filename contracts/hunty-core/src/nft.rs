@@ -0,0 +1,10 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Interface a hunt's NFT contract must expose to mint winner rewards.
+#[allow(dead_code)]
+#[contractclient(name = "NftRewardClient")]
+pub trait NftReward {
+    /// Mints the reward NFT for a hunt winner. `winner_index` is the winner's claim
+    /// position (1 for the first claimer), so each winner's token is distinct.
+    fn mint_reward(env: Env, to: Address, hunt_id: u64, winner_index: u32);
+}
@@ -1,11 +1,24 @@
-use soroban_sdk::{Env, Vec, Address, symbol_short};
-use crate::types::{Hunt, Clue, PlayerProgress};
+use soroban_sdk::{Env, Vec, Address, BytesN, Map, String, Symbol, TryFromVal, Val, symbol_short};
+use crate::types::{
+    AnswerCommitment, AttemptState, DescriptionRef, Durability, EventLevel, Hunt, HuntStatus, Clue,
+    LeaderboardEntry, PlayerProgress, PlayerProgressV0, PlayerProgressV1, PlayerProgressV2,
+    PlayerProgressV3, TemplateClue,
+};
 use crate::errors::HuntError;
 
+/// Approximate ledger close time, used to turn a hunt's `end_time` into a TTL.
+const LEDGER_SECONDS: u64 = 5;
+/// Extra ledgers (~4 hours) temporary progress outlives `end_time`, so winners can
+/// still claim shortly after the hunt ends.
+const PROGRESS_TTL_GRACE_LEDGERS: u32 = 2_880;
+/// Fields in the current `PlayerProgress` layout; older layouts have fewer.
+const PROGRESS_FIELDS: u32 = 13;
+
 /// Storage access layer for hunts, clues, and player progress.
 /// Provides type-safe, efficient storage operations with consistent key management.
 pub struct Storage;
 
+#[allow(dead_code)]
 impl Storage {
     // Symbol constants for key prefixes to prevent collisions
     // Using symbol_short for efficient key generation
@@ -14,8 +27,53 @@ impl Storage {
     const PROGRESS_KEY: soroban_sdk::Symbol = symbol_short!("PROG");
     const PLAYERS_LIST_KEY: soroban_sdk::Symbol = symbol_short!("PLRS");
     const CLUES_LIST_KEY: soroban_sdk::Symbol = symbol_short!("CLST");
+    const REQUIRED_CLUES_KEY: soroban_sdk::Symbol = symbol_short!("RCLS");
     const HUNT_COUNTER_KEY: soroban_sdk::Symbol = symbol_short!("CNTR");
     const CLUE_COUNTER_KEY: soroban_sdk::Symbol = symbol_short!("CCNT");
+    const PLAYER_HUNTS_KEY: soroban_sdk::Symbol = symbol_short!("PHNT");
+    const COMMITMENT_KEY: soroban_sdk::Symbol = symbol_short!("CMIT");
+    const ESCROW_KEY: soroban_sdk::Symbol = symbol_short!("ESCR");
+    const ACTIVE_COUNT_KEY: soroban_sdk::Symbol = symbol_short!("ACTV");
+    const PLAYER_COUNT_KEY: soroban_sdk::Symbol = symbol_short!("NPLR");
+    const DISTRIBUTED_KEY: soroban_sdk::Symbol = symbol_short!("DIST");
+    const FEE_PAID_KEY: soroban_sdk::Symbol = symbol_short!("FEEP");
+    const FEES_HELD_KEY: soroban_sdk::Symbol = symbol_short!("FEEH");
+    const TITLE_KEY: soroban_sdk::Symbol = symbol_short!("TITL");
+    const ADMIN_KEY: soroban_sdk::Symbol = symbol_short!("ADMIN");
+    const PAUSED_KEY: soroban_sdk::Symbol = symbol_short!("PAUSED");
+    const RAFFLE_KEY: soroban_sdk::Symbol = symbol_short!("DRAW");
+    const CLUE_VIEW_KEY: soroban_sdk::Symbol = symbol_short!("VIEW");
+    const COLLABORATORS_KEY: soroban_sdk::Symbol = symbol_short!("COLB");
+    const CLAIM_HELPERS_KEY: soroban_sdk::Symbol = symbol_short!("HLPR");
+    const STATUS_INDEX_KEY: soroban_sdk::Symbol = symbol_short!("HSTS");
+    const EVENT_SEQ_KEY: soroban_sdk::Symbol = symbol_short!("ESEQ");
+    const CREATOR_HUNTS_KEY: soroban_sdk::Symbol = symbol_short!("CHNT");
+    const COMPLETIONS_KEY: soroban_sdk::Symbol = symbol_short!("NCMP");
+    const HUNT_DISTRIBUTED_KEY: soroban_sdk::Symbol = symbol_short!("HDST");
+    const SALT_KEY: soroban_sdk::Symbol = symbol_short!("SALT");
+    const CLUE_SET_KEY: soroban_sdk::Symbol = symbol_short!("CSET");
+    const TEMPLATE_KEY: soroban_sdk::Symbol = symbol_short!("TMPL");
+    const TEMPLATE_NAMES_KEY: soroban_sdk::Symbol = symbol_short!("TMPN");
+    const ATTEMPTS_KEY: soroban_sdk::Symbol = symbol_short!("ATMP");
+    const DESCRIPTION_REF_KEY: soroban_sdk::Symbol = symbol_short!("DREF");
+    const REWARDS_FROZEN_KEY: soroban_sdk::Symbol = symbol_short!("FRZN");
+    const REGISTRATION_PAUSED_KEY: soroban_sdk::Symbol = symbol_short!("RGPS");
+    const PURGED_KEY: soroban_sdk::Symbol = symbol_short!("PRGD");
+    const MILESTONES_KEY: soroban_sdk::Symbol = symbol_short!("MLST");
+    const MILESTONES_FIRED_KEY: soroban_sdk::Symbol = symbol_short!("MLFD");
+    const ARCHIVE_AFTER_KEY: soroban_sdk::Symbol = symbol_short!("ARCH");
+    const MAX_CLUES_KEY: soroban_sdk::Symbol = symbol_short!("MXCL");
+    const NONCE_KEY: soroban_sdk::Symbol = symbol_short!("NONC");
+    const EVENT_LEVEL_KEY: soroban_sdk::Symbol = symbol_short!("EVLV");
+    const PENDING_ADMIN_KEY: soroban_sdk::Symbol = symbol_short!("PADM");
+    const CLUE_SOLVES_KEY: soroban_sdk::Symbol = symbol_short!("CSLV");
+    const DISTRIBUTION_KEY: soroban_sdk::Symbol = symbol_short!("PAYR");
+    const DISTRIBUTION_COUNT_KEY: soroban_sdk::Symbol = symbol_short!("PAYN");
+    const PENDING_TIES_KEY: soroban_sdk::Symbol = symbol_short!("TIES");
+    const LEADERBOARD_CACHE_KEY: soroban_sdk::Symbol = symbol_short!("LBCH");
+    const REWARDS_VOID_KEY: soroban_sdk::Symbol = symbol_short!("RVOD");
+    const VESTED_CLAIMED_KEY: soroban_sdk::Symbol = symbol_short!("VCLM");
+    const VESTED_PAID_KEY: soroban_sdk::Symbol = symbol_short!("VPAY");
 
     // ========== Hunt Storage Functions ==========
 
@@ -45,6 +103,12 @@ impl Storage {
         env.storage().persistent().get(&key)
     }
 
+    /// Returns whether a hunt is stored, checking the key without deserializing the hunt.
+    pub fn has_hunt(env: &Env, hunt_id: u64) -> bool {
+        let key = Self::hunt_key(hunt_id);
+        env.storage().persistent().has(&key)
+    }
+
     /// Retrieves a hunt by ID or returns an error if not found.
     /// 
     /// # Arguments
@@ -59,10 +123,22 @@ impl Storage {
             .ok_or(HuntError::HuntNotFound { hunt_id })
     }
 
+    /// Maps a creator's exact hunt title to its hunt ID.
+    pub fn set_hunt_id_for_title(env: &Env, creator: &Address, title: &String, hunt_id: u64) {
+        let key = (Self::TITLE_KEY, creator.clone(), title.clone());
+        env.storage().persistent().set(&key, &hunt_id);
+    }
+
+    /// Looks up a hunt ID by its creator and exact title.
+    pub fn get_hunt_id_for_title(env: &Env, creator: &Address, title: &String) -> Option<u64> {
+        let key = (Self::TITLE_KEY, creator.clone(), title.clone());
+        env.storage().persistent().get(&key)
+    }
+
     // ========== Clue Storage Functions ==========
 
     /// Stores a clue using composite keys (hunt_id + clue_id).
-    /// Also maintains a list of clue IDs for the hunt.
+    /// Also maintains the hunt's lists of clue IDs and required clue IDs.
     /// 
     /// # Arguments
     /// * `env` - The Soroban environment
@@ -75,6 +151,7 @@ impl Storage {
 
         // Update the list of clue IDs for this hunt
         Self::add_clue_to_list(env, hunt_id, clue.clue_id);
+        Self::set_clue_required_in_index(env, hunt_id, clue.clue_id, clue.is_required);
     }
 
     /// Retrieves an individual clue by hunt_id and clue_id.
@@ -91,6 +168,25 @@ impl Storage {
         env.storage().persistent().get(&key)
     }
 
+    /// Returns whether a clue is stored, checking the key without deserializing the clue.
+    pub fn has_clue(env: &Env, hunt_id: u64, clue_id: u32) -> bool {
+        let key = Self::clue_key(hunt_id, clue_id);
+        env.storage().persistent().has(&key)
+    }
+
+    /// Deletes a clue and drops it from the hunt's clue indexes. Hunt clue counts are
+    /// left to the caller.
+    pub fn remove_clue(env: &Env, hunt_id: u64, clue_id: u32) {
+        let key = Self::clue_key(hunt_id, clue_id);
+        env.storage().persistent().remove(&key);
+        Self::set_clue_required_in_index(env, hunt_id, clue_id, false);
+        let mut clue_ids = Self::get_clue_ids_for_hunt(env, hunt_id);
+        if let Some(index) = clue_ids.first_index_of(clue_id) {
+            clue_ids.remove(index);
+            Self::set_clue_ids_for_hunt(env, hunt_id, &clue_ids);
+        }
+    }
+
     /// Retrieves a clue or returns an error if not found.
     /// 
     /// # Arguments
@@ -129,6 +225,39 @@ impl Storage {
         clues
     }
 
+    /// Overwrites the clue index for a hunt. Callers must pass a permutation of the
+    /// existing clue IDs; listing and ordered solving follow this order.
+    pub fn set_clue_ids_for_hunt(env: &Env, hunt_id: u64, clue_ids: &Vec<u32>) {
+        let key = Self::clues_list_key(hunt_id);
+        env.storage().persistent().set(&key, clue_ids);
+    }
+
+    /// Returns the IDs of a hunt's required clues in ascending order.
+    pub fn get_required_clue_ids(env: &Env, hunt_id: u64) -> Vec<u32> {
+        let key = (Self::REQUIRED_CLUES_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Overwrites the hunt's required clue index. `clue_ids` must be sorted ascending.
+    pub fn set_required_clue_ids(env: &Env, hunt_id: u64, clue_ids: &Vec<u32>) {
+        let key = (Self::REQUIRED_CLUES_KEY, hunt_id);
+        env.storage().persistent().set(&key, clue_ids);
+    }
+
+    /// Adds a clue to, or drops it from, the hunt's sorted required clue index.
+    fn set_clue_required_in_index(env: &Env, hunt_id: u64, clue_id: u32, required: bool) {
+        let mut required_ids = Self::get_required_clue_ids(env, hunt_id);
+        match (required_ids.binary_search(clue_id), required) {
+            (Err(index), true) => required_ids.insert(index, clue_id),
+            (Ok(index), false) => {
+                required_ids.remove(index);
+            }
+            _ => return,
+        }
+        let key = (Self::REQUIRED_CLUES_KEY, hunt_id);
+        env.storage().persistent().set(&key, &required_ids);
+    }
+
     // ========== Player Progress Storage Functions ==========
 
     /// Stores player state/progress for a hunt.
@@ -140,13 +269,26 @@ impl Storage {
     pub fn save_player_progress(env: &Env, progress: &PlayerProgress) {
         // Store the progress with composite key (hunt_id + player address)
         let key = Self::progress_key(progress.hunt_id, &progress.player);
-        env.storage().persistent().set(&key, progress);
+        match Self::get_hunt(env, progress.hunt_id) {
+            Some(hunt) if hunt.progress_durability == Durability::Temporary => {
+                let ttl = Self::temporary_progress_ttl(env, hunt.closes_at());
+                env.storage().temporary().set(&key, progress);
+                env.storage().temporary().extend_ttl(&key, ttl, ttl);
+            }
+            _ => env.storage().persistent().set(&key, progress),
+        }
 
         // Update the list of players for this hunt
         Self::add_player_to_list(env, progress.hunt_id, &progress.player);
     }
 
     /// Retrieves player progress for a specific hunt and player.
+    ///
+    /// Entries written in an earlier layout (`PlayerProgressV0` to `PlayerProgressV3`, see
+    /// types.rs) are upgraded in memory with defaults for the newer fields. Reads never
+    /// write, so views stay free of storage costs; the entry is stored in the current
+    /// layout the next time a mutating call saves it. An entry matching no known layout
+    /// traps.
     /// 
     /// # Arguments
     /// * `env` - The Soroban environment
@@ -157,7 +299,23 @@ impl Storage {
     /// * `Some(PlayerProgress)` if progress exists, `None` otherwise
     pub fn get_player_progress(env: &Env, hunt_id: u64, player: &Address) -> Option<PlayerProgress> {
         let key = Self::progress_key(hunt_id, player);
-        env.storage().persistent().get(&key)
+        let raw: Val = if Self::has_temporary_progress(env, hunt_id) {
+            env.storage().temporary().get(&key)?
+        } else {
+            env.storage().persistent().get(&key)?
+        };
+        // The host traps when a struct is read from a map of another size, so the
+        // layout is picked by field count before converting.
+        let layout = Map::<Symbol, Val>::try_from_val(env, &raw).map(|fields| fields.len());
+        let progress = match layout {
+            Ok(PROGRESS_FIELDS) => PlayerProgress::try_from_val(env, &raw).ok()?,
+            Ok(12) => PlayerProgress::from_v3(PlayerProgressV3::try_from_val(env, &raw).ok()?),
+            Ok(11) => PlayerProgress::from_v2(env, PlayerProgressV2::try_from_val(env, &raw).ok()?),
+            Ok(10) => PlayerProgress::from_v1(env, PlayerProgressV1::try_from_val(env, &raw).ok()?),
+            Ok(8) => PlayerProgress::from_v0(env, PlayerProgressV0::try_from_val(env, &raw).ok()?),
+            _ => panic!("unrecognized PlayerProgress layout"),
+        };
+        Some(progress)
     }
 
     /// Retrieves player progress or returns an error if not found.
@@ -179,6 +337,42 @@ impl Storage {
             .ok_or(HuntError::PlayerNotRegistered { hunt_id })
     }
 
+    /// Deletes a player's progress for a hunt and removes them from the hunt's player list.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban environment
+    /// * `hunt_id` - The hunt the player is registered for
+    /// * `player` - The player's address
+    pub fn remove_player_progress(env: &Env, hunt_id: u64, player: &Address) {
+        let key = Self::progress_key(hunt_id, player);
+        if Self::has_temporary_progress(env, hunt_id) {
+            env.storage().temporary().remove(&key);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Self::remove_player_from_list(env, hunt_id, player);
+    }
+
+    /// Returns true when the hunt keeps player progress in temporary storage.
+    fn has_temporary_progress(env: &Env, hunt_id: u64) -> bool {
+        matches!(
+            Self::get_hunt(env, hunt_id).map(|hunt| hunt.progress_durability),
+            Some(Durability::Temporary)
+        )
+    }
+
+    /// Ledgers a temporary progress entry should live: until the hunt closes plus a grace
+    /// period, capped at the network maximum. Hunts without an end time get the maximum.
+    fn temporary_progress_ttl(env: &Env, closes_at: u64) -> u32 {
+        let max_ttl = env.storage().max_ttl();
+        if closes_at == 0 {
+            return max_ttl;
+        }
+        let remaining = closes_at.saturating_sub(env.ledger().timestamp()) / LEDGER_SECONDS;
+        let ledgers = remaining.saturating_add(PROGRESS_TTL_GRACE_LEDGERS as u64);
+        ledgers.min(max_ttl as u64) as u32
+    }
+
     /// Returns all registered players for a hunt.
     /// 
     /// # Arguments
@@ -202,6 +396,372 @@ impl Storage {
         progress_list
     }
 
+    // ========== Answer Commitment Functions ==========
+
+    /// Stores a player's answer commitment for a clue, replacing any earlier one.
+    pub fn save_commitment(
+        env: &Env,
+        hunt_id: u64,
+        clue_id: u32,
+        player: &Address,
+        commitment: &AnswerCommitment,
+    ) {
+        let key = Self::commitment_key(hunt_id, clue_id, player);
+        env.storage().persistent().set(&key, commitment);
+    }
+
+    /// Retrieves a player's answer commitment for a clue.
+    pub fn get_commitment(
+        env: &Env,
+        hunt_id: u64,
+        clue_id: u32,
+        player: &Address,
+    ) -> Option<AnswerCommitment> {
+        let key = Self::commitment_key(hunt_id, clue_id, player);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Deletes a player's answer commitment for a clue.
+    pub fn remove_commitment(env: &Env, hunt_id: u64, clue_id: u32, player: &Address) {
+        let key = Self::commitment_key(hunt_id, clue_id, player);
+        env.storage().persistent().remove(&key);
+    }
+
+    // ========== Attempt Limit Functions ==========
+
+    /// Stores a player's wrong-answer state for a clue.
+    pub fn save_attempt_state(
+        env: &Env,
+        hunt_id: u64,
+        clue_id: u32,
+        player: &Address,
+        state: &AttemptState,
+    ) {
+        let key = (Self::ATTEMPTS_KEY, hunt_id, clue_id, player.clone());
+        env.storage().persistent().set(&key, state);
+    }
+
+    /// Returns a player's wrong-answer state for a clue (all zero if none recorded).
+    pub fn get_attempt_state(
+        env: &Env,
+        hunt_id: u64,
+        clue_id: u32,
+        player: &Address,
+    ) -> AttemptState {
+        let key = (Self::ATTEMPTS_KEY, hunt_id, clue_id, player.clone());
+        env.storage().persistent().get(&key).unwrap_or_default()
+    }
+
+    /// Deletes a player's wrong-answer state for a clue.
+    pub fn remove_attempt_state(env: &Env, hunt_id: u64, clue_id: u32, player: &Address) {
+        let key = (Self::ATTEMPTS_KEY, hunt_id, clue_id, player.clone());
+        env.storage().persistent().remove(&key);
+    }
+
+    // ========== Signed Submission Functions ==========
+
+    /// Returns the nonce a player's next signed submission to a hunt must use.
+    pub fn get_nonce(env: &Env, hunt_id: u64, player: &Address) -> u64 {
+        let key = (Self::NONCE_KEY, hunt_id, player.clone());
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Stores a player's next signed-submission nonce for a hunt.
+    pub fn set_nonce(env: &Env, hunt_id: u64, player: &Address, nonce: u64) {
+        let key = (Self::NONCE_KEY, hunt_id, player.clone());
+        env.storage().persistent().set(&key, &nonce);
+    }
+
+    /// Deletes a player's signed-submission nonce for a hunt.
+    pub fn remove_nonce(env: &Env, hunt_id: u64, player: &Address) {
+        let key = (Self::NONCE_KEY, hunt_id, player.clone());
+        env.storage().persistent().remove(&key);
+    }
+
+    // ========== Event Sequence Functions ==========
+
+    /// Increments and returns a hunt's event sequence number (the first event gets 1).
+    pub fn next_event_seq(env: &Env, hunt_id: u64) -> u64 {
+        let seq = Self::get_event_seq(env, hunt_id) + 1;
+        env.storage()
+            .persistent()
+            .set(&(Self::EVENT_SEQ_KEY, hunt_id), &seq);
+        seq
+    }
+
+    /// Returns the number of events a hunt has emitted.
+    pub fn get_event_seq(env: &Env, hunt_id: u64) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&(Self::EVENT_SEQ_KEY, hunt_id))
+            .unwrap_or(0)
+    }
+
+    // ========== Status Index Functions ==========
+
+    /// Moves a hunt between status buckets. `from` is None for a newly created hunt.
+    pub fn move_hunt_status(env: &Env, hunt_id: u64, from: Option<HuntStatus>, to: HuntStatus) {
+        if let Some(from) = from {
+            let mut ids = Self::get_hunt_ids_by_status(env, &from);
+            if let Some(index) = ids.first_index_of(hunt_id) {
+                ids.remove(index);
+                env.storage().persistent().set(&(Self::STATUS_INDEX_KEY, from), &ids);
+            }
+        }
+        let mut ids = Self::get_hunt_ids_by_status(env, &to);
+        ids.push_back(hunt_id);
+        env.storage().persistent().set(&(Self::STATUS_INDEX_KEY, to), &ids);
+    }
+
+    /// Returns the IDs of hunts currently in `status`, in the order they entered it.
+    pub fn get_hunt_ids_by_status(env: &Env, status: &HuntStatus) -> Vec<u64> {
+        let key = (Self::STATUS_INDEX_KEY, status.clone());
+        env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+    }
+
+    // ========== Collaborator Functions ==========
+
+    /// Adds a collaborator to a hunt (no-op if already present).
+    pub fn add_collaborator(env: &Env, hunt_id: u64, collaborator: &Address) {
+        let key = (Self::COLLABORATORS_KEY, hunt_id);
+        let mut collaborators = Self::get_collaborators(env, hunt_id);
+        if !collaborators.contains(collaborator) {
+            collaborators.push_back(collaborator.clone());
+            env.storage().persistent().set(&key, &collaborators);
+        }
+    }
+
+    /// Removes a collaborator from a hunt (no-op if absent).
+    pub fn remove_collaborator(env: &Env, hunt_id: u64, collaborator: &Address) {
+        let key = (Self::COLLABORATORS_KEY, hunt_id);
+        let mut collaborators = Self::get_collaborators(env, hunt_id);
+        if let Some(index) = collaborators.first_index_of(collaborator) {
+            collaborators.remove(index);
+            env.storage().persistent().set(&key, &collaborators);
+        }
+    }
+
+    /// Returns a hunt's collaborators, in the order they were added.
+    pub fn get_collaborators(env: &Env, hunt_id: u64) -> Vec<Address> {
+        let key = (Self::COLLABORATORS_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Returns whether `addr` is a collaborator on a hunt.
+    pub fn is_collaborator(env: &Env, hunt_id: u64, addr: &Address) -> bool {
+        Self::get_collaborators(env, hunt_id).contains(addr)
+    }
+
+    /// Approves a helper to claim rewards on players' behalf (no-op if already present).
+    pub fn add_claim_helper(env: &Env, hunt_id: u64, helper: &Address) {
+        let key = (Self::CLAIM_HELPERS_KEY, hunt_id);
+        let mut helpers = Self::get_claim_helpers(env, hunt_id);
+        if !helpers.contains(helper) {
+            helpers.push_back(helper.clone());
+            env.storage().persistent().set(&key, &helpers);
+        }
+    }
+
+    /// Withdraws a claim helper's approval (no-op if absent).
+    pub fn remove_claim_helper(env: &Env, hunt_id: u64, helper: &Address) {
+        let key = (Self::CLAIM_HELPERS_KEY, hunt_id);
+        let mut helpers = Self::get_claim_helpers(env, hunt_id);
+        if let Some(index) = helpers.first_index_of(helper) {
+            helpers.remove(index);
+            env.storage().persistent().set(&key, &helpers);
+        }
+    }
+
+    /// Returns a hunt's approved claim helpers, in the order they were added.
+    pub fn get_claim_helpers(env: &Env, hunt_id: u64) -> Vec<Address> {
+        let key = (Self::CLAIM_HELPERS_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Returns whether `addr` is an approved claim helper on a hunt.
+    pub fn is_claim_helper(env: &Env, hunt_id: u64, addr: &Address) -> bool {
+        Self::get_claim_helpers(env, hunt_id).contains(addr)
+    }
+
+    // ========== Clue View Functions ==========
+
+    /// Records when a player first viewed a clue, starting their clock for its time bonus.
+    pub fn set_clue_viewed_at(env: &Env, hunt_id: u64, clue_id: u32, player: &Address, at: u64) {
+        let key = (Self::CLUE_VIEW_KEY, hunt_id, clue_id, player.clone());
+        env.storage().persistent().set(&key, &at);
+    }
+
+    /// Returns when a player first viewed a clue, if they have.
+    pub fn get_clue_viewed_at(
+        env: &Env,
+        hunt_id: u64,
+        clue_id: u32,
+        player: &Address,
+    ) -> Option<u64> {
+        let key = (Self::CLUE_VIEW_KEY, hunt_id, clue_id, player.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Deletes the record of when a player first viewed a clue.
+    pub fn remove_clue_viewed_at(env: &Env, hunt_id: u64, clue_id: u32, player: &Address) {
+        let key = (Self::CLUE_VIEW_KEY, hunt_id, clue_id, player.clone());
+        env.storage().persistent().remove(&key);
+    }
+
+    // ========== Player Hunt Index Functions ==========
+
+    /// Records that `player` joined `hunt_id` in the player's hunt index.
+    pub fn add_hunt_to_player_index(env: &Env, player: &Address, hunt_id: u64) {
+        let key = Self::player_hunts_key(player);
+        let mut hunt_ids: Vec<u64> = env.storage().persistent().get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        if hunt_ids.first_index_of(hunt_id).is_none() {
+            hunt_ids.push_back(hunt_id);
+            env.storage().persistent().set(&key, &hunt_ids);
+        }
+    }
+
+    /// Removes `hunt_id` from the player's hunt index.
+    pub fn remove_hunt_from_player_index(env: &Env, player: &Address, hunt_id: u64) {
+        let key = Self::player_hunts_key(player);
+        let mut hunt_ids: Vec<u64> = env.storage().persistent().get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        if let Some(index) = hunt_ids.first_index_of(hunt_id) {
+            hunt_ids.remove(index);
+            env.storage().persistent().set(&key, &hunt_ids);
+        }
+    }
+
+    /// Returns every hunt ID the player is registered for, in join order.
+    pub fn get_player_hunt_ids(env: &Env, player: &Address) -> Vec<u64> {
+        let key = Self::player_hunts_key(player);
+        env.storage().persistent().get(&key)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    // ========== Registration Milestone Functions ==========
+
+    /// Stores the player counts at which a hunt emits a milestone event.
+    pub fn set_milestones(env: &Env, hunt_id: u64, thresholds: &Vec<u32>) {
+        let key = (Self::MILESTONES_KEY, hunt_id);
+        env.storage().persistent().set(&key, thresholds);
+    }
+
+    /// Returns a hunt's registration milestones (empty if none were set).
+    pub fn get_milestones(env: &Env, hunt_id: u64) -> Vec<u32> {
+        let key = (Self::MILESTONES_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Records that a milestone's event was emitted, so it never fires twice.
+    pub fn add_fired_milestone(env: &Env, hunt_id: u64, count: u32) {
+        let key = (Self::MILESTONES_FIRED_KEY, hunt_id);
+        let mut fired = Self::get_fired_milestones(env, hunt_id);
+        fired.push_back(count);
+        env.storage().persistent().set(&key, &fired);
+    }
+
+    /// Returns the milestones a hunt has already emitted events for.
+    pub fn get_fired_milestones(env: &Env, hunt_id: u64) -> Vec<u32> {
+        let key = (Self::MILESTONES_FIRED_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+    }
+
+    // ========== Description Reference Functions ==========
+
+    /// Stores a hunt's off-chain description reference.
+    pub fn set_description_ref(env: &Env, hunt_id: u64, description: &DescriptionRef) {
+        let key = (Self::DESCRIPTION_REF_KEY, hunt_id);
+        env.storage().persistent().set(&key, description);
+    }
+
+    /// Returns a hunt's off-chain description reference, if one was set.
+    pub fn get_description_ref(env: &Env, hunt_id: u64) -> Option<DescriptionRef> {
+        let key = (Self::DESCRIPTION_REF_KEY, hunt_id);
+        env.storage().persistent().get(&key)
+    }
+
+    // ========== Answer Salt Functions ==========
+
+    /// Stores the salt a hunt's answer hashes are bound to.
+    pub fn set_answer_salt(env: &Env, hunt_id: u64, salt: &BytesN<32>) {
+        let key = (Self::SALT_KEY, hunt_id);
+        env.storage().persistent().set(&key, salt);
+    }
+
+    /// Returns the hunt's answer salt, or None if it was never rotated.
+    pub fn get_answer_salt(env: &Env, hunt_id: u64) -> Option<BytesN<32>> {
+        let key = (Self::SALT_KEY, hunt_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Stores the commitment to the hunt's clue answers taken at activation.
+    pub fn set_clue_set_commitment(env: &Env, hunt_id: u64, commitment: &BytesN<32>) {
+        let key = (Self::CLUE_SET_KEY, hunt_id);
+        env.storage().persistent().set(&key, commitment);
+    }
+
+    /// Returns the hunt's clue set commitment, or None if it was never activated.
+    pub fn get_clue_set_commitment(env: &Env, hunt_id: u64) -> Option<BytesN<32>> {
+        let key = (Self::CLUE_SET_KEY, hunt_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Drops the hunt's clue set commitment.
+    pub fn remove_clue_set_commitment(env: &Env, hunt_id: u64) {
+        let key = (Self::CLUE_SET_KEY, hunt_id);
+        env.storage().persistent().remove(&key);
+    }
+
+    // ========== Clue Template Functions ==========
+
+    /// Stores (or replaces) an owner's named clue template.
+    pub fn save_template(env: &Env, owner: &Address, name: &String, clues: &Vec<TemplateClue>) {
+        let key = (Self::TEMPLATE_KEY, owner.clone(), name.clone());
+        env.storage().persistent().set(&key, clues);
+        let names_key = (Self::TEMPLATE_NAMES_KEY, owner.clone());
+        let mut names = Self::get_template_names(env, owner);
+        if !names.contains(name) {
+            names.push_back(name.clone());
+            env.storage().persistent().set(&names_key, &names);
+        }
+    }
+
+    /// Returns an owner's clue template by name.
+    pub fn get_template(env: &Env, owner: &Address, name: &String) -> Option<Vec<TemplateClue>> {
+        let key = (Self::TEMPLATE_KEY, owner.clone(), name.clone());
+        env.storage().persistent().get(&key)
+    }
+
+    /// Returns the names of an owner's templates, in the order they were first saved.
+    pub fn get_template_names(env: &Env, owner: &Address) -> Vec<String> {
+        let key = (Self::TEMPLATE_NAMES_KEY, owner.clone());
+        env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+    }
+
+    // ========== Creator Hunt Index Functions ==========
+
+    /// Records a newly created hunt in its creator's hunt index.
+    pub fn add_hunt_to_creator_index(env: &Env, creator: &Address, hunt_id: u64) {
+        let key = (Self::CREATOR_HUNTS_KEY, creator.clone());
+        let mut hunt_ids: Vec<u64> = env.storage().persistent().get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        hunt_ids.push_back(hunt_id);
+        env.storage().persistent().set(&key, &hunt_ids);
+    }
+
+    /// Returns every hunt ID the creator has created, oldest first.
+    pub fn get_creator_hunt_ids(env: &Env, creator: &Address) -> Vec<u64> {
+        let key = (Self::CREATOR_HUNTS_KEY, creator.clone());
+        env.storage().persistent().get(&key)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Returns the number of players registered for a hunt, from the player index.
+    pub fn get_player_count(env: &Env, hunt_id: u64) -> u32 {
+        Self::get_player_addresses_for_hunt(env, hunt_id).len()
+    }
+
     // ========== Helper Functions for Key Generation ==========
 
     /// Generates a storage key for a hunt using a symbol prefix and hunt_id.
@@ -218,7 +778,7 @@ impl Storage {
 
     /// Generates a composite storage key for player progress.
     /// Uses tuple key (PROGRESS_KEY, hunt_id, player) for efficient storage access.
-    fn progress_key(hunt_id: u64, player: &Address) -> (soroban_sdk::Symbol, u64, Address) {
+    pub fn progress_key(hunt_id: u64, player: &Address) -> (soroban_sdk::Symbol, u64, Address) {
         (Self::PROGRESS_KEY, hunt_id, player.clone())
     }
 
@@ -239,6 +799,20 @@ impl Storage {
         (Self::PLAYERS_LIST_KEY, hunt_id)
     }
 
+    /// Generates a composite storage key for a player's answer commitment.
+    fn commitment_key(
+        hunt_id: u64,
+        clue_id: u32,
+        player: &Address,
+    ) -> (soroban_sdk::Symbol, u64, u32, Address) {
+        (Self::COMMITMENT_KEY, hunt_id, clue_id, player.clone())
+    }
+
+    /// Generates a storage key for the list of hunts a player has joined.
+    fn player_hunts_key(player: &Address) -> (soroban_sdk::Symbol, Address) {
+        (Self::PLAYER_HUNTS_KEY, player.clone())
+    }
+
     // ========== Internal Helper Functions ==========
 
     /// Adds a clue ID to the list of clues for a hunt.
@@ -265,8 +839,8 @@ impl Storage {
         }
     }
 
-    /// Retrieves the list of clue IDs for a hunt.
-    fn get_clue_ids_for_hunt(env: &Env, hunt_id: u64) -> Vec<u32> {
+    /// Retrieves the list of clue IDs for a hunt, in index order.
+    pub fn get_clue_ids_for_hunt(env: &Env, hunt_id: u64) -> Vec<u32> {
         let key = Self::clues_list_key(hunt_id);
         env.storage().persistent().get(&key)
             .unwrap_or_else(|| Vec::new(env))
@@ -296,13 +870,31 @@ impl Storage {
         }
     }
 
+    /// Removes a player address from the list of players for a hunt.
+    fn remove_player_from_list(env: &Env, hunt_id: u64, player: &Address) {
+        let key = Self::players_list_key(hunt_id);
+        let mut players: Vec<Address> = env.storage().persistent().get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        if let Some(index) = players.first_index_of(player) {
+            players.remove(index);
+            env.storage().persistent().set(&key, &players);
+        }
+    }
+
     /// Retrieves the list of player addresses for a hunt.
-    fn get_player_addresses_for_hunt(env: &Env, hunt_id: u64) -> Vec<Address> {
+    pub fn get_player_addresses_for_hunt(env: &Env, hunt_id: u64) -> Vec<Address> {
         let key = Self::players_list_key(hunt_id);
         env.storage().persistent().get(&key)
             .unwrap_or_else(|| Vec::new(env))
     }
 
+    /// Overwrites the list of players for a hunt. Callers must pass players that have
+    /// progress stored for the hunt, without duplicates.
+    pub fn set_player_addresses_for_hunt(env: &Env, hunt_id: u64, players: &Vec<Address>) {
+        let key = Self::players_list_key(hunt_id);
+        env.storage().persistent().set(&key, players);
+    }
+
     // ========== Hunt Counter Functions ==========
 
     /// Increments and returns the next hunt ID.
@@ -313,12 +905,15 @@ impl Storage {
     /// 
     /// # Returns
     /// The next available hunt ID (starting from 1)
-    pub fn next_hunt_id(env: &Env) -> u64 {
+    ///
+    /// # Errors
+    /// * `HuntIdSpaceExhausted` - The counter is already at `u64::MAX`
+    pub fn next_hunt_id(env: &Env) -> Result<u64, HuntError> {
         let key = Self::HUNT_COUNTER_KEY;
         let current: u64 = env.storage().persistent().get(&key).unwrap_or(0);
-        let next = current + 1;
+        let next = current.checked_add(1).ok_or(HuntError::HuntIdSpaceExhausted)?;
         env.storage().persistent().set(&key, &next);
-        next
+        Ok(next)
     }
 
     /// Gets the current hunt counter value without incrementing.
@@ -364,5 +959,357 @@ impl Storage {
         let key = Self::clue_counter_key(hunt_id);
         env.storage().persistent().get(&key).unwrap_or(0)
     }
-}
 
+    // ========== Admin / Pause Functions ==========
+
+    /// Stores the contract admin address.
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().persistent().set(&Self::ADMIN_KEY, admin);
+    }
+
+    /// Returns the contract admin, if the contract has been initialized.
+    pub fn get_admin(env: &Env) -> Option<Address> {
+        env.storage().persistent().get(&Self::ADMIN_KEY)
+    }
+
+    /// Stores the admin proposed by `propose_admin` until they accept.
+    pub fn set_pending_admin(env: &Env, admin: &Address) {
+        env.storage().persistent().set(&Self::PENDING_ADMIN_KEY, admin);
+    }
+
+    /// Returns the proposed admin, if a transfer is pending.
+    pub fn get_pending_admin(env: &Env) -> Option<Address> {
+        env.storage().persistent().get(&Self::PENDING_ADMIN_KEY)
+    }
+
+    /// Clears any pending admin proposal.
+    pub fn remove_pending_admin(env: &Env) {
+        env.storage().persistent().remove(&Self::PENDING_ADMIN_KEY);
+    }
+
+    /// Sets the global pause flag.
+    pub fn set_paused(env: &Env, paused: bool) {
+        env.storage().persistent().set(&Self::PAUSED_KEY, &paused);
+    }
+
+    /// Returns whether the contract is globally paused (false if never set).
+    pub fn is_paused(env: &Env) -> bool {
+        env.storage().persistent().get(&Self::PAUSED_KEY).unwrap_or(false)
+    }
+
+    /// Sets the most clues a hunt may have (0 = unlimited).
+    pub fn set_max_clues_per_hunt(env: &Env, max_clues: u32) {
+        env.storage().persistent().set(&Self::MAX_CLUES_KEY, &max_clues);
+    }
+
+    /// Returns the configured clue cap, or None if the admin never set one.
+    pub fn get_max_clues_per_hunt(env: &Env) -> Option<u32> {
+        env.storage().persistent().get(&Self::MAX_CLUES_KEY)
+    }
+
+    /// Sets how many events the contract publishes.
+    pub fn set_event_level(env: &Env, level: &EventLevel) {
+        env.storage().persistent().set(&Self::EVENT_LEVEL_KEY, level);
+    }
+
+    /// Returns the configured event level, or None if the admin never set one.
+    pub fn get_event_level(env: &Env) -> Option<EventLevel> {
+        env.storage().persistent().get(&Self::EVENT_LEVEL_KEY)
+    }
+
+    /// Sets the age after which finished hunts are archive candidates.
+    pub fn set_archive_after_secs(env: &Env, secs: u64) {
+        env.storage().persistent().set(&Self::ARCHIVE_AFTER_KEY, &secs);
+    }
+
+    /// Returns the configured archive age, or None if the admin never set one.
+    pub fn get_archive_after_secs(env: &Env) -> Option<u64> {
+        env.storage().persistent().get(&Self::ARCHIVE_AFTER_KEY)
+    }
+
+    /// Freezes or unfreezes reward claims for one hunt.
+    pub fn set_rewards_frozen(env: &Env, hunt_id: u64, frozen: bool) {
+        let key = (Self::REWARDS_FROZEN_KEY, hunt_id);
+        if frozen {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Returns whether reward claims for a hunt are frozen (false if never set).
+    pub fn are_rewards_frozen(env: &Env, hunt_id: u64) -> bool {
+        let key = (Self::REWARDS_FROZEN_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or(false)
+    }
+
+    /// Records that a hunt closed short of its player minimum, so it pays no rewards.
+    pub fn set_rewards_void(env: &Env, hunt_id: u64) {
+        let key = (Self::REWARDS_VOID_KEY, hunt_id);
+        env.storage().persistent().set(&key, &true);
+    }
+
+    /// Returns whether a hunt closed short of its player minimum.
+    pub fn are_rewards_void(env: &Env, hunt_id: u64) -> bool {
+        let key = (Self::REWARDS_VOID_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or(false)
+    }
+
+    /// Pauses or resumes new registrations for one hunt.
+    pub fn set_registration_paused(env: &Env, hunt_id: u64, paused: bool) {
+        let key = (Self::REGISTRATION_PAUSED_KEY, hunt_id);
+        if paused {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Returns whether new registrations for a hunt are paused (false if never set).
+    pub fn is_registration_paused(env: &Env, hunt_id: u64) -> bool {
+        let key = (Self::REGISTRATION_PAUSED_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or(false)
+    }
+
+    /// Records that a cancelled hunt's clues and player progress have all been deleted.
+    pub fn set_hunt_purged(env: &Env, hunt_id: u64) {
+        let key = (Self::PURGED_KEY, hunt_id);
+        env.storage().persistent().set(&key, &true);
+    }
+
+    /// Returns whether a hunt has been fully purged (false if never set).
+    pub fn is_hunt_purged(env: &Env, hunt_id: u64) -> bool {
+        let key = (Self::PURGED_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or(false)
+    }
+
+    // ========== Escrow Ledger Functions ==========
+
+    /// Returns the tokens currently held in escrow for a hunt (0 if never funded).
+    pub fn get_escrow_balance(env: &Env, hunt_id: u64) -> i128 {
+        let key = (Self::ESCROW_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Overwrites the escrowed token balance for a hunt.
+    pub fn set_escrow_balance(env: &Env, hunt_id: u64, balance: i128) {
+        let key = (Self::ESCROW_KEY, hunt_id);
+        env.storage().persistent().set(&key, &balance);
+    }
+
+    /// Records the entry fee a player paid for a hunt (0 removes the record).
+    pub fn set_entry_fee_paid(env: &Env, hunt_id: u64, player: &Address, amount: i128) {
+        let key = (Self::FEE_PAID_KEY, hunt_id, player.clone());
+        if amount == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &amount);
+        }
+    }
+
+    /// Returns the entry fee a player paid for a hunt and has not had refunded.
+    pub fn get_entry_fee_paid(env: &Env, hunt_id: u64, player: &Address) -> i128 {
+        let key = (Self::FEE_PAID_KEY, hunt_id, player.clone());
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Returns the total entry fees held for a hunt that could still be refunded.
+    pub fn get_fees_held(env: &Env, hunt_id: u64) -> i128 {
+        let key = (Self::FEES_HELD_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Overwrites the total entry fees held for a hunt.
+    pub fn set_fees_held(env: &Env, hunt_id: u64, amount: i128) {
+        let key = (Self::FEES_HELD_KEY, hunt_id);
+        env.storage().persistent().set(&key, &amount);
+    }
+
+    // ========== Per-Hunt Counters ==========
+
+    /// Adjusts the number of players who have completed a hunt.
+    pub fn adjust_completions(env: &Env, hunt_id: u64, delta: i32) {
+        let key = (Self::COMPLETIONS_KEY, hunt_id);
+        let next = Self::get_completions(env, hunt_id).saturating_add_signed(delta);
+        env.storage().persistent().set(&key, &next);
+    }
+
+    /// Returns the number of players who have completed a hunt.
+    pub fn get_completions(env: &Env, hunt_id: u64) -> u32 {
+        let key = (Self::COMPLETIONS_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Counts one more player solving a clue and returns the new count.
+    pub fn increment_clue_solves(env: &Env, hunt_id: u64, clue_id: u32) -> u32 {
+        let key = (Self::CLUE_SOLVES_KEY, hunt_id, clue_id);
+        let next = Self::get_clue_solves(env, hunt_id, clue_id).saturating_add(1);
+        env.storage().persistent().set(&key, &next);
+        next
+    }
+
+    /// Returns the number of players who have solved a clue.
+    pub fn get_clue_solves(env: &Env, hunt_id: u64, clue_id: u32) -> u32 {
+        let key = (Self::CLUE_SOLVES_KEY, hunt_id, clue_id);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Adds `amount` to the rewards paid out by a hunt.
+    pub fn add_hunt_rewards_distributed(env: &Env, hunt_id: u64, amount: i128) {
+        let key = (Self::HUNT_DISTRIBUTED_KEY, hunt_id);
+        let current = Self::get_hunt_rewards_distributed(env, hunt_id);
+        env.storage().persistent().set(&key, &(current + amount));
+    }
+
+    /// Returns the rewards paid out by a hunt.
+    pub fn get_hunt_rewards_distributed(env: &Env, hunt_id: u64) -> i128 {
+        let key = (Self::HUNT_DISTRIBUTED_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    // ========== Vesting Functions ==========
+
+    /// Records how much of a player's not yet fully vested reward they have claimed
+    /// (0 clears the record).
+    pub fn set_vested_claimed(env: &Env, hunt_id: u64, player: &Address, amount: i128) {
+        let key = (Self::VESTED_CLAIMED_KEY, hunt_id, player.clone());
+        if amount > 0 {
+            env.storage().persistent().set(&key, &amount);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    /// Returns how much of a player's not yet fully vested reward they have claimed.
+    pub fn get_vested_claimed(env: &Env, hunt_id: u64, player: &Address) -> i128 {
+        let key = (Self::VESTED_CLAIMED_KEY, hunt_id, player.clone());
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Adjusts the hunt's total of partial vested payouts to winners whose slots still
+    /// count as unclaimed.
+    pub fn adjust_vested_paid(env: &Env, hunt_id: u64, delta: i128) {
+        let key = (Self::VESTED_PAID_KEY, hunt_id);
+        let next = Self::get_vested_paid(env, hunt_id) + delta;
+        env.storage().persistent().set(&key, &next);
+    }
+
+    /// Returns the hunt's total of partial vested payouts to unclaimed slots.
+    pub fn get_vested_paid(env: &Env, hunt_id: u64) -> i128 {
+        let key = (Self::VESTED_PAID_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    // ========== Raffle Functions ==========
+
+    /// Stores the winners drawn for a raffle hunt.
+    pub fn set_raffle_winners(env: &Env, hunt_id: u64, winners: &Vec<Address>) {
+        let key = (Self::RAFFLE_KEY, hunt_id);
+        env.storage().persistent().set(&key, winners);
+    }
+
+    /// Returns the winners drawn for a raffle hunt, or None if no draw has happened.
+    pub fn get_raffle_winners(env: &Env, hunt_id: u64) -> Option<Vec<Address>> {
+        let key = (Self::RAFFLE_KEY, hunt_id);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Appends a paid claim to the hunt's distribution record. Each row is its own entry,
+    /// keyed by its position, so reading a page never loads the whole record.
+    pub fn add_distribution(env: &Env, hunt_id: u64, winner: &Address, amount: i128) {
+        let index = Self::get_distribution_count(env, hunt_id);
+        let key = (Self::DISTRIBUTION_KEY, hunt_id, index);
+        env.storage().persistent().set(&key, &(winner.clone(), amount));
+        let count_key = (Self::DISTRIBUTION_COUNT_KEY, hunt_id);
+        env.storage().persistent().set(&count_key, &(index + 1));
+    }
+
+    /// Returns the number of rows in the hunt's distribution record.
+    pub fn get_distribution_count(env: &Env, hunt_id: u64) -> u32 {
+        let key = (Self::DISTRIBUTION_COUNT_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Returns row `index` of the hunt's distribution record: the winner and amount paid.
+    pub fn get_distribution(env: &Env, hunt_id: u64, index: u32) -> Option<(Address, i128)> {
+        let key = (Self::DISTRIBUTION_KEY, hunt_id, index);
+        env.storage().persistent().get(&key)
+    }
+
+    /// Queues a player who tied for the last winner slot under `TiePolicy::ExpandSlots`.
+    pub fn add_pending_tie(env: &Env, hunt_id: u64, player: &Address) {
+        let key = (Self::PENDING_TIES_KEY, hunt_id);
+        let mut pending = Self::get_pending_ties(env, hunt_id);
+        pending.push_back(player.clone());
+        env.storage().persistent().set(&key, &pending);
+    }
+
+    /// Returns the players waiting on `approve_tie_slots`, in completion order.
+    pub fn get_pending_ties(env: &Env, hunt_id: u64) -> Vec<Address> {
+        let key = (Self::PENDING_TIES_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Clears the hunt's pending ties once the admin has approved them.
+    pub fn remove_pending_ties(env: &Env, hunt_id: u64) {
+        let key = (Self::PENDING_TIES_KEY, hunt_id);
+        env.storage().persistent().remove(&key);
+    }
+
+    // ========== Leaderboard Cache Functions ==========
+
+    /// Stores the hunt's cached top-N leaderboard, best first.
+    pub fn set_leaderboard_cache(env: &Env, hunt_id: u64, entries: &Vec<LeaderboardEntry>) {
+        let key = (Self::LEADERBOARD_CACHE_KEY, hunt_id);
+        env.storage().persistent().set(&key, entries);
+    }
+
+    /// Returns the hunt's cached leaderboard, or an empty Vec if none is kept.
+    pub fn get_leaderboard_cache(env: &Env, hunt_id: u64) -> Vec<LeaderboardEntry> {
+        let key = (Self::LEADERBOARD_CACHE_KEY, hunt_id);
+        env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Drops the hunt's cached leaderboard.
+    pub fn remove_leaderboard_cache(env: &Env, hunt_id: u64) {
+        let key = (Self::LEADERBOARD_CACHE_KEY, hunt_id);
+        env.storage().persistent().remove(&key);
+    }
+
+    // ========== Global Stat Counters ==========
+
+    /// Adjusts the number of hunts currently in Active status.
+    pub fn adjust_active_hunts(env: &Env, delta: i64) {
+        let current: u64 = env.storage().persistent().get(&Self::ACTIVE_COUNT_KEY).unwrap_or(0);
+        let next = current.saturating_add_signed(delta);
+        env.storage().persistent().set(&Self::ACTIVE_COUNT_KEY, &next);
+    }
+
+    /// Returns the number of hunts currently in Active status.
+    pub fn get_active_hunts(env: &Env) -> u64 {
+        env.storage().persistent().get(&Self::ACTIVE_COUNT_KEY).unwrap_or(0)
+    }
+
+    /// Adjusts the number of player registrations across all hunts.
+    pub fn adjust_total_players(env: &Env, delta: i64) {
+        let current: u64 = env.storage().persistent().get(&Self::PLAYER_COUNT_KEY).unwrap_or(0);
+        let next = current.saturating_add_signed(delta);
+        env.storage().persistent().set(&Self::PLAYER_COUNT_KEY, &next);
+    }
+
+    /// Returns the number of player registrations across all hunts.
+    pub fn get_total_players(env: &Env) -> u64 {
+        env.storage().persistent().get(&Self::PLAYER_COUNT_KEY).unwrap_or(0)
+    }
+
+    /// Adds `amount` to the running total of rewards paid out.
+    pub fn add_rewards_distributed(env: &Env, amount: i128) {
+        let current = Self::get_rewards_distributed(env);
+        env.storage().persistent().set(&Self::DISTRIBUTED_KEY, &(current + amount));
+    }
+
+    /// Returns the running total of rewards paid out.
+    pub fn get_rewards_distributed(env: &Env) -> i128 {
+        env.storage().persistent().get(&Self::DISTRIBUTED_KEY).unwrap_or(0)
+    }
+}
@@ -1,3 +1,4 @@
+#![allow(clippy::module_inception)]
 #[cfg(test)]
 extern crate std;
 
@@ -7,22 +8,149 @@ use std::string::ToString;
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{Env, String, Address};
+    use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec};
+    use soroban_sdk::xdr::ToXdr;
     // Bring Soroban testutils traits into scope (generate addresses, set ledger info, register contracts).
-    use soroban_sdk::testutils::{Address as _, Ledger as _, Register as _};
+    use soroban_sdk::testutils::{Address as _, Ledger as _};
+    use soroban_sdk::token::{StellarAssetClient, TokenClient};
     use crate::errors::{HuntErrorCode, HuntError};
-    use crate::types::HuntStatus;
+    use crate::test_utils::{get_all_events_for_hunt, HuntEvent};
+    use crate::types::{
+        AnswerCommitment, AnswerRejectedEvent, AttemptState, CompletionPolicy, Durability,
+        HuntStatus, HuntStatusChangedEvent, PlayerDisqualifiedEvent, ClueInfo, ClueInput,
+        CountersRecomputedEvent, CreatorStats, DescriptionRef, EventLevel, HashAlgo, Location,
+        HuntCancelledEvent, ProgressResetEvent, RewardClaimedEvent, RewardMode,
+        RewardTokenSwappedEvent, RewardsExhaustedEvent, PlayerProgress, PlayerProgressV0,
+        PlayerProgressV1, PlayerProgressV2, TiePolicy,
+    };
     use crate::storage::Storage;
     use crate::HuntyCore;
 
     /// Runs a closure inside a registered HuntyCore contract context so storage is accessible.
     fn with_core_contract<T>(env: &Env, f: impl FnOnce(&Env, &Address) -> T) -> T {
-        let contract_id = env.register_contract(None, HuntyCore);
+        let contract_id = env.register(HuntyCore, ());
         env.as_contract(&contract_id, || {
             f(env, &contract_id)
         })
     }
 
+    /// Runs `f` in a fresh frame of the registered contract. Mocked auth lets an address
+    /// authorize only once per frame, so repeated calls by the same player need this.
+    /// Tests using it must call `mock_all_auths_allowing_non_root_auth`.
+    fn in_frame<T>(env: &Env, cid: &Address, f: impl FnOnce() -> T) -> T {
+        env.as_contract(cid, f)
+    }
+
+    /// Creates a Draft hunt titled "Hunt <id>" owned by `creator` with `clues` clues,
+    /// each answered "answer".
+    /// Must be called inside a contract context with mocked auths.
+    fn create_hunt_with_clues(env: &Env, creator: &Address, clues: u32, required: bool) -> u64 {
+        let title = std::format!("Hunt {}", Storage::get_hunt_counter(env) + 1);
+        let hid = HuntyCore::create_hunt(
+            env.clone(),
+            creator.clone(),
+            String::from_str(env, &title),
+            String::from_str(env, "Desc"),
+            None,
+            None,
+        )
+        .unwrap();
+        for _ in 0..clues {
+            HuntyCore::add_clue(
+                env.clone(),
+                hid,
+                creator.clone(),
+                String::from_str(env, "Q"),
+                String::from_str(env, "answer"),
+                10,
+                required,
+            )
+            .unwrap();
+        }
+        hid
+    }
+
+    /// Creates a hunt with `clues` required clues and activates it.
+    fn create_active_hunt(env: &Env, creator: &Address, clues: u32) -> u64 {
+        let hid = create_hunt_with_clues(env, creator, clues, true);
+        HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+        hid
+    }
+
+    /// Creates a one-clue hunt funded with `pool` of `token`, split across `max_winners`,
+    /// and activates it. The creator must hold at least `pool`.
+    fn create_funded_hunt(
+        env: &Env,
+        creator: &Address,
+        token: &Address,
+        pool: i128,
+        max_winners: u32,
+    ) -> u64 {
+        let hid = create_hunt_with_clues(env, creator, 1, true);
+        HuntyCore::set_max_winners(env.clone(), hid, max_winners).unwrap();
+        HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), pool).unwrap();
+        HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+        hid
+    }
+
+    /// Registers `player` and solves every clue of a hunt built by the helpers above.
+    fn register_and_complete(env: &Env, cid: &Address, hunt_id: u64, player: &Address) {
+        in_frame(env, cid, || {
+            HuntyCore::register_player(env.clone(), hunt_id, player.clone()).unwrap()
+        });
+        for clue in HuntyCore::list_clues(env.clone(), hunt_id).iter() {
+            in_frame(env, cid, || {
+                HuntyCore::submit_answer(
+                    env.clone(),
+                    hunt_id,
+                    clue.clue_id,
+                    player.clone(),
+                    String::from_str(env, "answer"),
+                )
+                .unwrap()
+            });
+        }
+    }
+
+    /// Registers a Stellar asset contract and mints `amount` to `to`. Requires mocked auths.
+    fn create_funded_token(env: &Env, to: &Address, amount: i128) -> Address {
+        let issuer = Address::generate(env);
+        let token = env.register_stellar_asset_contract_v2(issuer).address();
+        StellarAssetClient::new(env, &token).mint(to, &amount);
+        token
+    }
+
+    /// Stand-in NFT contract that records every `mint_reward` call.
+    #[soroban_sdk::contract]
+    struct MockNft;
+
+    #[soroban_sdk::contractimpl]
+    impl MockNft {
+        pub fn mint_reward(env: Env, to: Address, hunt_id: u64, winner_index: u32) {
+            let mut minted = Self::minted(env.clone());
+            minted.push_back((to, hunt_id, winner_index));
+            env.storage().instance().set(&soroban_sdk::symbol_short!("MINTED"), &minted);
+        }
+
+        pub fn minted(env: Env) -> Vec<(Address, u64, u32)> {
+            env.storage()
+                .instance()
+                .get(&soroban_sdk::symbol_short!("MINTED"))
+                .unwrap_or_else(|| Vec::new(&env))
+        }
+    }
+
+    /// Stand-in token contract reporting 2 decimals, unlike Stellar asset contracts.
+    #[soroban_sdk::contract]
+    struct MockCentsToken;
+
+    #[soroban_sdk::contractimpl]
+    impl MockCentsToken {
+        pub fn decimals(_env: Env) -> u32 {
+            2
+        }
+    }
+
      #[test]
     fn test_error_with_context_display() {
         let err = HuntError::HuntNotFound { hunt_id: 42 };
@@ -77,6 +205,7 @@ mod test {
     fn test_create_hunt_success() {
         let env = Env::default();
         env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
         let creator = Address::generate(&env);
         let title = String::from_str(&env, "Test Hunt");
         let description = String::from_str(&env, "This is a test hunt description");
@@ -105,7 +234,7 @@ mod test {
         assert_eq!(hunt.total_clues, 0);
         assert_eq!(hunt.required_clues, 0);
         assert_eq!(hunt.reward_config.xlm_pool, 0);
-        assert_eq!(hunt.reward_config.nft_enabled, false);
+        assert!(!hunt.reward_config.nft_enabled);
         assert_eq!(hunt.reward_config.max_winners, 0);
         assert_eq!(hunt.reward_config.claimed_count, 0);
         assert!(hunt.created_at > 0);
@@ -117,6 +246,7 @@ mod test {
     fn test_create_hunt_with_end_time() {
         let env = Env::default();
         env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
         let creator = Address::generate(&env);
         let title = String::from_str(&env, "Timed Hunt");
         let description = String::from_str(&env, "A hunt with an end time");
@@ -141,6 +271,7 @@ mod test {
     fn test_create_hunt_empty_title() {
         let env = Env::default();
         env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
         let creator = Address::generate(&env);
         let title = String::from_str(&env, "");
         let description = String::from_str(&env, "Valid description");
@@ -156,6 +287,7 @@ mod test {
     fn test_create_hunt_title_too_long() {
         let env = Env::default();
         env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
         let creator = Address::generate(&env);
         // Create a title longer than 200 characters
         let long_title = String::from_str(&env, &"a".repeat(201));
@@ -172,6 +304,7 @@ mod test {
     fn test_create_hunt_title_exactly_max_length() {
         let env = Env::default();
         env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
         let creator = Address::generate(&env);
         // Create a title exactly 200 characters (should be valid)
         let title = String::from_str(&env, &"a".repeat(200));
@@ -188,6 +321,7 @@ mod test {
     fn test_create_hunt_description_too_long() {
         let env = Env::default();
         env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
         let creator = Address::generate(&env);
         let title = String::from_str(&env, "Valid Title");
         // Create a description longer than 2000 characters
@@ -204,6 +338,7 @@ mod test {
     fn test_create_hunt_description_exactly_max_length() {
         let env = Env::default();
         env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
         let creator = Address::generate(&env);
         let title = String::from_str(&env, "Valid Title");
         // Create a description exactly 2000 characters (should be valid)
@@ -220,13 +355,14 @@ mod test {
     fn test_create_hunt_unique_ids() {
         let env = Env::default();
         env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
         let creator = Address::generate(&env);
         let title1 = String::from_str(&env, "Hunt 1");
         let title2 = String::from_str(&env, "Hunt 2");
         let title3 = String::from_str(&env, "Hunt 3");
         let description = String::from_str(&env, "Description");
 
-        let (hunt_id1, hunt_id2, hunt_id3) = with_core_contract(&env, |env, _cid| {
+        let (hunt_id1, hunt_id2, hunt_id3) = with_core_contract(&env, |env, cid| {
             let hunt_id1 = HuntyCore::create_hunt(
                 env.clone(),
                 creator.clone(),
@@ -236,24 +372,28 @@ mod test {
                 None,
             )
             .unwrap();
-            let hunt_id2 = HuntyCore::create_hunt(
-                env.clone(),
-                creator.clone(),
-                title2,
-                description.clone(),
-                None,
-                None,
-            )
-            .unwrap();
-            let hunt_id3 = HuntyCore::create_hunt(
-                env.clone(),
-                creator.clone(),
-                title3,
-                description,
-                None,
-                None,
-            )
-            .unwrap();
+            let hunt_id2 = in_frame(env, cid, || {
+                HuntyCore::create_hunt(
+                    env.clone(),
+                    creator.clone(),
+                    title2,
+                    description.clone(),
+                    None,
+                    None,
+                )
+                .unwrap()
+            });
+            let hunt_id3 = in_frame(env, cid, || {
+                HuntyCore::create_hunt(
+                    env.clone(),
+                    creator.clone(),
+                    title3,
+                    description,
+                    None,
+                    None,
+                )
+                .unwrap()
+            });
             (hunt_id1, hunt_id2, hunt_id3)
         });
 
@@ -269,12 +409,13 @@ mod test {
     fn test_create_hunt_different_creators() {
         let env = Env::default();
         env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
         let creator1 = Address::generate(&env);
         let creator2 = Address::generate(&env);
         let title = String::from_str(&env, "Test Hunt");
         let description = String::from_str(&env, "Description");
 
-        let (hunt_id1, hunt_id2, hunt1, hunt2) = with_core_contract(&env, |env, _cid| {
+        let (_hunt_id1, _hunt_id2, hunt1, hunt2) = with_core_contract(&env, |env, _cid| {
             let hunt_id1 = HuntyCore::create_hunt(
                 env.clone(),
                 creator1.clone(),
@@ -307,11 +448,12 @@ mod test {
     fn test_create_hunt_counter_increments() {
         let env = Env::default();
         env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
         let creator = Address::generate(&env);
         let title = String::from_str(&env, "Test Hunt");
         let description = String::from_str(&env, "Description");
 
-        let (start_counter, hunt_id1, counter_after_1, hunt_id2, counter_after_2) = with_core_contract(&env, |env, _cid| {
+        let (start_counter, hunt_id1, counter_after_1, hunt_id2, counter_after_2) = with_core_contract(&env, |env, cid| {
             // Verify counter starts at 0
             let start_counter = Storage::get_hunt_counter(env);
             
@@ -329,8 +471,12 @@ mod test {
             // Counter should be 1 after first hunt
             let counter_after_1 = Storage::get_hunt_counter(env);
             
-            // Create second hunt
-            let hunt_id2 = HuntyCore::create_hunt(env.clone(), creator.clone(), title, description, None, None).unwrap();
+            // Create second hunt (titles are unique per creator)
+            let title2 = String::from_str(env, "Test Hunt 2");
+            let hunt_id2 = in_frame(env, cid, || {
+                HuntyCore::create_hunt(env.clone(), creator.clone(), title2, description, None, None)
+                    .unwrap()
+            });
             
             // Counter should be 2 after second hunt
             let counter_after_2 = Storage::get_hunt_counter(env);
@@ -349,6 +495,7 @@ mod test {
     fn test_create_hunt_default_reward_config() {
         let env = Env::default();
         env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
         let creator = Address::generate(&env);
         let title = String::from_str(&env, "Test Hunt");
         let description = String::from_str(&env, "Description");
@@ -362,7 +509,7 @@ mod test {
 
         // Verify default reward config values
         assert_eq!(reward_config.xlm_pool, 0);
-        assert_eq!(reward_config.nft_enabled, false);
+        assert!(!reward_config.nft_enabled);
         assert_eq!(reward_config.nft_contract, None);
         assert_eq!(reward_config.max_winners, 0);
         assert_eq!(reward_config.claimed_count, 0);
@@ -372,6 +519,7 @@ mod test {
     fn test_create_hunt_created_at_timestamp() {
         let env = Env::default();
         env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
         let creator = Address::generate(&env);
         let title = String::from_str(&env, "Test Hunt");
         let description = String::from_str(&env, "Description");
@@ -389,6 +537,29 @@ mod test {
         assert!(current_time - hunt.created_at < 10);
     }
 
+    #[test]
+    fn test_create_hunt_fails_gracefully_when_ids_run_out() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        with_core_contract(&env, |env, cid| {
+            let counter = soroban_sdk::symbol_short!("CNTR");
+            env.storage().persistent().set(&counter, &(u64::MAX - 1));
+            let create = |title: &str| {
+                in_frame(env, cid, || {
+                    let title = String::from_str(env, title);
+                    let desc = String::from_str(env, "Desc");
+                    HuntyCore::create_hunt(env.clone(), creator.clone(), title, desc, None, None)
+                })
+            };
+            assert_eq!(create("Last"), Ok(u64::MAX));
+            assert_eq!(create("One too many"), Err(HuntErrorCode::HuntIdSpaceExhausted));
+            assert_eq!(Storage::get_hunt_counter(env), u64::MAX);
+        });
+    }
+
     // ========== add_clue() / get_clue() / list_clues() Tests ==========
 
     #[test]
@@ -415,6 +586,7 @@ mod test {
             let clue_id = HuntyCore::add_clue(
                 env.clone(),
                 hunt_id,
+                creator.clone(),
                 question.clone(),
                 answer,
                 10,
@@ -440,24 +612,29 @@ mod test {
     fn test_add_clue_unauthorized() {
         let env = Env::default();
         env.ledger().set_timestamp(1_700_000_000);
-        // Do NOT mock auth — require_auth(creator) will fail.
+        env.mock_all_auths();
         let creator = Address::generate(&env);
         let title = String::from_str(&env, "Test Hunt");
         let description = String::from_str(&env, "Description");
         let question = String::from_str(&env, "What is 2 + 2?");
         let answer = String::from_str(&env, "four");
 
-        with_core_contract(&env, |env, _cid| {
+        let (cid, hunt_id) = with_core_contract(&env, |env, cid| {
             let hunt_id = HuntyCore::create_hunt(
                 env.clone(),
-                creator,
+                creator.clone(),
                 title,
                 description,
                 None,
                 None,
             )
             .unwrap();
-            let _ = HuntyCore::add_clue(env.clone(), hunt_id, question, answer, 10, true);
+            (cid.clone(), hunt_id)
+        });
+        // Drop the mocked auths — require_auth(creator) will fail.
+        env.set_auths(&[]);
+        env.as_contract(&cid, || {
+            let _ = HuntyCore::add_clue(env.clone(), hunt_id, creator.clone(), question, answer, 10, true);
         });
     }
 
@@ -477,16 +654,16 @@ mod test {
         let (id1, id2, id3) = with_core_contract(&env, |env, _cid| {
             let hid = HuntyCore::create_hunt(
                 env.clone(),
-                creator,
+                creator.clone(),
                 title,
                 description,
                 None,
                 None,
             )
             .unwrap();
-            let id1 = HuntyCore::add_clue(env.clone(), hid, q1, a.clone(), 1, false).unwrap();
-            let id2 = HuntyCore::add_clue(env.clone(), hid, q2, a.clone(), 1, false).unwrap();
-            let id3 = HuntyCore::add_clue(env.clone(), hid, q3, a, 1, false).unwrap();
+            let id1 = HuntyCore::add_clue(env.clone(), hid, creator.clone(), q1, a.clone(), 1, false).unwrap();
+            let id2 = HuntyCore::add_clue(env.clone(), hid, creator.clone(), q2, a.clone(), 1, false).unwrap();
+            let id3 = HuntyCore::add_clue(env.clone(), hid, creator.clone(), q3, a, 1, false).unwrap();
             (id1, id2, id3)
         });
 
@@ -510,7 +687,7 @@ mod test {
         let (hash1, hash2) = with_core_contract(&env, |env, _cid| {
             let hid = HuntyCore::create_hunt(
                 env.clone(),
-                creator,
+                creator.clone(),
                 title,
                 description.clone(),
                 None,
@@ -520,6 +697,7 @@ mod test {
             let cid = HuntyCore::add_clue(
                 env.clone(),
                 hid,
+                creator.clone(),
                 question.clone(),
                 answer1,
                 5,
@@ -528,10 +706,11 @@ mod test {
             .unwrap();
             let c = Storage::get_clue(env, hid, cid).unwrap();
             let h1 = c.answer_hash;
+            let creator2 = Address::generate(env);
             let hid2 = HuntyCore::create_hunt(
                 env.clone(),
-                Address::generate(&env),
-                String::from_str(&env, "H2"),
+                creator2.clone(),
+                String::from_str(env, "H2"),
                 description,
                 None,
                 None,
@@ -540,6 +719,7 @@ mod test {
             let _cid2 = HuntyCore::add_clue(
                 env.clone(),
                 hid2,
+                creator2,
                 question,
                 answer2,
                 5,
@@ -568,14 +748,14 @@ mod test {
         let info = with_core_contract(&env, |env, _cid| {
             let hid = HuntyCore::create_hunt(
                 env.clone(),
-                creator,
+                creator.clone(),
                 title,
                 description,
                 None,
                 None,
             )
             .unwrap();
-            let _ = HuntyCore::add_clue(env.clone(), hid, question.clone(), answer, 7, true);
+            let _ = HuntyCore::add_clue(env.clone(), hid, creator.clone(), question.clone(), answer, 7, true);
             HuntyCore::get_clue(env.clone(), hid, 1).unwrap()
         });
 
@@ -597,7 +777,7 @@ mod test {
         let err = with_core_contract(&env, |env, _cid| {
             let hid = HuntyCore::create_hunt(
                 env.clone(),
-                creator,
+                creator.clone(),
                 title,
                 description,
                 None,
@@ -622,7 +802,7 @@ mod test {
         let list = with_core_contract(&env, |env, _cid| {
             let hid = HuntyCore::create_hunt(
                 env.clone(),
-                creator,
+                creator.clone(),
                 title,
                 description,
                 None,
@@ -650,15 +830,15 @@ mod test {
         let list = with_core_contract(&env, |env, _cid| {
             let hid = HuntyCore::create_hunt(
                 env.clone(),
-                creator,
+                creator.clone(),
                 title,
                 description,
                 None,
                 None,
             )
             .unwrap();
-            HuntyCore::add_clue(env.clone(), hid, q1, a.clone(), 1, false).unwrap();
-            HuntyCore::add_clue(env.clone(), hid, q2, a, 2, true).unwrap();
+            HuntyCore::add_clue(env.clone(), hid, creator.clone(), q1, a.clone(), 1, false).unwrap();
+            HuntyCore::add_clue(env.clone(), hid, creator.clone(), q2, a, 2, true).unwrap();
             HuntyCore::list_clues(env.clone(), hid)
         });
 
@@ -682,7 +862,8 @@ mod test {
         let answer = String::from_str(&env, "a");
 
         let err = with_core_contract(&env, |env, _cid| {
-            HuntyCore::add_clue(env.clone(), 9999, question, answer, 1, false).unwrap_err()
+            HuntyCore::add_clue(env.clone(), 9999, Address::generate(env), question, answer, 1, false)
+                .unwrap_err()
         });
 
         assert_eq!(err, HuntErrorCode::HuntNotFound);
@@ -702,14 +883,14 @@ mod test {
         let err = with_core_contract(&env, |env, _cid| {
             let hid = HuntyCore::create_hunt(
                 env.clone(),
-                creator,
+                creator.clone(),
                 title,
                 description,
                 None,
                 None,
             )
             .unwrap();
-            HuntyCore::add_clue(env.clone(), hid, empty, answer, 1, false).unwrap_err()
+            HuntyCore::add_clue(env.clone(), hid, creator.clone(), empty, answer, 1, false).unwrap_err()
         });
 
         assert_eq!(err, HuntErrorCode::InvalidQuestion);
@@ -729,14 +910,14 @@ mod test {
         let err = with_core_contract(&env, |env, _cid| {
             let hid = HuntyCore::create_hunt(
                 env.clone(),
-                creator,
+                creator.clone(),
                 title,
                 description,
                 None,
                 None,
             )
             .unwrap();
-            HuntyCore::add_clue(env.clone(), hid, question, empty, 1, false).unwrap_err()
+            HuntyCore::add_clue(env.clone(), hid, creator.clone(), question, empty, 1, false).unwrap_err()
         });
 
         assert_eq!(err, HuntErrorCode::InvalidAnswer);
@@ -756,14 +937,14 @@ mod test {
         let err = with_core_contract(&env, |env, _cid| {
             let hid = HuntyCore::create_hunt(
                 env.clone(),
-                creator,
+                creator.clone(),
                 title,
                 description,
                 None,
                 None,
             )
             .unwrap();
-            HuntyCore::add_clue(env.clone(), hid, question, ws, 1, false).unwrap_err()
+            HuntyCore::add_clue(env.clone(), hid, creator.clone(), question, ws, 1, false).unwrap_err()
         });
 
         assert_eq!(err, HuntErrorCode::InvalidAnswer);
@@ -784,7 +965,7 @@ mod test {
         let err = with_core_contract(&env, |env, _cid| {
             let hid = HuntyCore::create_hunt(
                 env.clone(),
-                creator,
+                creator.clone(),
                 title,
                 description,
                 None,
@@ -795,6 +976,7 @@ mod test {
                 HuntyCore::add_clue(
                     env.clone(),
                     hid,
+                    creator.clone(),
                     question.clone(),
                     answer.clone(),
                     1,
@@ -802,12 +984,43 @@ mod test {
                 )
                 .unwrap();
             }
-            HuntyCore::add_clue(env.clone(), hid, question, answer, 1, false).unwrap_err()
+            HuntyCore::add_clue(env.clone(), hid, creator.clone(), question, answer, 1, false).unwrap_err()
         });
 
         assert_eq!(err, HuntErrorCode::TooManyClues);
     }
 
+    #[test]
+    fn test_admin_configured_clue_limit() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+
+        with_core_contract(&env, |env, cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            assert_eq!(HuntyCore::get_max_clues_per_hunt(env.clone()), 100);
+            HuntyCore::set_max_clues_per_hunt(env.clone(), 3).unwrap();
+            assert_eq!(HuntyCore::get_max_clues_per_hunt(env.clone()), 3);
+
+            let hid = create_hunt_with_clues(env, &creator, 3, true);
+            let add = || {
+                in_frame(env, cid, || {
+                    let text = |t: &str| String::from_str(env, t);
+                    HuntyCore::add_clue(
+                        env.clone(), hid, creator.clone(), text("Q"), text("a"), 1, false,
+                    )
+                })
+            };
+            assert_eq!(add(), Err(HuntErrorCode::TooManyClues));
+
+            // 0 lifts the cap.
+            in_frame(env, cid, || HuntyCore::set_max_clues_per_hunt(env.clone(), 0).unwrap());
+            assert_eq!(add(), Ok(4));
+        });
+    }
+
     #[test]
     fn test_add_clue_invalid_hunt_status_not_draft() {
         let env = Env::default();
@@ -832,7 +1045,7 @@ mod test {
             let mut h = Storage::get_hunt(env, hid).unwrap();
             h.status = HuntStatus::Active;
             Storage::save_hunt(env, &h);
-            HuntyCore::add_clue(env.clone(), hid, question, answer, 1, false).unwrap_err()
+            HuntyCore::add_clue(env.clone(), hid, creator.clone(), question, answer, 1, false).unwrap_err()
         });
 
         assert_eq!(err, HuntErrorCode::InvalidHuntStatus);
@@ -852,17 +1065,5388 @@ mod test {
         let err = with_core_contract(&env, |env, _cid| {
             let hid = HuntyCore::create_hunt(
                 env.clone(),
-                creator,
+                creator.clone(),
                 title,
                 description,
                 None,
                 None,
             )
             .unwrap();
-            HuntyCore::add_clue(env.clone(), hid, long_q, answer, 1, false).unwrap_err()
+            HuntyCore::add_clue(env.clone(), hid, creator.clone(), long_q, answer, 1, false).unwrap_err()
         });
 
         assert_eq!(err, HuntErrorCode::InvalidQuestion);
     }
-}
 
+    // ========== Admin Transfer Tests ==========
+
+    #[test]
+    fn test_admin_transfer_completes_on_acceptance() {
+        use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+        use soroban_sdk::IntoVal;
+
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        let cid = env.register(HuntyCore, ());
+        let client = crate::HuntyCoreClient::new(&env, &cid);
+        client.initialize(&admin);
+        client.propose_admin(&new_admin);
+        assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+        assert_eq!(client.get_admin(), Some(admin.clone()));
+
+        // Only the stranger signs, so accepting on the proposed admin's behalf fails.
+        env.mock_auths(&[MockAuth {
+            address: &stranger,
+            invoke: &MockAuthInvoke {
+                contract: &cid,
+                fn_name: "accept_admin",
+                args: ().into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_accept_admin().is_err());
+        assert_eq!(client.get_admin(), Some(admin.clone()));
+
+        env.mock_all_auths();
+        client.accept_admin();
+        assert_eq!(client.get_admin(), Some(new_admin.clone()));
+        assert_eq!(client.get_pending_admin(), None);
+        assert_eq!(client.try_accept_admin(), Err(Ok(HuntErrorCode::Unauthorized)));
+    }
+
+    #[test]
+    fn test_cancel_admin_proposal() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+        let cid = env.register(HuntyCore, ());
+        let client = crate::HuntyCoreClient::new(&env, &cid);
+        client.initialize(&admin);
+        client.propose_admin(&new_admin);
+        client.cancel_admin_proposal();
+
+        assert_eq!(client.get_pending_admin(), None);
+        assert_eq!(client.try_accept_admin(), Err(Ok(HuntErrorCode::Unauthorized)));
+        assert_eq!(client.get_admin(), Some(admin));
+    }
+
+    // ========== emergency_withdraw() Tests ==========
+
+    #[test]
+    fn test_emergency_withdraw_while_paused() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 1_000);
+
+        let (withdrawn, hunt, contract_balance) = with_core_contract(&env, |env, cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            let hid = HuntyCore::create_hunt(
+                env.clone(),
+                creator.clone(),
+                String::from_str(env, "Hunt"),
+                String::from_str(env, "Desc"),
+                None,
+                None,
+            )
+            .unwrap();
+            HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), 1_000).unwrap();
+            HuntyCore::pause(env.clone()).unwrap();
+            let withdrawn = HuntyCore::emergency_withdraw(env.clone(), hid, recovery.clone()).unwrap();
+            let balance = TokenClient::new(env, &token).balance(cid);
+            (withdrawn, Storage::get_hunt(env, hid).unwrap(), balance)
+        });
+
+        assert_eq!(withdrawn, 1_000);
+        assert_eq!(hunt.reward_config.xlm_pool, 0);
+        assert_eq!(contract_balance, 0);
+        assert_eq!(TokenClient::new(&env, &token).balance(&recovery), 1_000);
+    }
+
+    #[test]
+    fn test_emergency_withdraw_rejected_while_unpaused() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let recovery = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 500);
+
+        let (err, hunt) = with_core_contract(&env, |env, _cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            let hid = HuntyCore::create_hunt(
+                env.clone(),
+                creator.clone(),
+                String::from_str(env, "Hunt"),
+                String::from_str(env, "Desc"),
+                None,
+                None,
+            )
+            .unwrap();
+            HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), 500).unwrap();
+            let err = HuntyCore::emergency_withdraw(env.clone(), hid, recovery.clone()).unwrap_err();
+            (err, Storage::get_hunt(env, hid).unwrap())
+        });
+
+        assert_eq!(err, HuntErrorCode::ContractNotPaused);
+        assert_eq!(hunt.reward_config.xlm_pool, 500);
+        assert_eq!(TokenClient::new(&env, &token).balance(&recovery), 0);
+    }
+
+    #[test]
+    fn test_create_hunt_rejected_while_paused() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+
+        let err = with_core_contract(&env, |env, _cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            HuntyCore::pause(env.clone()).unwrap();
+            HuntyCore::create_hunt(
+                env.clone(),
+                creator.clone(),
+                String::from_str(env, "Hunt"),
+                String::from_str(env, "Desc"),
+                None,
+                None,
+            )
+            .unwrap_err()
+        });
+
+        assert_eq!(err, HuntErrorCode::ContractPaused);
+    }
+
+    // ========== freeze_hunt_rewards() Tests ==========
+
+    #[test]
+    fn test_frozen_rewards_block_claims_but_not_play() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 200);
+
+        with_core_contract(&env, |env, cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            let hid = create_funded_hunt(env, &creator, &token, 200, 2);
+            register_and_complete(env, cid, hid, &alice);
+
+            HuntyCore::freeze_hunt_rewards(env.clone(), hid).unwrap();
+            assert!(HuntyCore::are_rewards_frozen(env.clone(), hid));
+            // Play carries on while claims are frozen.
+            register_and_complete(env, cid, hid, &bob);
+            let claim = |player: &Address| {
+                in_frame(env, cid, || {
+                    HuntyCore::claim_reward(env.clone(), hid, player.clone(), None)
+                })
+            };
+            assert_eq!(claim(&alice), Err(HuntErrorCode::RewardsFrozen));
+            assert_eq!(
+                HuntyCore::can_claim_reward(env.clone(), hid, bob.clone()),
+                (false, HuntErrorCode::RewardsFrozen as u32)
+            );
+
+            in_frame(env, cid, || HuntyCore::unfreeze_hunt_rewards(env.clone(), hid).unwrap());
+            assert!(!HuntyCore::are_rewards_frozen(env.clone(), hid));
+            assert_eq!(claim(&alice), Ok(100));
+            assert_eq!(claim(&bob), Ok(100));
+            assert_eq!(
+                HuntyCore::freeze_hunt_rewards(env.clone(), 99),
+                Err(HuntErrorCode::HuntNotFound)
+            );
+
+            let frozen: std::vec::Vec<bool> = get_all_events_for_hunt(env, hid)
+                .into_iter()
+                .filter_map(|event| match event {
+                    HuntEvent::RewardsFrozen(e) => Some(e.frozen),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(frozen, std::vec![true, false]);
+        });
+    }
+
+    // ========== Completion Policy Tests ==========
+
+    #[test]
+    fn test_percent_completion_policy_eighty_of_ten() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let hunt = with_core_contract(&env, |env, _cid| {
+            let hid = create_hunt_with_clues(env, &creator, 10, false);
+            HuntyCore::set_completion_policy(env.clone(), hid, CompletionPolicy::RequiredPercent(80))
+                .unwrap();
+            Storage::get_hunt(env, hid).unwrap()
+        });
+
+        assert_eq!(hunt.completion_policy, CompletionPolicy::RequiredPercent(80));
+        assert_eq!(hunt.total_clues, 10);
+        assert!(!hunt.is_completion_met(7, 0));
+        assert!(hunt.is_completion_met(8, 0));
+        assert!(hunt.is_completion_met(10, 0));
+    }
+
+    #[test]
+    fn test_all_clues_policy_completes_after_last_clue() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let completed_after_each = with_core_contract(&env, |env, cid| {
+            // No clue is marked required; AllClues does not need any.
+            let hid = create_hunt_with_clues(env, &creator, 3, false);
+            HuntyCore::set_completion_policy(env.clone(), hid, CompletionPolicy::AllClues)
+                .unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            let mut completed = std::vec::Vec::new();
+            for clue_id in [3, 1, 2] {
+                in_frame(env, cid, || {
+                    let answer = String::from_str(env, "answer");
+                    HuntyCore::submit_answer(env.clone(), hid, clue_id, player.clone(), answer)
+                        .unwrap()
+                });
+                let progress = Storage::get_player_progress(env, hid, &player).unwrap();
+                completed.push(progress.is_completed);
+            }
+            completed
+        });
+
+        assert_eq!(completed_after_each, std::vec![false, false, true]);
+    }
+
+    #[test]
+    fn test_required_count_policy_tracks_required_clues() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let hunt = with_core_contract(&env, |env, _cid| {
+            let hid = create_hunt_with_clues(env, &creator, 3, true);
+            HuntyCore::add_clue(
+                env.clone(),
+                hid,
+                creator.clone(),
+                String::from_str(env, "Optional"),
+                String::from_str(env, "answer"),
+                5,
+                false,
+            )
+            .unwrap();
+            Storage::get_hunt(env, hid).unwrap()
+        });
+
+        assert_eq!(hunt.completion_policy, CompletionPolicy::RequiredCount);
+        assert_eq!(hunt.total_clues, 4);
+        assert_eq!(hunt.required_clues, 3);
+        assert!(!hunt.is_completion_met(3, 2));
+        assert!(hunt.is_completion_met(3, 3));
+    }
+
+    #[test]
+    fn test_set_completion_policy_rejects_percent_over_100() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (over, zero) = with_core_contract(&env, |env, _cid| {
+            let hid = create_hunt_with_clues(env, &creator, 2, false);
+            let over = HuntyCore::set_completion_policy(
+                env.clone(),
+                hid,
+                CompletionPolicy::RequiredPercent(101),
+            )
+            .unwrap_err();
+            let zero = HuntyCore::set_completion_policy(
+                env.clone(),
+                hid,
+                CompletionPolicy::RequiredPercent(0),
+            )
+            .unwrap_err();
+            (over, zero)
+        });
+
+        assert_eq!(over, HuntErrorCode::InvalidCompletionPolicy);
+        assert_eq!(zero, HuntErrorCode::InvalidCompletionPolicy);
+    }
+
+    #[test]
+    fn test_activate_rejects_all_optional_clues_under_required_count() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        let (optional, participation, required) = with_core_contract(&env, |env, cid| {
+            let optional = create_hunt_with_clues(env, &creator, 2, false);
+            let rejected = HuntyCore::activate_hunt(env.clone(), optional);
+
+            let open = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 2, false));
+            let policy = CompletionPolicy::Participation;
+            HuntyCore::set_completion_policy(env.clone(), open, policy).unwrap();
+            let participation = HuntyCore::activate_hunt(env.clone(), open);
+
+            let one = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 2, false));
+            HuntyCore::set_clue_required(env.clone(), one, creator.clone(), 1, true).unwrap();
+            (rejected, participation, HuntyCore::activate_hunt(env.clone(), one))
+        });
+
+        assert_eq!(optional, Err(HuntErrorCode::NoRequiredClues));
+        assert_eq!(participation, Ok(()));
+        assert_eq!(required, Ok(()));
+    }
+
+    // ========== get_leaderboard() Tests ==========
+
+    #[test]
+    fn test_leaderboard_breaks_score_tie_by_clue_difficulty() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let easy = Address::generate(&env);
+        let hard = Address::generate(&env);
+        let low = Address::generate(&env);
+
+        let board = with_core_contract(&env, |env, cid| {
+            let hid = HuntyCore::create_hunt(
+                env.clone(),
+                creator.clone(),
+                String::from_str(env, "Hunt"),
+                String::from_str(env, "Desc"),
+                None,
+                None,
+            )
+            .unwrap();
+            for points in [10, 10, 20, 5] {
+                in_frame(env, cid, || {
+                    let q = String::from_str(env, "Q");
+                    let a = String::from_str(env, "answer");
+                    HuntyCore::add_clue(env.clone(), hid, creator.clone(), q, a, points, false)
+                        .unwrap()
+                });
+            }
+            let policy = CompletionPolicy::RequiredPercent(25);
+            HuntyCore::set_completion_policy(env.clone(), hid, policy).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+
+            let solve = |player: &Address, clue_id: u32| {
+                in_frame(env, cid, || {
+                    let answer = String::from_str(env, "answer");
+                    HuntyCore::submit_answer(env.clone(), hid, clue_id, player.clone(), answer)
+                        .unwrap()
+                });
+            };
+            for player in [&easy, &hard, &low] {
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                });
+            }
+            // `easy` finishes first with two 10-point clues (difficulty 200); `hard` ties
+            // on 20 points later with one 20-point clue (difficulty 400).
+            solve(&easy, 1);
+            solve(&easy, 2);
+            env.ledger().set_timestamp(1_700_000_100);
+            solve(&hard, 3);
+            solve(&low, 4);
+            HuntyCore::get_leaderboard(env.clone(), hid, 10)
+        });
+
+        assert_eq!(board.len(), 3);
+        let first = board.get(0).unwrap();
+        let second = board.get(1).unwrap();
+        assert_eq!((first.player, first.total_score, first.difficulty_score), (hard, 20, 400));
+        assert_eq!((second.player, second.total_score, second.difficulty_score), (easy, 20, 200));
+        assert!(second.completed_at < first.completed_at);
+        assert_eq!(board.get(2).unwrap().player, low);
+    }
+
+    #[test]
+    fn test_leaderboard_saturates_scores_for_huge_clue_points() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let both = Address::generate(&env);
+        let one = Address::generate(&env);
+        let small = Address::generate(&env);
+
+        let board = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 0, false);
+            for points in [u32::MAX, u32::MAX, 7] {
+                in_frame(env, cid, || {
+                    let q = String::from_str(env, "Q");
+                    let a = String::from_str(env, "answer");
+                    HuntyCore::add_clue(env.clone(), hid, creator.clone(), q, a, points, false)
+                        .unwrap()
+                });
+            }
+            let policy = CompletionPolicy::Participation;
+            HuntyCore::set_completion_policy(env.clone(), hid, policy).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            for (player, clue_ids) in [(&both, &[1, 2][..]), (&one, &[1]), (&small, &[3])] {
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                });
+                for &clue_id in clue_ids {
+                    in_frame(env, cid, || {
+                        let answer = String::from_str(env, "answer");
+                        HuntyCore::submit_answer(env.clone(), hid, clue_id, player.clone(), answer)
+                            .unwrap()
+                    });
+                }
+            }
+            HuntyCore::get_leaderboard(env.clone(), hid, 10)
+        });
+
+        let max_square = (u32::MAX as u64) * (u32::MAX as u64);
+        let rows: std::vec::Vec<_> = board
+            .iter()
+            .map(|e| (e.player, e.total_score, e.difficulty_score))
+            .collect();
+        assert_eq!(
+            rows,
+            std::vec![
+                (both, u32::MAX, u64::MAX),
+                (one, u32::MAX, max_square),
+                (small, 7, 49),
+            ]
+        );
+    }
+
+    // ========== get_player_hunts() Tests ==========
+
+    #[test]
+    fn test_get_player_hunts_tracks_register_and_unregister() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (h1, h2, h3, before, page, after, events) = with_core_contract(&env, |env, cid| {
+            let h1 = create_active_hunt(env, &creator, 1);
+            let h2 = in_frame(env, cid, || create_active_hunt(env, &creator, 1));
+            let h3 = in_frame(env, cid, || create_active_hunt(env, &creator, 1));
+            for hid in [h1, h2, h3] {
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                });
+            }
+            let before = HuntyCore::get_player_hunts(env.clone(), player.clone(), 0, 10);
+            let page = HuntyCore::get_player_hunts(env.clone(), player.clone(), 1, 1);
+            in_frame(env, cid, || {
+                HuntyCore::unregister_player(env.clone(), h2, player.clone()).unwrap()
+            });
+            let after = HuntyCore::get_player_hunts(env.clone(), player.clone(), 0, 10);
+            (h1, h2, h3, before, page, after, get_all_events_for_hunt(env, h2))
+        });
+
+        assert_eq!(before, soroban_sdk::vec![&env, h1, h2, h3]);
+        assert_eq!(page, soroban_sdk::vec![&env, h2]);
+        assert_eq!(after, soroban_sdk::vec![&env, h1, h3]);
+        match events.last() {
+            Some(HuntEvent::PlayerUnregistered(e)) => assert_eq!(e.player, player),
+            other => panic!("expected PlayerUnregistered, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_register_player_duplicate_and_inactive() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (dup, inactive, unregistered) = with_core_contract(&env, |env, cid| {
+            let active = create_active_hunt(env, &creator, 1);
+            let draft = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 1, true));
+            HuntyCore::register_player(env.clone(), active, player.clone()).unwrap();
+            let dup = in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), active, player.clone()).unwrap_err()
+            });
+            let inactive = in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), draft, player.clone()).unwrap_err()
+            });
+            let unregistered = in_frame(env, cid, || {
+                HuntyCore::unregister_player(env.clone(), draft, player.clone()).unwrap_err()
+            });
+            (dup, inactive, unregistered)
+        });
+
+        assert_eq!(dup, HuntErrorCode::DuplicateRegistration);
+        assert_eq!(inactive, HuntErrorCode::HuntNotActive);
+        assert_eq!(unregistered, HuntErrorCode::PlayerNotRegistered);
+    }
+
+    // ========== submit_answer() / commit-reveal Tests ==========
+
+    /// Computes `sha256(answer || salt || player.to_xdr())` as `reveal_answer` expects.
+    fn answer_commitment(env: &Env, answer: &str, salt: &BytesN<32>, player: &Address) -> BytesN<32> {
+        let mut preimage = Bytes::from_slice(env, answer.as_bytes());
+        preimage.append(&Bytes::from_array(env, &salt.to_array()));
+        preimage.append(&player.clone().to_xdr(env));
+        env.crypto().sha256(&preimage).to_bytes()
+    }
+
+    #[test]
+    fn test_submit_answer_wrong_then_complete() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (wrong, progress) = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 2);
+            HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap();
+            let wrong = in_frame(env, cid, || {
+                HuntyCore::submit_answer(env.clone(), hid, 1, player.clone(), String::from_str(env, "nope"))
+                    .unwrap_err()
+            });
+            for clue_id in [1, 2] {
+                in_frame(env, cid, || {
+                    HuntyCore::submit_answer(
+                        env.clone(),
+                        hid,
+                        clue_id,
+                        player.clone(),
+                        String::from_str(env, " Answer "),
+                    )
+                    .unwrap()
+                });
+            }
+            (wrong, Storage::get_player_progress(env, hid, &player).unwrap())
+        });
+
+        assert_eq!(wrong, HuntErrorCode::InvalidAnswer);
+        assert_eq!(progress.total_score, 20);
+        assert_eq!(progress.completed_clues.len(), 2);
+        assert!(progress.is_completed);
+        assert_eq!(progress.completed_at, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_commit_reveal_valid() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.ledger().set_sequence_number(100);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let salt = BytesN::from_array(&env, &[7u8; 32]);
+
+        let (points, progress) = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 2);
+            HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap();
+            let commitment = answer_commitment(env, "answer", &salt, &player);
+            in_frame(env, cid, || {
+                HuntyCore::commit_answer(env.clone(), hid, 1, player.clone(), commitment).unwrap()
+            });
+            env.ledger().set_sequence_number(101);
+            let points = in_frame(env, cid, || {
+                HuntyCore::reveal_answer(
+                    env.clone(),
+                    hid,
+                    1,
+                    player.clone(),
+                    String::from_str(env, "answer"),
+                    salt.clone(),
+                )
+                .unwrap()
+            });
+            (points, Storage::get_player_progress(env, hid, &player).unwrap())
+        });
+
+        assert_eq!(points, 10);
+        assert!(progress.has_completed_clue(1));
+        assert!(!progress.is_completed);
+    }
+
+    #[test]
+    fn test_reveal_without_commit_and_too_early() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.ledger().set_sequence_number(100);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let salt = BytesN::from_array(&env, &[1u8; 32]);
+
+        let (missing, early) = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 1);
+            HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap();
+            let answer = String::from_str(env, "answer");
+            let missing = in_frame(env, cid, || {
+                HuntyCore::reveal_answer(env.clone(), hid, 1, player.clone(), answer.clone(), salt.clone())
+                    .unwrap_err()
+            });
+            let commitment = answer_commitment(env, "answer", &salt, &player);
+            in_frame(env, cid, || {
+                HuntyCore::commit_answer(env.clone(), hid, 1, player.clone(), commitment).unwrap()
+            });
+            let early = in_frame(env, cid, || {
+                HuntyCore::reveal_answer(env.clone(), hid, 1, player.clone(), answer.clone(), salt.clone())
+                    .unwrap_err()
+            });
+            (missing, early)
+        });
+
+        assert_eq!(missing, HuntErrorCode::CommitmentNotFound);
+        assert_eq!(early, HuntErrorCode::RevealTooEarly);
+    }
+
+    // ========== submit_answer_signed() Tests ==========
+
+    /// Signs a `submit_answer_signed` message for `key` with the player's current nonce.
+    fn sign_submission(
+        env: &Env,
+        key: &ed25519_dalek::SigningKey,
+        cid: &Address,
+        (hunt_id, clue_id, answer, nonce): (u64, u32, &str, u64),
+    ) -> BytesN<64> {
+        use ed25519_dalek::Signer;
+        let message = (cid.clone(), hunt_id, clue_id, String::from_str(env, answer), nonce);
+        let message = message.to_xdr(env);
+        let mut buf = std::vec![0u8; message.len() as usize];
+        message.copy_into_slice(&mut buf);
+        BytesN::from_array(env, &key.sign(&buf).to_bytes())
+    }
+
+    #[test]
+    fn test_submit_answer_signed_checks_signature_and_nonce() {
+        use soroban_sdk::xdr::FromXdr;
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+        let key = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+        let impostor = ed25519_dalek::SigningKey::from_bytes(&[9; 32]);
+        // An account address is the XDR ScVal tag, address and key types, then the key.
+        let mut account_xdr = [0u8; 44];
+        account_xdr[3] = 18;
+        account_xdr[12..].copy_from_slice(key.verifying_key().as_bytes());
+        let player = Address::from_xdr(&env, &Bytes::from_slice(&env, &account_xdr)).unwrap();
+
+        let cid = env.register(HuntyCore, ());
+        let hid = env.as_contract(&cid, || {
+            let hid = create_active_hunt(&env, &creator, 2);
+            HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap();
+            hid
+        });
+        let client = crate::HuntyCoreClient::new(&env, &cid);
+        let answer = String::from_str(&env, "answer");
+        env.set_auths(&[]);
+
+        let first = sign_submission(&env, &key, &cid, (hid, 1, "answer", 0));
+        assert_eq!(client.submit_answer_signed(&hid, &1, &player, &answer, &first), 10);
+        assert_eq!(client.get_nonce(&hid, &player), 1);
+        // Replaying the signature fails: it covers the nonce that was just used.
+        assert!(client.try_submit_answer_signed(&hid, &2, &player, &answer, &first).is_err());
+
+        let forged = sign_submission(&env, &impostor, &cid, (hid, 2, "answer", 1));
+        assert!(client.try_submit_answer_signed(&hid, &2, &player, &answer, &forged).is_err());
+        assert_eq!(client.get_nonce(&hid, &player), 1);
+
+        let second = sign_submission(&env, &key, &cid, (hid, 2, "answer", 1));
+        assert_eq!(client.submit_answer_signed(&hid, &2, &player, &answer, &second), 10);
+        let progress = env.as_contract(&cid, || Storage::get_player_progress(&env, hid, &player));
+        assert!(progress.unwrap().is_completed);
+    }
+
+    // ========== Global Stats Tests ==========
+
+    #[test]
+    fn test_global_stats_track_create_activate_claim() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let p1 = Address::generate(&env);
+        let p2 = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 1_000);
+
+        let (initial, after_setup, after_claim, total, claimed) = with_core_contract(&env, |env, cid| {
+            let initial = HuntyCore::get_global_stats(env.clone());
+            let hid = create_funded_hunt(env, &creator, &token, 1_000, 2);
+            in_frame(env, cid, || create_hunt_with_clues(env, &creator, 1, true));
+            register_and_complete(env, cid, hid, &p1);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, p2.clone()).unwrap()
+            });
+            let after_setup = HuntyCore::get_global_stats(env.clone());
+            let claimed = in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, p1.clone(), None).unwrap()
+            });
+            let after_claim = HuntyCore::get_global_stats(env.clone());
+            let total = HuntyCore::get_total_hunts(env.clone());
+            (initial, after_setup, after_claim, total, claimed)
+        });
+
+        assert_eq!(initial.total_hunts, 0);
+        assert_eq!(initial.total_rewards_distributed, 0);
+        assert_eq!(after_setup.total_hunts, 2);
+        assert_eq!(after_setup.active_hunts, 1);
+        assert_eq!(after_setup.total_players, 2);
+        assert_eq!(after_setup.total_rewards_distributed, 0);
+        assert_eq!(claimed, 500);
+        assert_eq!(after_claim.total_rewards_distributed, 500);
+        assert_eq!(total, 2);
+        assert_eq!(TokenClient::new(&env, &token).balance(&p1), 500);
+    }
+
+    #[test]
+    fn test_creator_stats_aggregate_across_hunts() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let other = Address::generate(&env);
+        let p1 = Address::generate(&env);
+        let p2 = Address::generate(&env);
+        let p3 = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 1_000);
+
+        let (stats, other_stats) = with_core_contract(&env, |env, cid| {
+            // A busy funded hunt: two completions, one claim, one idle player.
+            let busy = create_funded_hunt(env, &creator, &token, 1_000, 2);
+            register_and_complete(env, cid, busy, &p1);
+            register_and_complete(env, cid, busy, &p2);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), busy, p3.clone()).unwrap()
+            });
+            in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), busy, p1.clone(), None).unwrap()
+            });
+            // A quiet hunt with a single registration and no completions.
+            let quiet = in_frame(env, cid, || create_active_hunt(env, &creator, 2));
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), quiet, p1.clone()).unwrap()
+            });
+            in_frame(env, cid, || create_hunt_with_clues(env, &other, 1, true));
+            (
+                HuntyCore::get_creator_stats(env.clone(), creator.clone()),
+                HuntyCore::get_creator_stats(env.clone(), Address::generate(env)),
+            )
+        });
+
+        assert_eq!(
+            stats,
+            CreatorStats {
+                hunts: 2,
+                total_players: 4,
+                total_completions: 2,
+                total_rewards_distributed: 500,
+            }
+        );
+        assert_eq!(other_stats.hunts, 0);
+        assert_eq!(other_stats.total_players, 0);
+    }
+
+    #[test]
+    fn test_can_claim_reward_reports_each_reason() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let late = Address::generate(&env);
+        let idle = Address::generate(&env);
+        let cheater = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 1_000);
+
+        with_core_contract(&env, |env, cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            let hid = create_funded_hunt(env, &creator, &token, 1_000, 1);
+            let draft = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 1, true));
+            register_and_complete(env, cid, hid, &winner);
+            register_and_complete(env, cid, hid, &late);
+            register_and_complete(env, cid, hid, &cheater);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, idle.clone()).unwrap()
+            });
+            in_frame(env, cid, || {
+                HuntyCore::disqualify_player(env.clone(), hid, cheater.clone()).unwrap()
+            });
+
+            let check = |hunt_id, player: &Address| {
+                HuntyCore::can_claim_reward(env.clone(), hunt_id, player.clone())
+            };
+            let reason = |code: HuntErrorCode| (false, code as u32);
+            assert_eq!(check(hid, &winner), (true, 0));
+            assert_eq!(check(hid, &late), reason(HuntErrorCode::InsufficientRewardPool));
+            assert_eq!(check(hid, &idle), reason(HuntErrorCode::HuntNotCompleted));
+            assert_eq!(check(hid, &cheater), reason(HuntErrorCode::Disqualified));
+            assert_eq!(check(hid, &stranger), reason(HuntErrorCode::PlayerNotRegistered));
+            assert_eq!(check(draft, &winner), reason(HuntErrorCode::InvalidHuntStatus));
+            assert_eq!(check(99, &winner), reason(HuntErrorCode::HuntNotFound));
+
+            in_frame(env, cid, || HuntyCore::pause(env.clone()).unwrap());
+            assert_eq!(check(hid, &winner), reason(HuntErrorCode::ContractPaused));
+            in_frame(env, cid, || HuntyCore::unpause(env.clone()).unwrap());
+
+            in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, winner.clone(), None).unwrap()
+            });
+            assert_eq!(check(hid, &winner), reason(HuntErrorCode::RewardAlreadyClaimed));
+        });
+    }
+
+    #[test]
+    fn test_claim_reward_requires_completion_and_single_claim() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let idle = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 300);
+
+        let (not_done, twice) = with_core_contract(&env, |env, cid| {
+            let hid = create_funded_hunt(env, &creator, &token, 300, 3);
+            register_and_complete(env, cid, hid, &winner);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, idle.clone()).unwrap()
+            });
+            let not_done = in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, idle.clone(), None).unwrap_err()
+            });
+            in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, winner.clone(), None).unwrap()
+            });
+            let twice = in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, winner.clone(), None).unwrap_err()
+            });
+            (not_done, twice)
+        });
+
+        assert_eq!(not_done, HuntErrorCode::HuntNotCompleted);
+        assert_eq!(twice, HuntErrorCode::RewardAlreadyClaimed);
+    }
+
+    #[test]
+    fn test_nft_winners_get_distinct_winner_indices() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let cold = Address::generate(&env);
+        let nft = env.register(MockNft, ());
+
+        let hid = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_max_winners(env.clone(), hid, 2).unwrap();
+            HuntyCore::set_nft_contract(env.clone(), hid, Some(nft.clone())).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            register_and_complete(env, cid, hid, &alice);
+            register_and_complete(env, cid, hid, &bob);
+            in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, bob.clone(), Some(cold.clone())).unwrap()
+            });
+            in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, alice.clone(), None).unwrap()
+            });
+            hid
+        });
+
+        let awarded = get_all_events_for_hunt(&env, hid)
+            .into_iter()
+            .filter(|event| matches!(event, HuntEvent::RewardClaimed(e) if e.nft_awarded))
+            .count();
+        assert_eq!(awarded, 2);
+        let minted = MockNftClient::new(&env, &nft).minted();
+        assert_eq!(minted, soroban_sdk::vec![&env, (cold, hid, 1), (alice.clone(), hid, 2)]);
+    }
+
+    #[test]
+    fn test_claim_reward_to_self_and_payout_address() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let cold_wallet = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 200);
+
+        let (hid, claims) = with_core_contract(&env, |env, cid| {
+            let hid = create_funded_hunt(env, &creator, &token, 200, 2);
+            register_and_complete(env, cid, hid, &alice);
+            register_and_complete(env, cid, hid, &bob);
+            in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, alice.clone(), None).unwrap()
+            });
+            in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, bob.clone(), Some(cold_wallet.clone()))
+                    .unwrap()
+            });
+            let claims: std::vec::Vec<RewardClaimedEvent> = get_all_events_for_hunt(env, hid)
+                .into_iter()
+                .filter_map(|e| match e {
+                    HuntEvent::RewardClaimed(claim) => Some(claim),
+                    _ => None,
+                })
+                .collect();
+            (hid, claims)
+        });
+
+        let balances = TokenClient::new(&env, &token);
+        assert_eq!(balances.balance(&alice), 100);
+        assert_eq!(balances.balance(&bob), 0);
+        assert_eq!(balances.balance(&cold_wallet), 100);
+        assert_eq!(
+            claims,
+            std::vec![
+                RewardClaimedEvent {
+                    hunt_id: hid,
+                    player: alice.clone(),
+                    payout_address: alice.clone(),
+                    xlm_amount: 100,
+                    nft_awarded: false,
+                    event_seq: 11,
+                },
+                RewardClaimedEvent {
+                    hunt_id: hid,
+                    player: bob.clone(),
+                    payout_address: cold_wallet.clone(),
+                    xlm_amount: 100,
+                    nft_awarded: false,
+                    event_seq: 12,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_claim_rewards_multi_skips_ineligible_hunts() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 400);
+
+        let amounts = with_core_contract(&env, |env, cid| {
+            let fund = || in_frame(env, cid, || create_funded_hunt(env, &creator, &token, 100, 1));
+            let (paid, unfinished, claimed, other) = (fund(), fund(), fund(), fund());
+            register_and_complete(env, cid, paid, &player);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), unfinished, player.clone()).unwrap()
+            });
+            register_and_complete(env, cid, claimed, &player);
+            in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), claimed, player.clone(), None).unwrap()
+            });
+            register_and_complete(env, cid, other, &player);
+
+            let hunt_ids = Vec::from_array(env, [paid, unfinished, claimed, 99, other]);
+            in_frame(env, cid, || {
+                HuntyCore::claim_rewards_multi(env.clone(), player.clone(), hunt_ids).unwrap()
+            })
+        });
+
+        assert_eq!(amounts, Vec::from_array(&env, [100, 0, 0, 0, 100]));
+        assert_eq!(TokenClient::new(&env, &token).balance(&player), 300);
+    }
+
+    #[test]
+    fn test_rewards_exhausted_event_on_final_claim() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let first = Address::generate(&env);
+        let last = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 200);
+
+        let (hid, after_first, exhausted) = with_core_contract(&env, |env, cid| {
+            let hid = create_funded_hunt(env, &creator, &token, 200, 2);
+            register_and_complete(env, cid, hid, &first);
+            register_and_complete(env, cid, hid, &last);
+            let exhausted_events = || -> std::vec::Vec<HuntEvent> {
+                get_all_events_for_hunt(env, hid)
+                    .into_iter()
+                    .filter(|e| matches!(e, HuntEvent::RewardsExhausted(_)))
+                    .collect()
+            };
+            in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, first.clone(), None).unwrap()
+            });
+            let after_first = exhausted_events().len();
+            in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, last.clone(), None).unwrap()
+            });
+            (hid, after_first, exhausted_events())
+        });
+
+        assert_eq!(after_first, 0);
+        assert_eq!(
+            exhausted,
+            std::vec![HuntEvent::RewardsExhausted(RewardsExhaustedEvent {
+                hunt_id: hid,
+                final_claimer: last.clone(),
+                event_seq: 13,
+            })]
+        );
+    }
+
+    #[test]
+    fn test_winner_slots_reserved_at_completion() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let late = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 200);
+
+        let (reserved, late_progress, late_err, first_amount, second_amount) =
+            with_core_contract(&env, |env, cid| {
+                let hid = create_funded_hunt(env, &creator, &token, 200, 2);
+                register_and_complete(env, cid, hid, &first);
+                register_and_complete(env, cid, hid, &second);
+                register_and_complete(env, cid, hid, &late);
+                let reserved = Storage::get_hunt(env, hid).unwrap().reward_config.reserved_winners;
+                let late_progress = Storage::get_player_progress(env, hid, &late).unwrap();
+                // The late finisher cannot claim even before the reserved winners do.
+                let late_err = in_frame(env, cid, || {
+                    HuntyCore::claim_reward(env.clone(), hid, late.clone(), None).unwrap_err()
+                });
+                let second_amount = in_frame(env, cid, || {
+                    HuntyCore::claim_reward(env.clone(), hid, second.clone(), None).unwrap()
+                });
+                let first_amount = in_frame(env, cid, || {
+                    HuntyCore::claim_reward(env.clone(), hid, first.clone(), None).unwrap()
+                });
+                (reserved, late_progress, late_err, first_amount, second_amount)
+            });
+
+        assert_eq!(reserved, 2);
+        assert!(late_progress.is_completed);
+        assert!(!late_progress.winner_slot);
+        assert_eq!(late_err, HuntErrorCode::InsufficientRewardPool);
+        assert_eq!(first_amount, 100);
+        assert_eq!(second_amount, 100);
+        assert_eq!(TokenClient::new(&env, &token).balance(&late), 0);
+    }
+
+    // ========== disqualify_player() Tests ==========
+
+    #[test]
+    fn test_disqualify_before_claim_frees_winner_slot() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let cheater = Address::generate(&env);
+        let honest = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 100);
+
+        let (claim_err, again, honest_amount, event) = with_core_contract(&env, |env, cid| {
+            let hid = create_funded_hunt(env, &creator, &token, 100, 1);
+            register_and_complete(env, cid, hid, &cheater);
+            HuntyCore::disqualify_player(env.clone(), hid, cheater.clone()).unwrap();
+            let again = HuntyCore::disqualify_player(env.clone(), hid, cheater.clone()).unwrap_err();
+            let claim_err = in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, cheater.clone(), None).unwrap_err()
+            });
+            // The released slot goes to the next player to complete.
+            register_and_complete(env, cid, hid, &honest);
+            let honest_amount = in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, honest.clone(), None).unwrap()
+            });
+            let event = get_all_events_for_hunt(env, hid)
+                .into_iter()
+                .find(|e| matches!(e, HuntEvent::PlayerDisqualified(_)));
+            (claim_err, again, honest_amount, event)
+        });
+
+        assert_eq!(claim_err, HuntErrorCode::Disqualified);
+        assert_eq!(again, HuntErrorCode::Disqualified);
+        assert_eq!(honest_amount, 100);
+        assert_eq!(
+            event,
+            Some(HuntEvent::PlayerDisqualified(PlayerDisqualifiedEvent {
+                hunt_id: 1,
+                player: cheater.clone(),
+                reward_claimed: false,
+                event_seq: 8,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_disqualify_after_claim_only_records_event() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let cheater = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 200);
+
+        let (reserved, submit_err, event) = with_core_contract(&env, |env, cid| {
+            let hid = create_funded_hunt(env, &creator, &token, 200, 2);
+            register_and_complete(env, cid, hid, &cheater);
+            in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, cheater.clone(), None).unwrap()
+            });
+            HuntyCore::disqualify_player(env.clone(), hid, cheater.clone()).unwrap();
+            let reserved = Storage::get_hunt(env, hid).unwrap().reward_config.reserved_winners;
+            let submit_err = in_frame(env, cid, || {
+                let answer = String::from_str(env, "answer");
+                HuntyCore::submit_answer(env.clone(), hid, 1, cheater.clone(), answer).unwrap_err()
+            });
+            let event = get_all_events_for_hunt(env, hid)
+                .into_iter()
+                .find(|e| matches!(e, HuntEvent::PlayerDisqualified(_)));
+            (reserved, submit_err, event)
+        });
+
+        // The paid slot stays consumed and the funds stay with the player.
+        assert_eq!(reserved, 1);
+        assert_eq!(submit_err, HuntErrorCode::Disqualified);
+        assert_eq!(TokenClient::new(&env, &token).balance(&cheater), 100);
+        assert_eq!(
+            event,
+            Some(HuntEvent::PlayerDisqualified(PlayerDisqualifiedEvent {
+                hunt_id: 1,
+                player: cheater.clone(),
+                reward_claimed: true,
+                event_seq: 9,
+            }))
+        );
+    }
+
+    // ========== reset_player_progress() Tests ==========
+
+    #[test]
+    fn test_reset_player_progress_lets_unrewarded_player_retry() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 100);
+
+        let (reset, reserved, registered, events, retried) = with_core_contract(&env, |env, cid| {
+            let hid = create_funded_hunt(env, &creator, &token, 100, 1);
+            register_and_complete(env, cid, hid, &player);
+            HuntyCore::reset_player_progress(env.clone(), hid, player.clone()).unwrap();
+            let reset = Storage::get_player_progress(env, hid, &player).unwrap();
+            let reserved = Storage::get_hunt(env, hid).unwrap().reward_config.reserved_winners;
+            let registered = HuntyCore::get_player_hunts(env.clone(), player.clone(), 0, 10);
+            let events = get_all_events_for_hunt(env, hid);
+            in_frame(env, cid, || {
+                let answer = String::from_str(env, "answer");
+                HuntyCore::submit_answer(env.clone(), hid, 1, player.clone(), answer).unwrap();
+            });
+            let retried = Storage::get_player_progress(env, hid, &player).unwrap();
+            (reset, reserved, registered, events, retried)
+        });
+
+        assert_eq!(reset.completed_clues.len(), 0);
+        assert_eq!(reset.total_score, 0);
+        assert!(!reset.is_completed);
+        assert!(!reset.winner_slot);
+        assert_eq!(reserved, 0);
+        assert_eq!(registered, soroban_sdk::vec![&env, 1]);
+        assert!(events.contains(&HuntEvent::ProgressReset(ProgressResetEvent {
+            hunt_id: 1,
+            player: player.clone(),
+            event_seq: 8,
+        })));
+        assert!(retried.is_completed);
+        assert!(retried.winner_slot);
+    }
+
+    #[test]
+    fn test_reset_player_progress_refuses_rewarded_player() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 100);
+
+        let (err, progress) = with_core_contract(&env, |env, cid| {
+            let hid = create_funded_hunt(env, &creator, &token, 100, 1);
+            register_and_complete(env, cid, hid, &player);
+            in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, player.clone(), None).unwrap()
+            });
+            let err = HuntyCore::reset_player_progress(env.clone(), hid, player.clone());
+            (err, Storage::get_player_progress(env, hid, &player).unwrap())
+        });
+
+        assert_eq!(err, Err(HuntErrorCode::InvalidHuntStatus));
+        assert!(progress.is_completed);
+        assert!(progress.reward_claimed);
+        assert_eq!(progress.completed_clues.len(), 1);
+    }
+
+    // ========== draw_winners() Tests ==========
+
+    /// Runs a five-player raffle for two winners, drawn at ledger `sequence`, and returns
+    /// the drawn players' registration positions.
+    fn run_raffle(sequence: u32) -> std::vec::Vec<usize> {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 200);
+        let players: std::vec::Vec<Address> = (0..5).map(|_| Address::generate(&env)).collect();
+
+        with_core_contract(&env, |env, cid| {
+            let hid = HuntyCore::create_hunt(
+                env.clone(),
+                creator.clone(),
+                String::from_str(env, "Raffle"),
+                String::from_str(env, "Desc"),
+                None,
+                Some(1_700_000_100),
+            )
+            .unwrap();
+            HuntyCore::add_clue(
+                env.clone(),
+                hid,
+                creator.clone(),
+                String::from_str(env, "Q"),
+                String::from_str(env, "answer"),
+                10,
+                true,
+            )
+            .unwrap();
+            HuntyCore::set_reward_mode(env.clone(), hid, RewardMode::Raffle).unwrap();
+            HuntyCore::set_max_winners(env.clone(), hid, 2).unwrap();
+            HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), 200).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            for player in &players {
+                register_and_complete(env, cid, hid, player);
+            }
+            // Completing reserves nothing in a raffle, and the draw waits for end_time.
+            assert_eq!(Storage::get_hunt(env, hid).unwrap().reward_config.reserved_winners, 0);
+            assert_eq!(
+                HuntyCore::draw_winners(env.clone(), hid),
+                Err(HuntErrorCode::InvalidHuntStatus)
+            );
+
+            env.ledger().with_mut(|li| {
+                li.timestamp = 1_700_000_100;
+                li.sequence_number = sequence;
+            });
+            let winners = in_frame(env, cid, || HuntyCore::draw_winners(env.clone(), hid).unwrap());
+            assert_eq!(HuntyCore::get_raffle_winners(env.clone(), hid), winners);
+            assert_eq!(
+                in_frame(env, cid, || HuntyCore::draw_winners(env.clone(), hid)),
+                Err(HuntErrorCode::InvalidHuntStatus)
+            );
+
+            let positions: std::vec::Vec<usize> = winners
+                .iter()
+                .map(|w| players.iter().position(|p| *p == w).unwrap())
+                .collect();
+            let loser = (0..players.len()).find(|i| !positions.contains(i)).unwrap();
+            for winner in winners.iter() {
+                let paid = in_frame(env, cid, || {
+                    HuntyCore::claim_reward(env.clone(), hid, winner.clone(), None).unwrap()
+                });
+                assert_eq!(paid, 100);
+            }
+            let loser_err = in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, players[loser].clone(), None).unwrap_err()
+            });
+            assert_eq!(loser_err, HuntErrorCode::InsufficientRewardPool);
+            positions
+        })
+    }
+
+    #[test]
+    fn test_draw_winners_is_deterministic_for_a_ledger() {
+        let first = run_raffle(1234);
+        let second = run_raffle(1234);
+
+        assert_eq!(first.len(), 2);
+        assert_ne!(first[0], first[1]);
+        assert_eq!(first, second);
+    }
+
+    // ========== set_clue_required() Tests ==========
+
+    #[test]
+    fn test_set_clue_required_tracks_required_count() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (optional, unchanged, restored, flag) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 3, true);
+            let required = |env: &Env| Storage::get_hunt(env, hid).unwrap().required_clues;
+            HuntyCore::set_clue_required(env.clone(), hid, creator.clone(), 2, false).unwrap();
+            let optional = required(env);
+            // Setting the same value again must not double count.
+            HuntyCore::set_clue_required(env.clone(), hid, creator.clone(), 2, false).unwrap();
+            let unchanged = required(env);
+            HuntyCore::set_clue_required(env.clone(), hid, creator.clone(), 2, true).unwrap();
+            let flag = HuntyCore::get_clue(env.clone(), hid, 2).unwrap().is_required;
+            (optional, unchanged, required(env), flag)
+        });
+
+        assert_eq!(optional, 2);
+        assert_eq!(unchanged, 2);
+        assert_eq!(restored, 3);
+        assert!(flag);
+    }
+
+    #[test]
+    fn test_set_clue_required_rejects_active_hunt_and_missing_clue() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (missing, active) = with_core_contract(&env, |env, _| {
+            let draft = create_hunt_with_clues(env, &creator, 1, true);
+            let missing = HuntyCore::set_clue_required(env.clone(), draft, creator.clone(), 9, false);
+            HuntyCore::activate_hunt(env.clone(), draft).unwrap();
+            let active = HuntyCore::set_clue_required(env.clone(), draft, creator.clone(), 1, false);
+            (missing, active)
+        });
+
+        assert_eq!(missing, Err(HuntErrorCode::ClueNotFound));
+        assert_eq!(active, Err(HuntErrorCode::InvalidHuntStatus));
+    }
+
+    // ========== set_clue_time_bonus() Tests ==========
+
+    #[test]
+    fn test_time_bonus_within_and_outside_window() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let fast = Address::generate(&env);
+        let slow = Address::generate(&env);
+
+        let (fast_points, slow_points, info) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 2, true);
+            HuntyCore::set_clue_time_bonus(env.clone(), hid, creator.clone(), 1, 60, 5).unwrap();
+            HuntyCore::set_clue_time_bonus(env.clone(), hid, creator.clone(), 2, 60, 5).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            let answer = String::from_str(env, "answer");
+            for player in [&fast, &slow] {
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                });
+            }
+
+            // Clue 1 runs from registration: fast solves at +30s, slow at +90s.
+            env.ledger().set_timestamp(1_700_000_030);
+            let fast_1 = in_frame(env, cid, || {
+                HuntyCore::submit_answer(env.clone(), hid, 1, fast.clone(), answer.clone()).unwrap()
+            });
+            env.ledger().set_timestamp(1_700_000_090);
+            let slow_1 = in_frame(env, cid, || {
+                HuntyCore::submit_answer(env.clone(), hid, 1, slow.clone(), answer.clone()).unwrap()
+            });
+
+            // Clue 2 runs from the first view, which later views do not reset.
+            let info = in_frame(env, cid, || {
+                HuntyCore::view_clue(env.clone(), hid, 2, fast.clone()).unwrap()
+            });
+            in_frame(env, cid, || HuntyCore::view_clue(env.clone(), hid, 2, slow.clone()).unwrap());
+            env.ledger().set_timestamp(1_700_000_140);
+            in_frame(env, cid, || HuntyCore::view_clue(env.clone(), hid, 2, slow.clone()).unwrap());
+            let fast_2 = in_frame(env, cid, || {
+                HuntyCore::submit_answer(env.clone(), hid, 2, fast.clone(), answer.clone()).unwrap()
+            });
+            env.ledger().set_timestamp(1_700_000_160);
+            let slow_2 = in_frame(env, cid, || {
+                HuntyCore::submit_answer(env.clone(), hid, 2, slow.clone(), answer.clone()).unwrap()
+            });
+            ((fast_1, fast_2), (slow_1, slow_2), info)
+        });
+
+        assert_eq!(fast_points, (15, 15));
+        assert_eq!(slow_points, (10, 10));
+        assert_eq!(info.time_limit_secs, 60);
+        assert_eq!(info.time_bonus, 5);
+    }
+
+    // ========== set_clue_first_solve_bonus() Tests ==========
+
+    #[test]
+    fn test_first_solver_gets_bonus() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+
+        let (points, solves, info) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_clue_first_solve_bonus(env.clone(), hid, creator.clone(), 1, 25)
+                .unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            let points: std::vec::Vec<u32> = [&first, &second]
+                .into_iter()
+                .map(|player| {
+                    in_frame(env, cid, || {
+                        HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                    });
+                    in_frame(env, cid, || {
+                        let answer = String::from_str(env, "answer");
+                        HuntyCore::submit_answer(env.clone(), hid, 1, player.clone(), answer)
+                            .unwrap()
+                    })
+                })
+                .collect();
+            let solves = HuntyCore::get_clue_solve_count(env.clone(), hid, 1);
+            (points, solves, HuntyCore::get_clue(env.clone(), hid, 1).unwrap())
+        });
+
+        assert_eq!(points, std::vec![35, 10]);
+        assert_eq!(solves, 2);
+        assert_eq!(info.first_solve_bonus, 25);
+    }
+
+    // ========== get_clue_location() Tests ==========
+
+    #[test]
+    fn test_clue_location_only_for_pinned_clues() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+        let pin = Location { latitude: 51_507_351, longitude: -127_758, radius: 50 };
+
+        with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 2, true);
+            let set = |location: Option<Location>| {
+                HuntyCore::set_clue_location(env.clone(), hid, creator.clone(), 1, location)
+            };
+            let off_map = Location { latitude: 91_000_000, ..pin.clone() };
+            assert_eq!(set(Some(off_map)), Err(HuntErrorCode::InvalidAmount));
+            set(Some(pin.clone())).unwrap();
+
+            assert_eq!(HuntyCore::get_clue_location(env.clone(), hid, 1), Some(pin.clone()));
+            assert_eq!(HuntyCore::get_clue_location(env.clone(), hid, 2), None);
+            assert_eq!(HuntyCore::get_clue_location(env.clone(), hid, 9), None);
+
+            set(None).unwrap();
+            assert_eq!(HuntyCore::get_clue_location(env.clone(), hid, 1), None);
+        });
+    }
+
+    // ========== Activation Funding Tests ==========
+
+    #[test]
+    fn test_activate_requires_escrow_to_cover_winner_slots() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 1_000);
+
+        let (underfunded, nft_without_contract, funded) = with_core_contract(&env, |env, cid| {
+            // A pool recorded on the hunt with nothing actually escrowed.
+            let short = create_hunt_with_clues(env, &creator, 1, true);
+            let mut hunt = Storage::get_hunt(env, short).unwrap();
+            hunt.reward_config.max_winners = 2;
+            hunt.reward_config.xlm_pool = 1_000;
+            Storage::save_hunt(env, &hunt);
+            let underfunded = HuntyCore::activate_hunt(env.clone(), short);
+
+            let nft = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 1, true));
+            let mut hunt = Storage::get_hunt(env, nft).unwrap();
+            hunt.reward_config.nft_enabled = true;
+            Storage::save_hunt(env, &hunt);
+            let nft_without_contract = HuntyCore::activate_hunt(env.clone(), nft);
+
+            let ok = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 1, true));
+            HuntyCore::set_max_winners(env.clone(), ok, 2).unwrap();
+            HuntyCore::fund_reward_pool(env.clone(), ok, token.clone(), 1_000).unwrap();
+            let funded = HuntyCore::activate_hunt(env.clone(), ok);
+            (underfunded, nft_without_contract, funded)
+        });
+
+        assert_eq!(underfunded, Err(HuntErrorCode::InsufficientRewardPool));
+        assert_eq!(nft_without_contract, Err(HuntErrorCode::InsufficientRewardPool));
+        assert_eq!(funded, Ok(()));
+    }
+
+    // ========== deactivate_hunt() Tests ==========
+
+    #[test]
+    fn test_reactivation_revalidates_after_deactivate_and_edit() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 1_000);
+
+        with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_max_winners(env.clone(), hid, 1).unwrap();
+            HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), 1_000).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            assert_eq!(Storage::get_hunt(env, hid).unwrap().activated_at, 1_000);
+
+            HuntyCore::deactivate_hunt(env.clone(), hid).unwrap();
+            let hunt = Storage::get_hunt(env, hid).unwrap();
+            assert_eq!(hunt.status, HuntStatus::Draft);
+            assert_eq!(hunt.activated_at, 0);
+            assert_eq!(HuntyCore::get_clue_set_commitment(env.clone(), hid), None);
+            assert_eq!(HuntyCore::get_global_stats(env.clone()).active_hunts, 0);
+
+            // Edit in Draft, then drain the escrow: re-activation must notice.
+            HuntyCore::add_clue(
+                env.clone(),
+                hid,
+                creator.clone(),
+                String::from_str(env, "Second"),
+                String::from_str(env, "answer"),
+                10,
+                true,
+            )
+            .unwrap();
+            Storage::set_escrow_balance(env, hid, 0);
+            assert_eq!(
+                HuntyCore::activate_hunt(env.clone(), hid),
+                Err(HuntErrorCode::InsufficientRewardPool)
+            );
+
+            Storage::set_escrow_balance(env, hid, 1_000);
+            env.ledger().set_timestamp(2_000);
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            let hunt = Storage::get_hunt(env, hid).unwrap();
+            assert_eq!(hunt.activated_at, 2_000);
+            assert_eq!(hunt.total_clues, 2);
+            assert_eq!(HuntyCore::get_global_stats(env.clone()).active_hunts, 1);
+
+            // Once someone has registered the hunt can no longer go back to Draft.
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            assert_eq!(
+                in_frame(env, cid, || HuntyCore::deactivate_hunt(env.clone(), hid)),
+                Err(HuntErrorCode::InvalidHuntStatus)
+            );
+        });
+    }
+
+    // ========== get_reward_display() Tests ==========
+
+    #[test]
+    fn test_reward_display_records_token_decimals() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+        // Stellar asset contracts report 7 decimals.
+        let token = create_funded_token(&env, &creator, 50_000_000);
+
+        let (before, after, stored) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            let before = HuntyCore::get_reward_display(env.clone(), hid);
+            HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), 50_000_000).unwrap();
+            let after = HuntyCore::get_reward_display(env.clone(), hid);
+            let stored = Storage::get_hunt(env, hid).unwrap().reward_config.reward_decimals;
+            (before, after, stored)
+        });
+
+        assert_eq!(before, (0, 0));
+        assert_eq!(after, (50_000_000, 7));
+        assert_eq!(stored, 7);
+    }
+
+    #[test]
+    fn test_reward_display_records_decimals_when_entry_fee_set_first() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 50_000_000);
+
+        let (after_fee, after_funding) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_entry_fee(env.clone(), hid, token.clone(), 10).unwrap();
+            let after_fee = HuntyCore::get_reward_display(env.clone(), hid);
+            HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), 50_000_000).unwrap();
+            (after_fee, HuntyCore::get_reward_display(env.clone(), hid))
+        });
+
+        // The entry fee fixed the token, so its decimals are known before any funding.
+        assert_eq!(after_fee, (0, 7));
+        assert_eq!(after_funding, (50_000_000, 7));
+    }
+
+    // ========== get_hunt_pool_balance() Tests ==========
+
+    #[test]
+    fn test_hunt_pool_balance_drops_by_reward_per_winner() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let players = [Address::generate(&env), Address::generate(&env)];
+        let token = create_funded_token(&env, &creator, 900);
+
+        let (balances, configured) = with_core_contract(&env, |env, cid| {
+            let mut balances = std::vec![HuntyCore::get_hunt_pool_balance(env.clone(), 1)];
+            let hid = create_funded_hunt(env, &creator, &token, 900, 3);
+            balances.push(HuntyCore::get_hunt_pool_balance(env.clone(), hid));
+            for player in &players {
+                register_and_complete(env, cid, hid, player);
+                in_frame(env, cid, || {
+                    HuntyCore::claim_reward(env.clone(), hid, player.clone(), None).unwrap()
+                });
+                balances.push(HuntyCore::get_hunt_pool_balance(env.clone(), hid));
+            }
+            (balances, Storage::get_hunt(env, hid).unwrap().reward_config.xlm_pool)
+        });
+
+        assert_eq!(balances, std::vec![0, 900, 600, 300]);
+        // The configured pool is unchanged by claims.
+        assert_eq!(configured, 900);
+    }
+
+    // ========== get_distribution_record() Tests ==========
+
+    #[test]
+    fn test_distribution_record_lists_paid_claims() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let players = [Address::generate(&env), Address::generate(&env)];
+        let token = create_funded_token(&env, &creator, 1_000);
+
+        let (record, second_page) = with_core_contract(&env, |env, cid| {
+            let hid = create_funded_hunt(env, &creator, &token, 1_000, 4);
+            for player in &players {
+                register_and_complete(env, cid, hid, player);
+                in_frame(env, cid, || {
+                    HuntyCore::claim_reward(env.clone(), hid, player.clone(), None).unwrap()
+                });
+            }
+            (
+                HuntyCore::get_distribution_record(env.clone(), hid, 0, 10),
+                HuntyCore::get_distribution_record(env.clone(), hid, 1, 10),
+            )
+        });
+
+        assert_eq!(
+            record,
+            soroban_sdk::vec![&env, (players[0].clone(), 250), (players[1].clone(), 250)]
+        );
+        assert_eq!(second_page, soroban_sdk::vec![&env, (players[1].clone(), 250)]);
+    }
+
+    // ========== withdraw_reward_pool() Tests ==========
+
+    #[test]
+    fn test_withdraw_reward_pool_in_draft_only() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 1_000);
+
+        let (left, over, active, pool, escrow) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_max_winners(env.clone(), hid, 1).unwrap();
+            HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), 1_000).unwrap();
+            let left = HuntyCore::withdraw_reward_pool(env.clone(), hid, 400);
+            let over = HuntyCore::withdraw_reward_pool(env.clone(), hid, 601);
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            let active = HuntyCore::withdraw_reward_pool(env.clone(), hid, 100);
+            let pool = Storage::get_hunt(env, hid).unwrap().reward_config.xlm_pool;
+            (left, over, active, pool, Storage::get_escrow_balance(env, hid))
+        });
+
+        assert_eq!(left, Ok(600));
+        assert_eq!(over, Err(HuntErrorCode::InsufficientRewardPool));
+        assert_eq!(active, Err(HuntErrorCode::InvalidHuntStatus));
+        assert_eq!(pool, 600);
+        assert_eq!(escrow, 600);
+        assert_eq!(TokenClient::new(&env, &token).balance(&creator), 400);
+    }
+
+    // ========== swap_reward_token() Tests ==========
+
+    #[test]
+    fn test_swap_reward_token_refunds_old_pool() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+        let old_token = create_funded_token(&env, &creator, 1_000);
+        let new_token = create_funded_token(&env, &creator, 500);
+
+        let (refunded, hunt, swaps) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_max_winners(env.clone(), hid, 1).unwrap();
+            HuntyCore::fund_reward_pool(env.clone(), hid, old_token.clone(), 1_000).unwrap();
+            let refunded = HuntyCore::swap_reward_token(env.clone(), hid, new_token.clone());
+            HuntyCore::fund_reward_pool(env.clone(), hid, new_token.clone(), 500).unwrap();
+            let swaps: std::vec::Vec<HuntEvent> = get_all_events_for_hunt(env, hid)
+                .into_iter()
+                .filter(|e| matches!(e, HuntEvent::RewardTokenSwapped(_)))
+                .collect();
+            (refunded, Storage::get_hunt(env, hid).unwrap(), swaps)
+        });
+
+        assert_eq!(refunded, Ok(1_000));
+        assert_eq!(hunt.reward_config.reward_token, Some(new_token.clone()));
+        assert_eq!(hunt.reward_config.xlm_pool, 500);
+        assert_eq!(TokenClient::new(&env, &old_token).balance(&creator), 1_000);
+        assert_eq!(TokenClient::new(&env, &new_token).balance(&creator), 0);
+        assert_eq!(
+            swaps,
+            std::vec![HuntEvent::RewardTokenSwapped(RewardTokenSwappedEvent {
+                hunt_id: hunt.hunt_id,
+                old_token: Some(old_token),
+                new_token,
+                refunded: 1_000,
+                event_seq: 4,
+            })]
+        );
+    }
+
+    #[test]
+    fn test_swap_reward_token_records_new_token_decimals() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+        let old_token = create_funded_token(&env, &creator, 1_000);
+        let new_token = env.register(MockCentsToken, ());
+
+        let (before, after) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::fund_reward_pool(env.clone(), hid, old_token.clone(), 1_000).unwrap();
+            let before = HuntyCore::get_reward_display(env.clone(), hid);
+            HuntyCore::swap_reward_token(env.clone(), hid, new_token.clone()).unwrap();
+            (before, HuntyCore::get_reward_display(env.clone(), hid))
+        });
+
+        assert_eq!(before, (1_000, 7));
+        assert_eq!(after, (0, 2));
+    }
+
+    #[test]
+    fn test_swap_reward_token_refused_while_entry_fee_set() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+        let old_token = create_funded_token(&env, &creator, 0);
+        let new_token = env.register(MockCentsToken, ());
+
+        let (priced, cleared, hunt) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_entry_fee(env.clone(), hid, old_token.clone(), 50).unwrap();
+            // A fee of 50 base units means something else once the decimals change.
+            let priced = HuntyCore::swap_reward_token(env.clone(), hid, new_token.clone());
+            HuntyCore::set_entry_fee(env.clone(), hid, old_token.clone(), 0).unwrap();
+            let cleared = HuntyCore::swap_reward_token(env.clone(), hid, new_token.clone());
+            (priced, cleared, Storage::get_hunt(env, hid).unwrap())
+        });
+
+        assert_eq!(priced, Err(HuntErrorCode::InvalidHuntStatus));
+        assert_eq!(cleared, Ok(0));
+        assert_eq!(hunt.reward_config.reward_token, Some(new_token));
+        assert_eq!(hunt.entry_fee, 0);
+    }
+
+    // ========== recompute_required_clues() Tests ==========
+
+    #[test]
+    fn test_recompute_required_clues_repairs_drifted_counters() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        let (hunt, denied, events) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 3, true);
+            HuntyCore::add_clue(
+                env.clone(),
+                hid,
+                creator.clone(),
+                String::from_str(env, "Optional"),
+                String::from_str(env, "answer"),
+                5,
+                false,
+            )
+            .unwrap();
+            let mut hunt = Storage::get_hunt(env, hid).unwrap();
+            hunt.total_clues = 9;
+            hunt.required_clues = 1;
+            Storage::save_hunt(env, &hunt);
+
+            let denied = HuntyCore::recompute_required_clues(env.clone(), hid, stranger.clone());
+            HuntyCore::recompute_required_clues(env.clone(), hid, creator.clone()).unwrap();
+            let events = get_all_events_for_hunt(env, hid);
+            (Storage::get_hunt(env, hid).unwrap(), denied, events)
+        });
+
+        assert_eq!(denied, Err(HuntErrorCode::Unauthorized));
+        assert_eq!(hunt.total_clues, 4);
+        assert_eq!(hunt.required_clues, 3);
+        assert_eq!(
+            events.last(),
+            Some(&HuntEvent::CountersRecomputed(CountersRecomputedEvent {
+                hunt_id: hunt.hunt_id,
+                total_clues: 4,
+                required_clues: 3,
+                event_seq: 6,
+            }))
+        );
+    }
+
+    // ========== rebuild_indexes() Tests ==========
+
+    #[test]
+    fn test_rebuild_indexes_repairs_corrupted_lists() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let players = [Address::generate(&env), Address::generate(&env)];
+
+        let (clue_ids, required_ids, player_count, last_event) =
+            with_core_contract(&env, |env, cid| {
+                HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+                let hid = create_active_hunt(env, &creator, 3);
+                for player in &players {
+                    in_frame(env, cid, || {
+                        HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                    });
+                }
+                Storage::set_clue_ids_for_hunt(env, hid, &soroban_sdk::vec![env, 1]);
+                Storage::set_required_clue_ids(env, hid, &Vec::new(env));
+                let first_only = soroban_sdk::vec![env, players[0].clone()];
+                Storage::set_player_addresses_for_hunt(env, hid, &first_only);
+                assert_eq!(Storage::get_player_count(env, hid), 1);
+
+                let all_players = soroban_sdk::vec![env, players[0].clone(), players[1].clone()];
+                let rebuild = |clue_ids: Vec<u32>, players: Vec<Address>| {
+                    HuntyCore::rebuild_indexes(env.clone(), hid, clue_ids, players)
+                };
+                let missing = rebuild(soroban_sdk::vec![env, 1, 9], all_players.clone());
+                assert_eq!(missing, Err(HuntErrorCode::ClueNotFound));
+                let repeated = rebuild(soroban_sdk::vec![env, 1, 1], all_players.clone());
+                assert_eq!(repeated, Err(HuntErrorCode::InvalidClueOrder));
+                let outsider = soroban_sdk::vec![env, Address::generate(env)];
+                let unregistered = rebuild(soroban_sdk::vec![env, 1], outsider);
+                assert_eq!(unregistered, Err(HuntErrorCode::PlayerNotRegistered));
+
+                rebuild(soroban_sdk::vec![env, 3, 1, 2], all_players).unwrap();
+                (
+                    HuntyCore::get_clue_ids(env.clone(), hid),
+                    HuntyCore::get_required_clue_ids(env.clone(), hid),
+                    Storage::get_player_count(env, hid),
+                    get_all_events_for_hunt(env, hid).pop().unwrap(),
+                )
+            });
+
+        assert_eq!(clue_ids, soroban_sdk::vec![&env, 3, 1, 2]);
+        assert_eq!(required_ids, soroban_sdk::vec![&env, 1, 2, 3]);
+        assert_eq!(player_count, 2);
+        match last_event {
+            HuntEvent::IndexesRebuilt(e) => assert_eq!((e.clue_count, e.player_count), (3, 2)),
+            other => panic!("unexpected event {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_rebuild_indexes_drops_hunt_from_unlisted_players() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let (kept, dropped) = (Address::generate(&env), Address::generate(&env));
+
+        let (kept_hunts, dropped_hunts) = with_core_contract(&env, |env, cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            let hid = create_active_hunt(env, &creator, 1);
+            for player in [&kept, &dropped] {
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                });
+            }
+            let clue_ids = HuntyCore::get_clue_ids(env.clone(), hid);
+            let only_kept = soroban_sdk::vec![env, kept.clone()];
+            HuntyCore::rebuild_indexes(env.clone(), hid, clue_ids, only_kept).unwrap();
+            (
+                HuntyCore::get_player_hunts(env.clone(), kept.clone(), 0, 10),
+                HuntyCore::get_player_hunts(env.clone(), dropped.clone(), 0, 10),
+            )
+        });
+
+        assert_eq!(kept_hunts.len(), 1);
+        assert!(dropped_hunts.is_empty());
+    }
+
+    // ========== hunt_exists() / clue_exists() Tests ==========
+
+    #[test]
+    fn test_hunt_exists_for_created_and_missing() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (existing, missing, zero) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 0, true);
+            (
+                HuntyCore::hunt_exists(env.clone(), hid),
+                HuntyCore::hunt_exists(env.clone(), hid + 1),
+                HuntyCore::hunt_exists(env.clone(), 0),
+            )
+        });
+
+        assert!(existing);
+        assert!(!missing);
+        assert!(!zero);
+    }
+
+    #[test]
+    fn test_clue_exists_for_created_missing_and_removed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (existing, never, removed, other_hunt, ids) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 3, true);
+            Storage::remove_clue(env, hid, 2);
+            (
+                HuntyCore::clue_exists(env.clone(), hid, 1),
+                HuntyCore::clue_exists(env.clone(), hid, 7),
+                HuntyCore::clue_exists(env.clone(), hid, 2),
+                HuntyCore::clue_exists(env.clone(), 9999, 1),
+                HuntyCore::get_clue_ids(env.clone(), hid),
+            )
+        });
+
+        assert!(existing);
+        assert!(!never);
+        assert!(!removed);
+        assert!(!other_hunt);
+        assert_eq!(ids, soroban_sdk::vec![&env, 1, 3]);
+    }
+
+    // ========== is_archive_candidate() Tests ==========
+
+    #[test]
+    fn test_archive_candidates_are_old_finished_hunts() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+
+        with_core_contract(&env, |env, cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            assert_eq!(HuntyCore::get_archive_after_secs(env.clone()), 90 * 24 * 60 * 60);
+            HuntyCore::set_archive_after_secs(env.clone(), 1_000).unwrap();
+            let hunt = |close: bool| {
+                in_frame(env, cid, || {
+                    let hid = create_active_hunt(env, &creator, 1);
+                    if close {
+                        HuntyCore::close_hunt(env.clone(), hid).unwrap();
+                    }
+                    hid
+                })
+            };
+            let (old, old_active) = (hunt(true), hunt(false));
+            env.ledger().set_timestamp(1_700_002_000);
+            let recent = hunt(true);
+            assert_eq!(HuntyCore::get_hunt_age(env.clone(), old), 2_000);
+            assert_eq!(HuntyCore::get_hunt_age(env.clone(), recent), 0);
+            assert!(HuntyCore::is_archive_candidate(env.clone(), old));
+            assert!(!HuntyCore::is_archive_candidate(env.clone(), old_active));
+            assert!(!HuntyCore::is_archive_candidate(env.clone(), recent));
+            assert!(!HuntyCore::is_archive_candidate(env.clone(), 99));
+        });
+    }
+
+    // ========== get_player_clue_order() Tests ==========
+
+    #[test]
+    fn test_player_clue_order_is_stable_and_per_player() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+
+        let (alice_order, alice_again, bob_order, plain) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 6, true);
+            HuntyCore::set_shuffle_clues(env.clone(), hid, true).unwrap();
+            let plain_hid = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 6, true));
+            let order = |hunt_id, player: &Address| {
+                HuntyCore::get_player_clue_order(env.clone(), hunt_id, player.clone())
+            };
+            (order(hid, &alice), order(hid, &alice), order(hid, &bob), order(plain_hid, &alice))
+        });
+
+        assert_eq!(alice_order, alice_again);
+        assert_ne!(alice_order, bob_order);
+        for order in [&alice_order, &bob_order] {
+            let mut ids: std::vec::Vec<u32> = order.iter().collect();
+            ids.sort();
+            assert_eq!(ids, std::vec![1, 2, 3, 4, 5, 6]);
+        }
+        assert_eq!(plain, soroban_sdk::vec![&env, 1, 2, 3, 4, 5, 6]);
+    }
+
+    // ========== set_end_time() Tests ==========
+
+    #[test]
+    fn test_set_end_time_in_draft() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (scheduled, past, now, cleared, active) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_end_time(env.clone(), hid, 1_700_086_400).unwrap();
+            let scheduled = Storage::get_hunt(env, hid).unwrap().end_time;
+            let past = HuntyCore::set_end_time(env.clone(), hid, 1_699_999_999);
+            let now = HuntyCore::set_end_time(env.clone(), hid, 1_700_000_000);
+            HuntyCore::set_end_time(env.clone(), hid, 0).unwrap();
+            let cleared = Storage::get_hunt(env, hid).unwrap().end_time;
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            let active = HuntyCore::set_end_time(env.clone(), hid, 1_700_086_400);
+            (scheduled, past, now, cleared, active)
+        });
+
+        assert_eq!(scheduled, 1_700_086_400);
+        assert_eq!(past, Err(HuntErrorCode::InvalidEndTime));
+        assert_eq!(now, Err(HuntErrorCode::InvalidEndTime));
+        assert_eq!(cleared, 0);
+        assert_eq!(active, Err(HuntErrorCode::InvalidHuntStatus));
+    }
+
+    // ========== start_time Tests ==========
+
+    #[test]
+    fn test_start_time_gates_play_before_at_and_after() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let early = Address::generate(&env);
+        let late = Address::generate(&env);
+
+        with_core_contract(&env, |env, cid| {
+            let hid = HuntyCore::create_hunt(
+                env.clone(),
+                creator.clone(),
+                String::from_str(env, "Scheduled"),
+                String::from_str(env, "Desc"),
+                Some(1_700_000_100),
+                None,
+            )
+            .unwrap();
+            HuntyCore::add_clue(
+                env.clone(),
+                hid,
+                creator.clone(),
+                String::from_str(env, "Q"),
+                String::from_str(env, "answer"),
+                10,
+                true,
+            )
+            .unwrap();
+            // Activation is allowed ahead of the start; play is not.
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            assert_eq!(Storage::get_hunt(env, hid).unwrap().status, HuntStatus::Active);
+            assert!(!HuntyCore::is_hunt_active(env.clone(), hid));
+            let before = in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, early.clone())
+            });
+            assert_eq!(before, Err(HuntErrorCode::HuntNotStarted));
+
+            env.ledger().set_timestamp(1_700_000_100);
+            assert!(HuntyCore::is_hunt_active(env.clone(), hid));
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, early.clone()).unwrap()
+            });
+
+            env.ledger().set_timestamp(1_700_000_500);
+            register_and_complete(env, cid, hid, &late);
+            let early_points = in_frame(env, cid, || {
+                let answer = String::from_str(env, "answer");
+                HuntyCore::submit_answer(env.clone(), hid, 1, early.clone(), answer)
+            });
+            assert_eq!(early_points, Ok(10));
+        });
+    }
+
+    #[test]
+    fn test_grace_period_accepts_late_answers_then_closes() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let latecomer = Address::generate(&env);
+
+        with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 2, true);
+            HuntyCore::set_end_time(env.clone(), hid, 2_000).unwrap();
+            HuntyCore::set_grace_period(env.clone(), hid, 300).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            assert_eq!(HuntyCore::get_hunt_time_remaining(env.clone(), hid), (1_300, 2_300));
+
+            // Past end_time but inside the grace period: answers land, registration is shut.
+            env.ledger().set_timestamp(2_100);
+            let within = in_frame(env, cid, || {
+                let answer = String::from_str(env, "answer");
+                HuntyCore::submit_answer(env.clone(), hid, 1, player.clone(), answer)
+            });
+            assert_eq!(within, Ok(10));
+            assert!(!HuntyCore::is_hunt_active(env.clone(), hid));
+            let register = in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, latecomer.clone())
+            });
+            assert_eq!(register, Err(HuntErrorCode::HuntNotActive));
+            assert_eq!(HuntyCore::get_hunt_time_remaining(env.clone(), hid), (200, 2_300));
+
+            env.ledger().set_timestamp(2_300);
+            let beyond = in_frame(env, cid, || {
+                let answer = String::from_str(env, "answer");
+                HuntyCore::submit_answer(env.clone(), hid, 2, player.clone(), answer)
+            });
+            assert_eq!(beyond, Err(HuntErrorCode::HuntNotActive));
+            assert_eq!(HuntyCore::get_hunt_time_remaining(env.clone(), hid), (0, 2_300));
+            assert_eq!(HuntyCore::get_hunt_time_remaining(env.clone(), 99), (0, 0));
+        });
+    }
+
+    #[test]
+    fn test_create_hunt_rejects_end_before_start() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let result = with_core_contract(&env, |env, _| {
+            HuntyCore::create_hunt(
+                env.clone(),
+                creator.clone(),
+                String::from_str(env, "Backwards"),
+                String::from_str(env, "Desc"),
+                Some(2_000),
+                Some(1_000),
+            )
+        });
+
+        assert_eq!(result, Err(HuntErrorCode::InvalidEndTime));
+    }
+
+    // ========== get_unsolved_clues() Tests ==========
+
+    #[test]
+    fn test_get_unsolved_clues_after_partial_progress() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        let (before, after, unregistered) = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 4);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            let before = HuntyCore::get_unsolved_clues(env.clone(), hid, player.clone());
+            for clue_id in [1, 3] {
+                in_frame(env, cid, || {
+                    let answer = String::from_str(env, "answer");
+                    HuntyCore::submit_answer(env.clone(), hid, clue_id, player.clone(), answer)
+                        .unwrap()
+                });
+            }
+            (
+                before,
+                HuntyCore::get_unsolved_clues(env.clone(), hid, player.clone()),
+                HuntyCore::get_unsolved_clues(env.clone(), hid, stranger.clone()),
+            )
+        });
+
+        assert_eq!(before, soroban_sdk::vec![&env, 1, 2, 3, 4]);
+        assert_eq!(after, soroban_sdk::vec![&env, 2, 4]);
+        assert_eq!(unregistered, soroban_sdk::vec![&env, 1, 2, 3, 4]);
+    }
+
+    // ========== simulate_completion() Tests ==========
+
+    #[test]
+    fn test_simulate_completion_leaves_progress_untouched() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (partial, full, stranger, progress) = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 4);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            for clue_id in [1, 3] {
+                in_frame(env, cid, || {
+                    let answer = String::from_str(env, "answer");
+                    HuntyCore::submit_answer(env.clone(), hid, clue_id, player.clone(), answer)
+                        .unwrap()
+                });
+            }
+            let simulate = |player: &Address, clue_ids: Vec<u32>| {
+                HuntyCore::simulate_completion(env.clone(), hid, player.clone(), clue_ids)
+            };
+            (
+                simulate(&player, soroban_sdk::vec![env, 2]),
+                // Already-solved and unknown clues are ignored.
+                simulate(&player, soroban_sdk::vec![env, 2, 4, 1, 99]),
+                simulate(&Address::generate(env), soroban_sdk::vec![env, 1, 2, 3, 4]),
+                Storage::get_player_progress(env, hid, &player).unwrap(),
+            )
+        });
+
+        assert_eq!(partial, (30, false));
+        assert_eq!(full, (40, true));
+        assert_eq!(stranger, (0, false));
+        assert_eq!(progress.total_score, 20);
+        assert!(!progress.is_completed);
+    }
+
+    // ========== set_prerequisite_hunt() Tests ==========
+
+    #[test]
+    fn test_prerequisite_hunt_gates_registration() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        with_core_contract(&env, |env, cid| {
+            let part_one = create_hunt_with_clues(env, &creator, 1, true);
+            let part_two = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 1, true));
+            let link = |hunt_id, prerequisite| {
+                HuntyCore::set_prerequisite_hunt(env.clone(), hunt_id, prerequisite)
+            };
+            link(part_two, Some(part_one)).unwrap();
+            assert_eq!(link(part_one, Some(part_two)), Err(HuntErrorCode::PrerequisiteCycle));
+            assert_eq!(link(part_one, Some(part_one)), Err(HuntErrorCode::PrerequisiteCycle));
+            assert_eq!(link(part_one, Some(99)), Err(HuntErrorCode::HuntNotFound));
+            HuntyCore::activate_hunt(env.clone(), part_one).unwrap();
+            HuntyCore::activate_hunt(env.clone(), part_two).unwrap();
+
+            let register = || {
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), part_two, player.clone())
+                })
+            };
+            assert_eq!(register(), Err(HuntErrorCode::PrerequisiteNotMet));
+            register_and_complete(env, cid, part_one, &player);
+            assert_eq!(register(), Ok(()));
+        });
+    }
+
+    // ========== Creator Play Tests ==========
+
+    #[test]
+    fn test_creator_and_collaborator_cannot_register_by_default() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let collaborator = Address::generate(&env);
+
+        let (creator_err, collaborator_err) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::add_collaborator(env.clone(), hid, collaborator.clone()).unwrap();
+            in_frame(env, cid, || HuntyCore::activate_hunt(env.clone(), hid).unwrap());
+            let creator_err = in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, creator.clone())
+            });
+            let collaborator_err = in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, collaborator.clone())
+            });
+            (creator_err, collaborator_err)
+        });
+
+        assert_eq!(creator_err, Err(HuntErrorCode::CreatorCannotPlay));
+        assert_eq!(collaborator_err, Err(HuntErrorCode::CreatorCannotPlay));
+    }
+
+    #[test]
+    fn test_creator_can_register_when_allowed() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        let registered = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_allow_creator_play(env.clone(), hid, true).unwrap();
+            in_frame(env, cid, || HuntyCore::activate_hunt(env.clone(), hid).unwrap());
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, creator.clone()).unwrap()
+            });
+            Storage::get_player_progress(env, hid, &creator).is_some()
+        });
+
+        assert!(registered);
+    }
+
+    // ========== award_points() Tests ==========
+
+    #[test]
+    fn test_award_points_overrides_clue_value_in_judged_hunt() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (awarded, again, progress) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 2, true);
+            HuntyCore::set_manual_judging(env.clone(), hid, true).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            let awarded = HuntyCore::award_points(env.clone(), hid, player.clone(), 1, 37).unwrap();
+            let again = HuntyCore::award_points(env.clone(), hid, player.clone(), 1, 5);
+            in_frame(env, cid, || {
+                HuntyCore::award_points(env.clone(), hid, player.clone(), 2, 3).unwrap()
+            });
+            (awarded, again, Storage::get_player_progress(env, hid, &player).unwrap())
+        });
+
+        assert_eq!(awarded, 37);
+        assert_eq!(again, Err(HuntErrorCode::ClueAlreadyCompleted));
+        // 37 + 3 rather than the clues' default 10 + 10.
+        assert_eq!(progress.total_score, 40);
+        assert!(progress.is_completed);
+    }
+
+    #[test]
+    fn test_award_points_requires_manual_judging() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (err, score) = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 1);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            let err = HuntyCore::award_points(env.clone(), hid, player.clone(), 1, 99);
+            (err, Storage::get_player_progress(env, hid, &player).unwrap().total_score)
+        });
+
+        assert_eq!(err, Err(HuntErrorCode::InvalidHuntStatus));
+        assert_eq!(score, 0);
+    }
+
+    // ========== get_score_breakdown() Tests ==========
+
+    #[test]
+    fn test_score_breakdown_lists_points_per_solve() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (breakdown, total, stranger) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 0, false);
+            for points in [10, 25, 5] {
+                in_frame(env, cid, || {
+                    let q = String::from_str(env, "Q");
+                    let a = String::from_str(env, "answer");
+                    HuntyCore::add_clue(env.clone(), hid, creator.clone(), q, a, points, true)
+                        .unwrap()
+                });
+            }
+            in_frame(env, cid, || {
+                HuntyCore::set_clue_first_solve_bonus(env.clone(), hid, creator.clone(), 3, 2)
+                    .unwrap()
+            });
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            for clue_id in [2, 1, 3] {
+                in_frame(env, cid, || {
+                    let answer = String::from_str(env, "answer");
+                    HuntyCore::submit_answer(env.clone(), hid, clue_id, player.clone(), answer)
+                        .unwrap()
+                });
+            }
+            (
+                HuntyCore::get_score_breakdown(env.clone(), hid, player.clone()),
+                Storage::get_player_progress(env, hid, &player).unwrap().total_score,
+                HuntyCore::get_score_breakdown(env.clone(), hid, Address::generate(env)),
+            )
+        });
+
+        assert_eq!(breakdown, soroban_sdk::vec![&env, (2, 25), (1, 10), (3, 7)]);
+        assert_eq!(total, 42);
+        assert!(stranger.is_empty());
+    }
+
+    // ========== get_player_last_activity() Tests ==========
+
+    #[test]
+    fn test_last_activity_advances_with_each_solve() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (unregistered, registered, after_first, after_wrong, after_second) =
+            with_core_contract(&env, |env, cid| {
+                let hid = create_active_hunt(env, &creator, 3);
+                let last = || HuntyCore::get_player_last_activity(env.clone(), hid, player.clone());
+                let unregistered = last();
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                });
+                let registered = last();
+                let submit = |clue_id: u32, answer: &str| {
+                    in_frame(env, cid, || {
+                        let answer = String::from_str(env, answer);
+                        HuntyCore::submit_answer(env.clone(), hid, clue_id, player.clone(), answer)
+                    })
+                };
+                env.ledger().set_timestamp(1_700_000_050);
+                submit(1, "answer").unwrap();
+                let after_first = last();
+                env.ledger().set_timestamp(1_700_000_080);
+                submit(2, "wrong").unwrap_err();
+                let after_wrong = last();
+                env.ledger().set_timestamp(1_700_000_120);
+                submit(2, "answer").unwrap();
+                (unregistered, registered, after_first, after_wrong, last())
+            });
+
+        assert_eq!(unregistered, None);
+        assert_eq!(registered, Some(1_700_000_000));
+        assert_eq!(after_first, Some(1_700_000_050));
+        assert_eq!(after_wrong, Some(1_700_000_050));
+        assert_eq!(after_second, Some(1_700_000_120));
+    }
+
+    // ========== get_hunt_view() Tests ==========
+
+    #[test]
+    fn test_get_hunt_view_flags_solved_clues() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        let (view, page, unregistered) = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 4);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            for clue_id in [2, 3] {
+                in_frame(env, cid, || {
+                    let answer = String::from_str(env, "answer");
+                    HuntyCore::submit_answer(env.clone(), hid, clue_id, player.clone(), answer)
+                        .unwrap()
+                });
+            }
+            (
+                HuntyCore::get_hunt_view(env.clone(), hid, player.clone(), 0, 10),
+                HuntyCore::get_hunt_view(env.clone(), hid, player.clone(), 1, 2),
+                HuntyCore::get_hunt_view(env.clone(), hid, stranger.clone(), 0, 10),
+            )
+        });
+
+        let flags = |v: &Vec<crate::types::ClueWithStatus>| {
+            v.iter().map(|c| (c.clue.clue_id, c.solved)).collect::<std::vec::Vec<_>>()
+        };
+        assert_eq!(flags(&view), [(1, false), (2, true), (3, true), (4, false)]);
+        assert_eq!(flags(&page), [(2, true), (3, true)]);
+        assert!(unregistered.iter().all(|c| !c.solved));
+        assert_eq!(unregistered.len(), 4);
+        assert_eq!(view.get(0).unwrap().clue.points, 10);
+    }
+
+    // ========== get_progress_batch() Tests ==========
+
+    #[test]
+    fn test_get_progress_batch_aligns_to_input() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        let (batch, capped) = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 1);
+            register_and_complete(env, cid, hid, &alice);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, bob.clone()).unwrap()
+            });
+            let query = soroban_sdk::vec![
+                env,
+                stranger.clone(),
+                alice.clone(),
+                stranger.clone(),
+                bob.clone()
+            ];
+            let mut oversized = Vec::new(env);
+            for _ in 0..60 {
+                oversized.push_back(alice.clone());
+            }
+            (
+                HuntyCore::get_progress_batch(env.clone(), hid, query),
+                HuntyCore::get_progress_batch(env.clone(), hid, oversized).len(),
+            )
+        });
+
+        assert_eq!(batch.len(), 4);
+        assert!(batch.get(0).unwrap().is_none());
+        let alice_progress = batch.get(1).unwrap().unwrap();
+        assert_eq!(alice_progress.player, alice);
+        assert!(alice_progress.is_completed);
+        assert!(batch.get(2).unwrap().is_none());
+        let bob_progress = batch.get(3).unwrap().unwrap();
+        assert_eq!(bob_progress.player, bob);
+        assert!(!bob_progress.is_completed);
+        assert_eq!(capped, 50);
+    }
+
+    // ========== is_hunt_active() Tests ==========
+
+    #[test]
+    fn test_is_hunt_active_states() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        let (active, draft, timed, expired, missing) = with_core_contract(&env, |env, cid| {
+            let active = create_active_hunt(env, &creator, 1);
+            let draft = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 1, true));
+            let timed = in_frame(env, cid, || {
+                HuntyCore::create_hunt(
+                    env.clone(),
+                    creator.clone(),
+                    String::from_str(env, "Timed"),
+                    String::from_str(env, "Desc"),
+                    None,
+                    Some(1_700_000_100),
+                )
+                .unwrap()
+            });
+            HuntyCore::add_clue(
+                env.clone(),
+                timed,
+                creator.clone(),
+                String::from_str(env, "Q"),
+                String::from_str(env, "answer"),
+                10,
+                true,
+            )
+            .unwrap();
+            HuntyCore::activate_hunt(env.clone(), timed).unwrap();
+            let timed_before = HuntyCore::is_hunt_active(env.clone(), timed);
+            env.ledger().set_timestamp(1_700_000_100);
+            (
+                HuntyCore::is_hunt_active(env.clone(), active),
+                HuntyCore::is_hunt_active(env.clone(), draft),
+                timed_before,
+                HuntyCore::is_hunt_active(env.clone(), timed),
+                HuntyCore::is_hunt_active(env.clone(), 9999),
+            )
+        });
+
+        assert!(active);
+        assert!(!draft);
+        assert!(timed);
+        assert!(!expired);
+        assert!(!missing);
+    }
+
+    // ========== get_clue_ids() Tests ==========
+
+    #[test]
+    fn test_get_clue_ids_follows_index_order() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (initial, reordered, missing) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 4, true);
+            let initial = HuntyCore::get_clue_ids(env.clone(), hid);
+            let order = soroban_sdk::vec![env, 4, 2, 1, 3];
+            HuntyCore::reorder_clues(env.clone(), hid, creator.clone(), order).unwrap();
+            (
+                initial,
+                HuntyCore::get_clue_ids(env.clone(), hid),
+                HuntyCore::get_clue_ids(env.clone(), 9999),
+            )
+        });
+
+        assert_eq!(initial, soroban_sdk::vec![&env, 1, 2, 3, 4]);
+        assert_eq!(reordered, soroban_sdk::vec![&env, 4, 2, 1, 3]);
+        assert!(missing.is_empty());
+    }
+
+    #[test]
+    fn test_required_clue_ids_track_required_flag() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 4, false);
+            let required = || HuntyCore::get_required_clue_ids(env.clone(), hid);
+            let set = |clue_id: u32, flag: bool| {
+                in_frame(env, cid, || {
+                    HuntyCore::set_clue_required(env.clone(), hid, creator.clone(), clue_id, flag)
+                        .unwrap()
+                })
+            };
+            assert!(required().is_empty());
+
+            set(3, true);
+            set(1, true);
+            assert_eq!(required(), soroban_sdk::vec![env, 1, 3]);
+            set(3, false);
+            assert_eq!(required(), soroban_sdk::vec![env, 1]);
+
+            Storage::remove_clue(env, hid, 1);
+            assert!(required().is_empty());
+        });
+    }
+
+    // ========== reorder_clues() Tests ==========
+
+    #[test]
+    fn test_reorder_clues_drives_ordered_solving() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (listed, out_of_order, first) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 3, true);
+            HuntyCore::set_ordered(env.clone(), hid, true).unwrap();
+            let order = soroban_sdk::vec![env, 3, 1, 2];
+            HuntyCore::reorder_clues(env.clone(), hid, creator.clone(), order).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            let listed: std::vec::Vec<u32> = HuntyCore::list_clues(env.clone(), hid)
+                .iter()
+                .map(|c| c.clue_id)
+                .collect();
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            let answer = String::from_str(env, "answer");
+            let out_of_order = in_frame(env, cid, || {
+                HuntyCore::submit_answer(env.clone(), hid, 1, player.clone(), answer.clone())
+                    .unwrap_err()
+            });
+            let first = in_frame(env, cid, || {
+                HuntyCore::submit_answer(env.clone(), hid, 3, player.clone(), answer.clone())
+            });
+            (listed, out_of_order, first)
+        });
+
+        assert_eq!(listed, std::vec![3, 1, 2]);
+        assert_eq!(out_of_order, HuntErrorCode::InvalidClueOrder);
+        assert_eq!(first, Ok(10));
+    }
+
+    #[test]
+    fn test_reorder_clues_rejects_bad_permutation() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (duplicate, short, unknown) = with_core_contract(&env, |env, _cid| {
+            let hid = create_hunt_with_clues(env, &creator, 3, true);
+            let reorder = |order| HuntyCore::reorder_clues(env.clone(), hid, creator.clone(), order);
+            let duplicate = reorder(soroban_sdk::vec![env, 1, 1, 2]);
+            let short = reorder(soroban_sdk::vec![env, 1, 2]);
+            let unknown = reorder(soroban_sdk::vec![env, 1, 2, 9]);
+            (duplicate, short, unknown)
+        });
+
+        assert_eq!(duplicate, Err(HuntErrorCode::InvalidClueOrder));
+        assert_eq!(short, Err(HuntErrorCode::InvalidClueOrder));
+        assert_eq!(unknown, Err(HuntErrorCode::InvalidClueOrder));
+    }
+
+    // ========== add_accepted_answer() / remove_accepted_answer() Tests ==========
+
+    #[test]
+    fn test_accepted_answers_match_any_alternative() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let players: std::vec::Vec<Address> = (0..4).map(|_| Address::generate(&env)).collect();
+
+        with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            let text = |t: &str| String::from_str(env, t);
+            let add =
+                |t| HuntyCore::add_accepted_answer(env.clone(), hid, creator.clone(), 1, text(t));
+            for alt in ["colour", "color", "hue", "tint", "shade"] {
+                add(alt).unwrap();
+            }
+            // Re-adding an accepted answer is a no-op; a sixth alternative is over the cap.
+            assert_eq!(add("  COLOR "), Ok(()));
+            assert_eq!(add("answer"), Ok(()));
+            assert_eq!(add("tone"), Err(HuntErrorCode::TooManyAnswers));
+            let remove =
+                |t| HuntyCore::remove_accepted_answer(env.clone(), hid, creator.clone(), 1, text(t));
+            remove("hue").unwrap();
+            assert_eq!(remove("hue"), Err(HuntErrorCode::InvalidAnswer));
+
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            let mut results = std::vec::Vec::new();
+            for (player, answer) in players.iter().zip(["answer", "Colour", "shade", "hue"]) {
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                });
+                results.push(in_frame(env, cid, || {
+                    HuntyCore::submit_answer(env.clone(), hid, 1, player.clone(), text(answer))
+                }));
+            }
+            assert_eq!(
+                results,
+                std::vec![Ok(10), Ok(10), Ok(10), Err(HuntErrorCode::InvalidAnswer)]
+            );
+        });
+    }
+
+    // ========== set_min_answer_length() Tests ==========
+
+    #[test]
+    fn test_min_answer_length_rejects_short_answers() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 0, true);
+            let text = |t: &str| String::from_str(env, t);
+            let add = |answer: &str| {
+                in_frame(env, cid, || {
+                    HuntyCore::add_clue(
+                        env.clone(), hid, creator.clone(), text("Q"), text(answer), 10, true,
+                    )
+                })
+            };
+            // Without a minimum, any non-empty answer is fine.
+            assert_eq!(add("yes"), Ok(1));
+
+            in_frame(env, cid, || HuntyCore::set_min_answer_length(env.clone(), hid, 4).unwrap());
+            // Length is measured after trimming.
+            assert_eq!(add("  yes  "), Err(HuntErrorCode::InvalidAnswer));
+            assert_eq!(add("lighthouse"), Ok(2));
+            assert_eq!(
+                in_frame(env, cid, || {
+                    HuntyCore::add_accepted_answer(env.clone(), hid, creator.clone(), 2, text("no"))
+                }),
+                Err(HuntErrorCode::InvalidAnswer)
+            );
+            assert_eq!(
+                in_frame(env, cid, || HuntyCore::set_min_answer_length(env.clone(), hid, 257)),
+                Err(HuntErrorCode::InvalidAmount)
+            );
+        });
+    }
+
+    // ========== Attempt Limit Tests ==========
+
+    #[test]
+    fn test_attempt_lockout_cooldown_doubles() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_attempt_limit(env.clone(), hid, 2, 60).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap();
+            let submit = |answer: &str| {
+                in_frame(env, cid, || {
+                    let answer = String::from_str(env, answer);
+                    HuntyCore::submit_answer(env.clone(), hid, 1, player.clone(), answer)
+                })
+            };
+            let state = || HuntyCore::get_attempt_state(env.clone(), hid, 1, player.clone());
+
+            // Wrong answers are recorded (0 points) until the limit starts a 60s lockout.
+            assert_eq!(submit("nope"), Ok(0));
+            assert_eq!(state(), (1, 0));
+            assert_eq!(submit("nope"), Ok(0));
+            assert_eq!(state(), (2, 1_700_000_060));
+            assert_eq!(submit("answer"), Err(HuntErrorCode::TooManyAttempts));
+
+            // Once the cooldown has passed the player gets a fresh round; the next lockout
+            // lasts twice as long.
+            env.ledger().set_timestamp(1_700_000_060);
+            assert_eq!(state(), (0, 1_700_000_060));
+            assert_eq!(submit("nope"), Ok(0));
+            assert_eq!(submit("nope"), Ok(0));
+            assert_eq!(state(), (2, 1_700_000_180));
+            env.ledger().set_timestamp(1_700_000_179);
+            assert_eq!(submit("answer"), Err(HuntErrorCode::TooManyAttempts));
+
+            env.ledger().set_timestamp(1_700_000_180);
+            assert_eq!(submit("answer"), Ok(10));
+            assert_eq!(state(), (0, 0));
+        });
+    }
+
+    // ========== set_answer_algo() Tests ==========
+
+    #[test]
+    fn test_answer_algo_hashes_and_verifies_per_hunt() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        with_core_contract(&env, |env, cid| {
+            let text = |t: &str| String::from_str(env, t);
+            let normalized = Bytes::from_slice(env, b"answer");
+            for (algo, expected) in [
+                (HashAlgo::Sha256, env.crypto().sha256(&normalized).to_bytes()),
+                (HashAlgo::Keccak256, env.crypto().keccak256(&normalized).to_bytes()),
+            ] {
+                let hid = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 0, true));
+                in_frame(env, cid, || {
+                    HuntyCore::set_answer_algo(env.clone(), hid, algo.clone()).unwrap()
+                });
+                assert_eq!(HuntyCore::get_answer_algo(env.clone(), hid), Some(algo.clone()));
+                in_frame(env, cid, || {
+                    HuntyCore::add_clue(
+                        env.clone(), hid, creator.clone(), text("Q"), text(" Answer "), 10, true,
+                    )
+                    .unwrap()
+                });
+                assert_eq!(Storage::get_clue(env, hid, 1).unwrap().answer_hash, expected);
+                // Stored hashes depend on the algorithm, so it is locked once clues exist.
+                assert_eq!(
+                    in_frame(env, cid, || {
+                        HuntyCore::set_answer_algo(env.clone(), hid, HashAlgo::Sha256)
+                    }),
+                    Err(HuntErrorCode::InvalidHuntStatus)
+                );
+
+                in_frame(env, cid, || HuntyCore::activate_hunt(env.clone(), hid).unwrap());
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                });
+                let submit = |answer: &str| {
+                    in_frame(env, cid, || {
+                        HuntyCore::submit_answer(env.clone(), hid, 1, player.clone(), text(answer))
+                    })
+                };
+                assert_eq!(submit("wrong"), Err(HuntErrorCode::InvalidAnswer));
+                assert_eq!(submit("ANSWER"), Ok(10));
+            }
+            assert_eq!(HuntyCore::get_answer_algo(env.clone(), 99), None);
+        });
+    }
+
+    // ========== rotate_salt() Tests ==========
+
+    #[test]
+    fn test_rotate_salt_rehashes_every_clue() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 2, true);
+            let answer = |text: &str| String::from_str(env, text);
+            HuntyCore::add_accepted_answer(env.clone(), hid, creator.clone(), 2, answer("alt"))
+                .unwrap();
+            let old_hash = Storage::get_clue(env, hid, 1).unwrap().answer_hash;
+
+            // The creator salts the hashes off-chain; only hashes reach the contract.
+            let salt = BytesN::from_array(env, &[7; 32]);
+            let salted = |text: &str| {
+                let hash =
+                    HuntyCore::normalize_and_hash_answer(env, &HashAlgo::Sha256, &answer(text))
+                        .unwrap();
+                let mut preimage = Bytes::from_array(env, &salt.to_array());
+                preimage.extend_from_array(&hash.to_array());
+                env.crypto().sha256(&preimage).to_bytes()
+            };
+            let none = Vec::new(env);
+            let rotate = |entries| HuntyCore::rotate_salt(env.clone(), hid, salt.clone(), entries);
+
+            let missing = rotate(soroban_sdk::vec![env, (1, salted("answer"), none.clone())]);
+            assert_eq!(missing, Err(HuntErrorCode::InvalidClueOrder));
+            let duplicate = rotate(soroban_sdk::vec![
+                env,
+                (1, salted("answer"), none.clone()),
+                (1, salted("answer"), none.clone()),
+            ]);
+            assert_eq!(duplicate, Err(HuntErrorCode::InvalidClueOrder));
+            // Clue 2's alternative must be re-salted rather than silently dropped.
+            let dropped_alt = rotate(soroban_sdk::vec![
+                env,
+                (1, salted("answer"), none.clone()),
+                (2, salted("fresh"), none.clone()),
+            ]);
+            assert_eq!(dropped_alt, Err(HuntErrorCode::InvalidAnswer));
+            assert_eq!(Storage::get_answer_salt(env, hid), None);
+
+            // Clue 2's leaked answer is replaced while rotating.
+            rotate(soroban_sdk::vec![
+                env,
+                (1, salted("answer"), none.clone()),
+                (2, salted("fresh"), soroban_sdk::vec![env, salted("alt")]),
+            ])
+            .unwrap();
+            assert_eq!(Storage::get_answer_salt(env, hid), Some(salt.clone()));
+            assert_eq!(Storage::get_clue(env, hid, 1).unwrap().answer_hash, salted("answer"));
+            assert_ne!(salted("answer"), old_hash);
+            HuntyCore::add_clue(
+                env.clone(),
+                hid,
+                creator.clone(),
+                answer("Third"),
+                answer("third"),
+                10,
+                true,
+            )
+            .unwrap();
+
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            let submit = |clue_id, text| {
+                in_frame(env, cid, || {
+                    HuntyCore::submit_answer(env.clone(), hid, clue_id, player.clone(), answer(text))
+                })
+            };
+            assert_eq!(submit(1, "answer"), Ok(10));
+            assert_eq!(submit(2, "answer"), Err(HuntErrorCode::InvalidAnswer));
+            assert_eq!(submit(2, "ALT"), Ok(10));
+            assert_eq!(submit(3, "third"), Ok(10));
+        });
+    }
+
+    // ========== verify_clue_hashes() Tests ==========
+
+    #[test]
+    fn test_verify_clue_hashes_mixed_inputs() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 2, true);
+            let digest = env.crypto().sha256(&Bytes::from_slice(env, b"answer")).to_array();
+            let hex: std::string::String =
+                digest.iter().map(|b| std::format!("{:02x}", b)).collect();
+            let text = |t: &str| String::from_str(env, t);
+            let wrong = "00".repeat(32);
+            let verify = || {
+                HuntyCore::verify_clue_hashes(
+                    env.clone(),
+                    hid,
+                    soroban_sdk::vec![
+                        env,
+                        (1, text(&hex)),
+                        (2, text(&hex.to_uppercase())),
+                        (2, text(&wrong)),
+                        (9, text(&hex)),
+                        (1, text("zz")),
+                    ],
+                )
+            };
+            assert_eq!(verify(), soroban_sdk::vec![env, true, true, false, false, false]);
+
+            // The salt is applied on the contract side, so plain hashes still verify.
+            let salt = BytesN::from_array(env, &[7; 32]);
+            let mut preimage = Bytes::from_array(env, &salt.to_array());
+            preimage.extend_from_array(&digest);
+            let salted: BytesN<32> = env.crypto().sha256(&preimage).to_bytes();
+            let entries = soroban_sdk::vec![
+                env,
+                (1, salted.clone(), Vec::new(env)),
+                (2, salted, Vec::new(env)),
+            ];
+            HuntyCore::rotate_salt(env.clone(), hid, salt, entries).unwrap();
+            assert_eq!(verify(), soroban_sdk::vec![env, true, true, false, false, false]);
+        });
+    }
+
+    // ========== close_hunt() Tests ==========
+
+    #[test]
+    fn test_close_hunt_reclaims_unreserved_escrow_and_stops_play() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let straggler = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 1_000);
+
+        let hid = with_core_contract(&env, |env, cid| {
+            let hid = create_funded_hunt(env, &creator, &token, 1_000, 2);
+            register_and_complete(env, cid, hid, &winner);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, straggler.clone()).unwrap()
+            });
+
+            env.ledger().set_timestamp(1_700_000_500);
+            assert_eq!(HuntyCore::close_hunt(env.clone(), hid), Ok(500));
+            let hunt = Storage::get_hunt(env, hid).unwrap();
+            assert_eq!(hunt.status, HuntStatus::Completed);
+            assert_eq!(hunt.closed_at, 1_700_000_500);
+            assert_eq!(HuntyCore::get_global_stats(env.clone()).active_hunts, 0);
+
+            let late = in_frame(env, cid, || {
+                let answer = String::from_str(env, "answer");
+                HuntyCore::submit_answer(env.clone(), hid, 1, straggler.clone(), answer)
+            });
+            assert_eq!(late, Err(HuntErrorCode::HuntNotActive));
+            let again = in_frame(env, cid, || HuntyCore::close_hunt(env.clone(), hid));
+            assert_eq!(again, Err(HuntErrorCode::InvalidHuntStatus));
+            let cancel = in_frame(env, cid, || {
+                HuntyCore::cancel_hunt(env.clone(), hid, String::from_str(env, ""))
+            });
+            assert_eq!(cancel, Err(HuntErrorCode::InvalidHuntStatus));
+
+            // The reserved winner can still collect.
+            let claimed = in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, winner.clone(), None)
+            });
+            assert_eq!(claimed, Ok(500));
+            hid
+        });
+
+        assert!(get_all_events_for_hunt(&env, hid).iter().any(|event| matches!(
+            event,
+            HuntEvent::HuntClosed(e) if e.reclaimed == 500 && e.closed_at == 1_700_000_500
+        )));
+        let client = TokenClient::new(&env, &token);
+        assert_eq!(client.balance(&creator), 500);
+        assert_eq!(client.balance(&winner), 500);
+    }
+
+    // ========== cancel_hunt() / claim_cancellation_refund() Tests ==========
+
+    #[test]
+    fn test_cancellation_refunds_entry_fee_to_player() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 1_000);
+        StellarAssetClient::new(&env, &token).mint(&player, &50);
+
+        let (creator_refund, player_refund, second, status) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_max_winners(env.clone(), hid, 1).unwrap();
+            HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), 1_000).unwrap();
+            HuntyCore::set_entry_fee(env.clone(), hid, token.clone(), 50).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            let creator_refund = in_frame(env, cid, || HuntyCore::cancel_hunt(env.clone(), hid, String::from_str(env, "")).unwrap());
+            let player_refund = in_frame(env, cid, || {
+                HuntyCore::claim_cancellation_refund(env.clone(), hid, player.clone()).unwrap()
+            });
+            let second = in_frame(env, cid, || {
+                HuntyCore::claim_cancellation_refund(env.clone(), hid, player.clone()).unwrap()
+            });
+            (creator_refund, player_refund, second, Storage::get_hunt(env, hid).unwrap().status)
+        });
+
+        assert_eq!(status, HuntStatus::Cancelled);
+        assert_eq!(creator_refund, 1_000);
+        assert_eq!(player_refund, 50);
+        assert_eq!(second, 0);
+        let client = TokenClient::new(&env, &token);
+        assert_eq!(client.balance(&creator), 1_000);
+        assert_eq!(client.balance(&player), 50);
+    }
+
+    #[test]
+    fn test_cancellation_refund_nothing_for_non_payer() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let outsider = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 0);
+        StellarAssetClient::new(&env, &token).mint(&player, &50);
+
+        let (refund, not_cancelled) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_entry_fee(env.clone(), hid, token.clone(), 50).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            let not_cancelled = in_frame(env, cid, || {
+                HuntyCore::claim_cancellation_refund(env.clone(), hid, player.clone()).unwrap_err()
+            });
+            in_frame(env, cid, || HuntyCore::cancel_hunt(env.clone(), hid, String::from_str(env, "")).unwrap());
+            let refund = in_frame(env, cid, || {
+                HuntyCore::claim_cancellation_refund(env.clone(), hid, outsider.clone()).unwrap()
+            });
+            (refund, not_cancelled)
+        });
+
+        assert_eq!(not_cancelled, HuntErrorCode::InvalidHuntStatus);
+        assert_eq!(refund, 0);
+        assert_eq!(TokenClient::new(&env, &token).balance(&outsider), 0);
+    }
+
+    #[test]
+    fn test_cancel_hunt_records_reason() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let reason = String::from_str(&env, "Venue closed");
+        let too_long = String::from_str(&env, &"x".repeat(501));
+
+        let (with_reason, without_reason, rejected) = with_core_contract(&env, |env, cid| {
+            let explained = create_hunt_with_clues(env, &creator, 1, true);
+            let rejected = HuntyCore::cancel_hunt(env.clone(), explained, too_long.clone());
+            in_frame(env, cid, || {
+                HuntyCore::cancel_hunt(env.clone(), explained, reason.clone()).unwrap()
+            });
+            let silent = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 1, true));
+            in_frame(env, cid, || {
+                HuntyCore::cancel_hunt(env.clone(), silent, String::from_str(env, "")).unwrap()
+            });
+            let cancelled = |hid: u64| {
+                let stored = Storage::get_hunt(env, hid).unwrap().cancel_reason;
+                let event = get_all_events_for_hunt(env, hid)
+                    .into_iter()
+                    .find(|e| matches!(e, HuntEvent::HuntCancelled(_)));
+                (stored, event)
+            };
+            (cancelled(explained), cancelled(silent), rejected)
+        });
+
+        assert_eq!(rejected, Err(HuntErrorCode::InvalidDescription));
+        assert_eq!(with_reason.0, reason);
+        assert_eq!(
+            with_reason.1,
+            Some(HuntEvent::HuntCancelled(HuntCancelledEvent {
+                hunt_id: 1,
+                reason,
+                event_seq: 4,
+            }))
+        );
+        let empty = String::from_str(&env, "");
+        assert_eq!(without_reason.0, empty);
+        assert_eq!(
+            without_reason.1,
+            Some(HuntEvent::HuntCancelled(HuntCancelledEvent {
+                hunt_id: 2,
+                reason: empty,
+                event_seq: 4,
+            }))
+        );
+    }
+
+    // ========== get_hunt_summaries() Tests ==========
+
+    #[test]
+    fn test_hunt_summaries_skip_missing_ids() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 500);
+
+        let (summaries, funded, draft) = with_core_contract(&env, |env, cid| {
+            let funded = create_funded_hunt(env, &creator, &token, 500, 1);
+            let draft = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 3, true));
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), funded, player.clone()).unwrap()
+            });
+            let ids = soroban_sdk::vec![env, 42, draft, funded, 0];
+            (HuntyCore::get_hunt_summaries(env.clone(), ids), funded, draft)
+        });
+
+        assert_eq!(summaries.len(), 2);
+        let first = summaries.get(0).unwrap();
+        assert_eq!((first.hunt_id, first.status, first.total_clues), (draft, HuntStatus::Draft, 3));
+        assert_eq!((first.player_count, first.reward_pool), (0, 0));
+        let second = summaries.get(1).unwrap();
+        assert_eq!(second.hunt_id, funded);
+        assert_eq!(second.title, String::from_str(&env, "Hunt 1"));
+        assert_eq!((second.status, second.player_count), (HuntStatus::Active, 1));
+        assert_eq!((second.total_clues, second.reward_pool), (1, 500));
+    }
+
+    // ========== get_hunts_by_status() Tests ==========
+
+    #[test]
+    fn test_get_hunts_by_status_tracks_transitions() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        let buckets = with_core_contract(&env, |env, cid| {
+            let mut ids = std::vec::Vec::new();
+            for _ in 0..5 {
+                ids.push(in_frame(env, cid, || create_hunt_with_clues(env, &creator, 1, true)));
+            }
+            for &hid in &ids[1..4] {
+                in_frame(env, cid, || HuntyCore::activate_hunt(env.clone(), hid).unwrap());
+            }
+            // One cancelled from Active, one from Draft.
+            for hid in [ids[2], ids[0]] {
+                in_frame(env, cid, || {
+                    HuntyCore::cancel_hunt(env.clone(), hid, String::from_str(env, "")).unwrap()
+                });
+            }
+            let bucket =
+                |status: HuntStatus| HuntyCore::get_hunts_by_status(env.clone(), status, 0, 10);
+            (
+                bucket(HuntStatus::Draft),
+                bucket(HuntStatus::Active),
+                bucket(HuntStatus::Cancelled),
+                bucket(HuntStatus::Completed),
+                HuntyCore::get_hunts_by_status(env.clone(), HuntStatus::Active, 1, 5),
+            )
+        });
+
+        assert_eq!(buckets.0, soroban_sdk::vec![&env, 5]);
+        assert_eq!(buckets.1, soroban_sdk::vec![&env, 2, 4]);
+        assert_eq!(buckets.2, soroban_sdk::vec![&env, 3, 1]);
+        assert_eq!(buckets.3, soroban_sdk::vec![&env]);
+        assert_eq!(buckets.4, soroban_sdk::vec![&env, 4]);
+    }
+
+    // ========== get_event_seq() Tests ==========
+
+    #[test]
+    fn test_event_seq_numbers_each_hunt_event() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (hunt_id, other_id, seq, other_seq) = with_core_contract(&env, |env, cid| {
+            let hunt_id = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 2, true));
+            in_frame(env, cid, || {
+                HuntyCore::activate_hunt(env.clone(), hunt_id).unwrap()
+            });
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hunt_id, player.clone()).unwrap()
+            });
+            let other_id = in_frame(env, cid, || create_hunt_with_clues(env, &creator, 1, true));
+            (
+                hunt_id,
+                other_id,
+                HuntyCore::get_event_seq(env.clone(), hunt_id),
+                HuntyCore::get_event_seq(env.clone(), other_id),
+            )
+        });
+
+        // Created, two clues added, activated, registered.
+        assert_eq!(seq, 5);
+        assert_eq!(other_seq, 2);
+
+        let seqs: std::vec::Vec<u64> = get_all_events_for_hunt(&env, hunt_id)
+            .iter()
+            .map(|event| match event {
+                HuntEvent::HuntCreated(e) => e.event_seq,
+                HuntEvent::ClueAdded(e) => e.event_seq,
+                HuntEvent::HuntStatusChanged(e) => e.event_seq,
+                HuntEvent::PlayerRegistered(e) => e.event_seq,
+                other => panic!("unexpected event {:?}", other),
+            })
+            .collect();
+        assert_eq!(seqs, std::vec![1, 2, 3, 4, 5]);
+        assert_eq!(get_all_events_for_hunt(&env, other_id).len(), 2);
+        assert_eq!(
+            with_core_contract(&env, |env, _| HuntyCore::get_event_seq(env.clone(), 99)),
+            0
+        );
+    }
+
+    // ========== get_hunt_id_by_title() Tests ==========
+
+    #[test]
+    fn test_get_hunt_id_by_title() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let other = Address::generate(&env);
+        let title = String::from_str(&env, "Treasure");
+        let description = String::from_str(&env, "Desc");
+
+        let (hid, found, missing, other_creator, duplicate) = with_core_contract(&env, |env, cid| {
+            let hid = HuntyCore::create_hunt(
+                env.clone(),
+                creator.clone(),
+                title.clone(),
+                description.clone(),
+                None,
+                None,
+            )
+            .unwrap();
+            let found = HuntyCore::get_hunt_id_by_title(env.clone(), creator.clone(), title.clone());
+            let missing = HuntyCore::get_hunt_id_by_title(
+                env.clone(),
+                creator.clone(),
+                String::from_str(env, "treasure"),
+            );
+            let other_creator = HuntyCore::create_hunt(
+                env.clone(),
+                other.clone(),
+                title.clone(),
+                description.clone(),
+                None,
+                None,
+            );
+            let duplicate = in_frame(env, cid, || {
+                HuntyCore::create_hunt(
+                    env.clone(),
+                    creator.clone(),
+                    title.clone(),
+                    description.clone(),
+                    None,
+                    None,
+                )
+            });
+            (hid, found, missing, other_creator, duplicate)
+        });
+
+        assert_eq!(found, Some(hid));
+        assert_eq!(missing, None);
+        assert_eq!(other_creator, Ok(2));
+        assert_eq!(duplicate, Err(HuntErrorCode::DuplicateTitle));
+    }
+
+    // ========== Error Diagnostics Tests ==========
+
+    #[test]
+    #[cfg(feature = "error-events")]
+    fn test_error_events_published_on_failures() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (hid, hunt_errors, missing_errors) = with_core_contract(&env, |env, _cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            let _ = HuntyCore::register_player(env.clone(), hid, player.clone());
+            let _ = HuntyCore::add_clue(
+                env.clone(),
+                9999,
+                creator.clone(),
+                String::from_str(env, "Q"),
+                String::from_str(env, "a"),
+                1,
+                false,
+            );
+            let errors_for = |hunt_id| -> std::vec::Vec<HuntEvent> {
+                get_all_events_for_hunt(env, hunt_id)
+                    .into_iter()
+                    .filter(|event| matches!(event, HuntEvent::Error(_)))
+                    .collect()
+            };
+            (hid, errors_for(hid), errors_for(9999))
+        });
+
+        assert_eq!(
+            hunt_errors,
+            std::vec![HuntEvent::Error(crate::types::ErrorEvent {
+                code: HuntErrorCode::HuntNotActive as u32,
+                context: hid,
+            })]
+        );
+        assert_eq!(
+            missing_errors,
+            std::vec![HuntEvent::Error(crate::types::ErrorEvent {
+                code: HuntErrorCode::HuntNotFound as u32,
+                context: 9999,
+            })]
+        );
+    }
+
+    #[test]
+    fn test_error_events_not_published_on_success() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (hid, events) = with_core_contract(&env, |env, _cid| {
+            let hid = create_active_hunt(env, &creator, 2);
+            (hid, get_all_events_for_hunt(env, hid))
+        });
+
+        let names: std::vec::Vec<&str> = events
+            .iter()
+            .map(|event| match event {
+                HuntEvent::HuntCreated(_) => "HuntCreated",
+                HuntEvent::ClueAdded(_) => "ClueAdded",
+                HuntEvent::HuntStatusChanged(_) => "HuntStatusChanged",
+                _ => "other",
+            })
+            .collect();
+        assert_eq!(names, std::vec!["HuntCreated", "ClueAdded", "ClueAdded", "HuntStatusChanged"]);
+        assert_eq!(
+            events.last(),
+            Some(&HuntEvent::HuntStatusChanged(HuntStatusChangedEvent {
+                hunt_id: hid,
+                old_status: HuntStatus::Draft,
+                new_status: HuntStatus::Active,
+                event_seq: 4,
+            }))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "error-events")]
+    fn test_error_events_ignore_event_level() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let admin = Address::generate(&env);
+
+        let errors = with_core_contract(&env, |env, _cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            HuntyCore::set_event_level(env.clone(), EventLevel::None).unwrap();
+            let _ = HuntyCore::activate_hunt(env.clone(), 9999);
+            get_all_events_for_hunt(env, 9999)
+        });
+
+        assert_eq!(
+            errors,
+            std::vec![HuntEvent::Error(crate::types::ErrorEvent {
+                code: HuntErrorCode::HuntNotFound as u32,
+                context: 9999,
+            })]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "error-events")]
+    fn test_error_events_rolled_back_with_failed_invocation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let cid = env.register(HuntyCore, ());
+        let client = crate::HuntyCoreClient::new(&env, &cid);
+
+        assert_eq!(client.try_activate_hunt(&9999), Err(Ok(HuntErrorCode::HuntNotFound)));
+        // Like any event of a failed call, the ErrorEvent never reaches the event log.
+        assert!(get_all_events_for_hunt(&env, 9999).is_empty());
+    }
+
+    // ========== estimate_hunt_storage() Tests ==========
+
+    #[test]
+    fn test_estimate_hunt_storage_grows_with_hunt() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        let (small, large, missing) = with_core_contract(&env, |env, cid| {
+            let small_id = create_hunt_with_clues(env, &creator, 1, true);
+            let large_id = in_frame(env, cid, || create_active_hunt(env, &creator, 20));
+            for _ in 0..5 {
+                let player = Address::generate(env);
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), large_id, player.clone()).unwrap()
+                });
+            }
+            (
+                HuntyCore::estimate_hunt_storage(env.clone(), small_id),
+                HuntyCore::estimate_hunt_storage(env.clone(), large_id),
+                HuntyCore::estimate_hunt_storage(env.clone(), 9999),
+            )
+        });
+
+        // "Hunt 1" / "Hunt 2" titles (6 bytes) and "Desc" description (4 bytes).
+        assert_eq!(small, 512 + 10 + 320);
+        assert_eq!(large, 512 + 10 + 20 * 320 + 5 * 240);
+        assert!(large > small);
+        assert_eq!(missing, 0);
+    }
+
+    // ========== set_description_ref() Tests ==========
+
+    #[test]
+    fn test_description_ref_set_and_read() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            assert_eq!(HuntyCore::get_description_ref(env.clone(), hid), None);
+
+            let content = Bytes::from_slice(env, &[b'x'; 5000]);
+            let content_hash: BytesN<32> = env.crypto().sha256(&content).to_bytes();
+            let summary = String::from_str(env, "A city-wide hunt in five acts");
+            HuntyCore::set_description_ref(env.clone(), hid, content_hash.clone(), summary.clone())
+                .unwrap();
+            assert_eq!(
+                HuntyCore::get_description_ref(env.clone(), hid),
+                Some(DescriptionRef { content_hash: content_hash.clone(), summary })
+            );
+
+            let long_summary = String::from_str(env, &"s".repeat(281));
+            assert_eq!(
+                HuntyCore::set_description_ref(env.clone(), hid, content_hash, long_summary),
+                Err(HuntErrorCode::InvalidDescription)
+            );
+        });
+    }
+
+    // ========== set_max_players() Tests ==========
+
+    #[test]
+    fn test_max_players_rejects_registration_when_full() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        let (before, after, err, unlimited) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_max_players(env.clone(), hid, 2).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            let before = HuntyCore::get_remaining_slots(env.clone(), hid);
+            for _ in 0..2 {
+                let player = Address::generate(env);
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                });
+            }
+            let after = HuntyCore::get_remaining_slots(env.clone(), hid);
+            let late = Address::generate(env);
+            let err = in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, late.clone()).unwrap_err()
+            });
+            let open = in_frame(env, cid, || create_active_hunt(env, &creator, 1));
+            (before, after, err, HuntyCore::get_remaining_slots(env.clone(), open))
+        });
+
+        assert_eq!(before, Some(2));
+        assert_eq!(after, Some(0));
+        assert_eq!(err, HuntErrorCode::HuntFull);
+        assert_eq!(unlimited, None);
+    }
+
+    // ========== Registration Milestone Tests ==========
+
+    #[test]
+    fn test_registration_milestone_fires_once() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let players: std::vec::Vec<Address> = (0..4).map(|_| Address::generate(&env)).collect();
+
+        let milestones = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            let set = |thresholds: &[u32]| {
+                in_frame(env, cid, || {
+                    let thresholds = Vec::from_slice(env, thresholds);
+                    HuntyCore::set_registration_milestones(env.clone(), hid, thresholds)
+                })
+            };
+            assert_eq!(set(&[10, 0]), Err(HuntErrorCode::InvalidAmount));
+            set(&[2, 3]).unwrap();
+            assert_eq!(
+                HuntyCore::get_registration_milestones(env.clone(), hid),
+                Vec::from_array(env, [2, 3])
+            );
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            let register = |player: &Address| {
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                })
+            };
+            for player in &players[..3] {
+                register(player);
+            }
+            // Dropping back under 3 and returning to it does not fire the milestone again.
+            in_frame(env, cid, || {
+                HuntyCore::unregister_player(env.clone(), hid, players[2].clone()).unwrap()
+            });
+            register(&players[3]);
+
+            let milestones: std::vec::Vec<u32> = get_all_events_for_hunt(env, hid)
+                .into_iter()
+                .filter_map(|e| match e {
+                    HuntEvent::RegistrationMilestone(m) => Some(m.count),
+                    _ => None,
+                })
+                .collect();
+            milestones
+        });
+
+        assert_eq!(milestones, std::vec![2, 3]);
+    }
+
+    // ========== Event Level Tests ==========
+
+    #[test]
+    fn test_minimal_event_level_skips_per_clue_events() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let events = with_core_contract(&env, |env, cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            assert_eq!(HuntyCore::get_event_level(env.clone()), EventLevel::Full);
+            HuntyCore::set_event_level(env.clone(), EventLevel::Minimal).unwrap();
+            assert_eq!(HuntyCore::get_event_level(env.clone()), EventLevel::Minimal);
+
+            let hid = create_hunt_with_clues(env, &creator, 2, true);
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            register_and_complete(env, cid, hid, &player);
+            get_all_events_for_hunt(env, hid)
+        });
+
+        assert!(matches!(events[0], HuntEvent::HuntCreated(_)));
+        assert!(matches!(events[1], HuntEvent::HuntStatusChanged(_)));
+        assert_eq!(events.len(), 2);
+        // Suppressed events use no sequence numbers, so the published ones stay gapless.
+        match &events[1] {
+            HuntEvent::HuntStatusChanged(e) => assert_eq!(e.event_seq, 2),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_no_event_level_silences_lifecycle_events() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+
+        let (silent, completed) = with_core_contract(&env, |env, cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            HuntyCore::set_event_level(env.clone(), EventLevel::None).unwrap();
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            let silent = get_all_events_for_hunt(env, hid);
+
+            in_frame(env, cid, || {
+                HuntyCore::set_event_level(env.clone(), EventLevel::Full).unwrap()
+            });
+            register_and_complete(env, cid, hid, &Address::generate(env));
+            (silent, get_all_events_for_hunt(env, hid))
+        });
+
+        assert!(silent.is_empty());
+        assert!(completed.iter().any(|e| matches!(e, HuntEvent::ClueCompleted(_))));
+    }
+
+    // ========== set_progress_durability() Tests ==========
+
+    #[test]
+    fn test_progress_durability_persistent_and_temporary() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (cid, persistent, temporary) = with_core_contract(&env, |env, cid| {
+            let persistent = create_active_hunt(env, &creator, 2);
+            let temporary = in_frame(env, cid, || {
+                HuntyCore::create_hunt(
+                    env.clone(),
+                    creator.clone(),
+                    String::from_str(env, "Pop-up hunt"),
+                    String::from_str(env, "Desc"),
+                    None,
+                    Some(1_700_000_100),
+                )
+                .unwrap()
+            });
+            HuntyCore::add_clue(
+                env.clone(),
+                temporary,
+                creator.clone(),
+                String::from_str(env, "Q"),
+                String::from_str(env, "answer"),
+                10,
+                true,
+            )
+            .unwrap();
+            HuntyCore::set_progress_durability(env.clone(), temporary, Durability::Temporary)
+                .unwrap();
+            HuntyCore::activate_hunt(env.clone(), temporary).unwrap();
+            assert_eq!(
+                HuntyCore::set_progress_durability(env.clone(), temporary, Durability::Persistent),
+                Err(HuntErrorCode::InvalidHuntStatus)
+            );
+
+            for hid in [persistent, temporary] {
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                });
+                in_frame(env, cid, || {
+                    HuntyCore::submit_answer(
+                        env.clone(),
+                        hid,
+                        1,
+                        player.clone(),
+                        String::from_str(env, "answer"),
+                    )
+                    .unwrap()
+                });
+            }
+
+            let key = (soroban_sdk::symbol_short!("PROG"), temporary, player.clone());
+            assert!(env.storage().temporary().has(&key));
+            assert_eq!(
+                Storage::get_player_progress(env, temporary, &player).unwrap().total_score,
+                10
+            );
+            assert_eq!(
+                Storage::get_player_progress(env, persistent, &player).unwrap().total_score,
+                10
+            );
+            (cid.clone(), persistent, temporary)
+        });
+
+        // 100s until end_time (20 ledgers) plus the grace period, then one more ledger.
+        env.ledger().with_mut(|li| {
+            li.sequence_number += 20 + 2_880 + 1;
+            li.timestamp += 3_000 * 5;
+        });
+
+        env.as_contract(&cid, || {
+            assert!(Storage::get_player_progress(&env, temporary, &player).is_none());
+            assert!(Storage::get_player_progress(&env, persistent, &player).is_some());
+        });
+    }
+
+    // ========== create_hunt_with_clues() Tests ==========
+
+    fn clue_input(env: &Env, question: &str, answer: &str, required: bool) -> ClueInput {
+        ClueInput {
+            question: String::from_str(env, question),
+            answer: String::from_str(env, answer),
+            points: 10,
+            is_required: required,
+        }
+    }
+
+    #[test]
+    fn test_create_hunt_with_clues_in_one_call() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (hunt, clue_ids, first) = with_core_contract(&env, |env, _| {
+            let clues = soroban_sdk::vec![
+                env,
+                clue_input(env, "Q1", "one", true),
+                clue_input(env, "Q2", "two", false),
+                clue_input(env, "Q3", "three", true),
+            ];
+            let hid = HuntyCore::create_hunt_with_clues(
+                env.clone(),
+                creator.clone(),
+                String::from_str(env, "Scripted"),
+                String::from_str(env, "Desc"),
+                Some(1_700_100_000),
+                clues,
+            )
+            .unwrap();
+            (
+                Storage::get_hunt(env, hid).unwrap(),
+                HuntyCore::get_clue_ids(env.clone(), hid),
+                HuntyCore::get_clue(env.clone(), hid, 1).unwrap(),
+            )
+        });
+
+        assert_eq!(hunt.status, HuntStatus::Draft);
+        assert_eq!(hunt.end_time, 1_700_100_000);
+        assert_eq!(hunt.total_clues, 3);
+        assert_eq!(hunt.required_clues, 2);
+        assert_eq!(clue_ids, soroban_sdk::vec![&env, 1, 2, 3]);
+        assert_eq!(first.question, String::from_str(&env, "Q1"));
+    }
+
+    #[test]
+    fn test_create_hunt_with_clues_rejects_invalid_clue() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (result, hunts, by_title) = with_core_contract(&env, |env, _| {
+            let clues = soroban_sdk::vec![
+                env,
+                clue_input(env, "Q1", "one", true),
+                clue_input(env, "Q2", "   ", true),
+            ];
+            let title = String::from_str(env, "Scripted");
+            let result = HuntyCore::create_hunt_with_clues(
+                env.clone(),
+                creator.clone(),
+                title.clone(),
+                String::from_str(env, "Desc"),
+                None,
+                clues,
+            );
+            (
+                result,
+                HuntyCore::get_total_hunts(env.clone()),
+                HuntyCore::get_hunt_id_by_title(env.clone(), creator.clone(), title),
+            )
+        });
+
+        assert_eq!(result, Err(HuntErrorCode::InvalidAnswer));
+        assert_eq!(hunts, 0);
+        assert_eq!(by_title, None);
+    }
+
+    // ========== Clue Template Tests ==========
+
+    #[test]
+    fn test_create_two_hunts_from_saved_template() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_700_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        with_core_contract(&env, |env, cid| {
+            let name = String::from_str(env, "city-walk");
+            let clues = soroban_sdk::vec![
+                env,
+                clue_input(env, "Q1", "one", true),
+                clue_input(env, "Q2", "two", false),
+            ];
+            HuntyCore::save_template(env.clone(), creator.clone(), name.clone(), clues).unwrap();
+            assert_eq!(
+                HuntyCore::list_templates(env.clone(), creator.clone()),
+                soroban_sdk::vec![env, name.clone()]
+            );
+            assert_eq!(HuntyCore::list_templates(env.clone(), other.clone()).len(), 0);
+
+            let from_template = |title: &str| {
+                in_frame(env, cid, || {
+                    HuntyCore::create_hunt_from_template(
+                        env.clone(),
+                        creator.clone(),
+                        String::from_str(env, title),
+                        String::from_str(env, "Desc"),
+                        name.clone(),
+                    )
+                })
+            };
+            let first = from_template("Monday").unwrap();
+            let second = from_template("Tuesday").unwrap();
+            assert_ne!(first, second);
+            for hid in [first, second] {
+                let hunt = Storage::get_hunt(env, hid).unwrap();
+                assert_eq!((hunt.total_clues, hunt.required_clues), (2, 1));
+                assert_eq!(
+                    HuntyCore::get_clue(env.clone(), hid, 2).unwrap().question,
+                    String::from_str(env, "Q2")
+                );
+            }
+            let first_hash = Storage::get_clue(env, first, 1).unwrap().answer_hash;
+            assert_eq!(first_hash, Storage::get_clue(env, second, 1).unwrap().answer_hash);
+
+            // Templates belong to their owner.
+            let missing = in_frame(env, cid, || {
+                HuntyCore::create_hunt_from_template(
+                    env.clone(),
+                    other.clone(),
+                    String::from_str(env, "Stolen"),
+                    String::from_str(env, "Desc"),
+                    name.clone(),
+                )
+            });
+            assert_eq!(missing, Err(HuntErrorCode::TemplateNotFound));
+        });
+    }
+
+    // ========== Creator Authorization Tests ==========
+
+    /// Asserts `call` fails with no authorizations and succeeds once auths are mocked.
+    fn assert_requires_auth<T, E>(env: &Env, name: &str, call: impl Fn() -> Result<T, E>) {
+        env.set_auths(&[]);
+        assert!(call().is_err(), "{} succeeded without authorization", name);
+        env.mock_all_auths();
+        assert!(call().is_ok(), "{} failed for the authorized caller", name);
+    }
+
+    #[test]
+    fn test_creator_actions_require_creator_auth() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 1_000);
+        let cid = env.register(HuntyCore, ());
+        let client = crate::HuntyCoreClient::new(&env, &cid);
+        let title = String::from_str(&env, "Hunt");
+        let description = String::from_str(&env, "Desc");
+        let question = String::from_str(&env, "Q");
+        let answer = String::from_str(&env, "answer");
+
+        assert_requires_auth(&env, "create_hunt", || {
+            client.try_create_hunt(&creator, &title, &description, &None, &None)
+        });
+        let hid = 1;
+        assert_requires_auth(&env, "add_clue", || {
+            client.try_add_clue(&hid, &creator, &question, &answer, &10, &true)
+        });
+        client.add_clue(&hid, &creator, &question, &answer, &10, &true);
+        assert_requires_auth(&env, "reorder_clues", || {
+            client.try_reorder_clues(&hid, &creator, &soroban_sdk::vec![&env, 2, 1])
+        });
+        assert_requires_auth(&env, "set_clue_time_bonus", || {
+            client.try_set_clue_time_bonus(&hid, &creator, &1, &60, &5)
+        });
+        assert_requires_auth(&env, "set_completion_policy", || {
+            client.try_set_completion_policy(&hid, &CompletionPolicy::RequiredPercent(50))
+        });
+        assert_requires_auth(&env, "set_ordered", || client.try_set_ordered(&hid, &true));
+        assert_requires_auth(&env, "set_max_winners", || client.try_set_max_winners(&hid, &2));
+        assert_requires_auth(&env, "set_progress_durability", || {
+            client.try_set_progress_durability(&hid, &Durability::Persistent)
+        });
+        assert_requires_auth(&env, "set_end_time", || client.try_set_end_time(&hid, &0));
+        assert_requires_auth(&env, "set_max_players", || client.try_set_max_players(&hid, &10));
+        assert_requires_auth(&env, "set_reward_mode", || {
+            client.try_set_reward_mode(&hid, &RewardMode::FirstCome)
+        });
+        assert_requires_auth(&env, "set_entry_fee", || client.try_set_entry_fee(&hid, &token, &0));
+        let collaborator = Address::generate(&env);
+        assert_requires_auth(&env, "add_collaborator", || {
+            client.try_add_collaborator(&hid, &collaborator)
+        });
+        assert_requires_auth(&env, "remove_collaborator", || {
+            client.try_remove_collaborator(&hid, &collaborator)
+        });
+        assert_requires_auth(&env, "fund_reward_pool", || {
+            client.try_fund_reward_pool(&hid, &token, &1_000)
+        });
+        assert_requires_auth(&env, "activate_hunt", || client.try_activate_hunt(&hid));
+        let reason = String::from_str(&env, "");
+        assert_requires_auth(&env, "cancel_hunt", || client.try_cancel_hunt(&hid, &reason));
+    }
+
+    // ========== Collaborator Tests ==========
+
+    #[test]
+    fn test_collaborator_can_edit_clues() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let collaborator = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            let add = |editor: &Address| {
+                in_frame(env, cid, || {
+                    HuntyCore::add_clue(
+                        env.clone(),
+                        hid,
+                        editor.clone(),
+                        String::from_str(env, "Q"),
+                        String::from_str(env, "answer"),
+                        5,
+                        false,
+                    )
+                })
+            };
+            assert_eq!(add(&collaborator), Err(HuntErrorCode::Unauthorized));
+
+            HuntyCore::add_collaborator(env.clone(), hid, collaborator.clone()).unwrap();
+            assert!(HuntyCore::is_collaborator(env.clone(), hid, collaborator.clone()));
+            assert!(!HuntyCore::is_collaborator(env.clone(), hid, stranger.clone()));
+            assert_eq!(add(&collaborator), Ok(2));
+            assert_eq!(add(&stranger), Err(HuntErrorCode::Unauthorized));
+            in_frame(env, cid, || {
+                let order = soroban_sdk::vec![env, 2, 1];
+                HuntyCore::reorder_clues(env.clone(), hid, collaborator.clone(), order).unwrap();
+            });
+            in_frame(env, cid, || {
+                HuntyCore::set_clue_time_bonus(env.clone(), hid, collaborator.clone(), 2, 30, 3)
+                    .unwrap();
+            });
+            assert_eq!(HuntyCore::get_clue_ids(env.clone(), hid), soroban_sdk::vec![env, 2, 1]);
+
+            in_frame(env, cid, || {
+                HuntyCore::remove_collaborator(env.clone(), hid, collaborator.clone()).unwrap();
+            });
+            assert!(!HuntyCore::is_collaborator(env.clone(), hid, collaborator.clone()));
+            assert_eq!(add(&collaborator), Err(HuntErrorCode::Unauthorized));
+        });
+    }
+
+    #[test]
+    fn test_collaborator_cannot_cancel_hunt() {
+        use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
+        use soroban_sdk::IntoVal;
+
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+        let collaborator = Address::generate(&env);
+
+        let (cid, hid) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::add_collaborator(env.clone(), hid, collaborator.clone()).unwrap();
+            (cid.clone(), hid)
+        });
+
+        // Only the collaborator signs: cancel needs the creator's authorization.
+        env.mock_auths(&[MockAuth {
+            address: &collaborator,
+            invoke: &MockAuthInvoke {
+                contract: &cid,
+                fn_name: "cancel_hunt",
+                args: (hid, String::from_str(&env, "")).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        let client = crate::HuntyCoreClient::new(&env, &cid);
+        assert!(client.try_cancel_hunt(&hid, &String::from_str(&env, "")).is_err());
+        let status = env.as_contract(&cid, || Storage::get_hunt(&env, hid).unwrap().status);
+        assert_eq!(status, HuntStatus::Draft);
+    }
+
+    // ========== Tie Policy Tests ==========
+
+    /// Funds a two-winner hunt paying 450 per winner with `policy`, then has `first`
+    /// complete at t=100 and `tied` all complete together at t=200, so they tie for the
+    /// last slot.
+    fn create_tied_hunt(
+        env: &Env,
+        cid: &Address,
+        creator: &Address,
+        token: &Address,
+        policy: TiePolicy,
+        first: &Address,
+        tied: &[Address],
+    ) -> u64 {
+        let hid = create_hunt_with_clues(env, creator, 1, true);
+        HuntyCore::set_max_winners(env.clone(), hid, 2).unwrap();
+        HuntyCore::set_tie_policy(env.clone(), hid, policy).unwrap();
+        HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), 900).unwrap();
+        HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+        env.ledger().set_timestamp(100);
+        register_and_complete(env, cid, hid, first);
+        env.ledger().set_timestamp(200);
+        for player in tied {
+            register_and_complete(env, cid, hid, player);
+        }
+        hid
+    }
+
+    fn try_claim(
+        env: &Env,
+        cid: &Address,
+        hunt_id: u64,
+        player: &Address,
+    ) -> Result<i128, HuntErrorCode> {
+        in_frame(env, cid, || HuntyCore::claim_reward(env.clone(), hunt_id, player.clone(), None))
+    }
+
+    #[test]
+    fn test_tie_policy_first_come_rewards_earliest_completion() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let first = Address::generate(&env);
+        let tied = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        let token = create_funded_token(&env, &creator, 900);
+
+        let claims = with_core_contract(&env, |env, cid| {
+            let hid =
+                create_tied_hunt(env, cid, &creator, &token, TiePolicy::FirstCome, &first, &tied);
+            env.ledger().set_timestamp(300);
+            let mut claims = std::vec![try_claim(env, cid, hid, &first)];
+            for player in &tied {
+                claims.push(try_claim(env, cid, hid, player));
+            }
+            claims
+        });
+
+        assert_eq!(
+            claims,
+            std::vec![
+                Ok(450),
+                Ok(450),
+                Err(HuntErrorCode::InsufficientRewardPool),
+                Err(HuntErrorCode::InsufficientRewardPool),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tie_policy_split_equally_shares_last_slot() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let first = Address::generate(&env);
+        let tied = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        let token = create_funded_token(&env, &creator, 900);
+
+        let (early, claims, rewards, escrow) = with_core_contract(&env, |env, cid| {
+            let policy = TiePolicy::SplitEqually;
+            let hid = create_tied_hunt(env, cid, &creator, &token, policy, &first, &tied);
+            // Someone else could still tie in the cutoff ledger.
+            let early = try_claim(env, cid, hid, &tied[0]);
+            env.ledger().set_timestamp(201);
+            let mut claims = std::vec![try_claim(env, cid, hid, &first)];
+            for player in &tied {
+                claims.push(try_claim(env, cid, hid, player));
+            }
+            let rewards = Storage::get_hunt(env, hid).unwrap().reward_config;
+            (early, claims, rewards, Storage::get_escrow_balance(env, hid))
+        });
+
+        assert_eq!(early, Err(HuntErrorCode::TieUnresolved));
+        assert_eq!(claims, std::vec![Ok(450), Ok(150), Ok(150), Ok(150)]);
+        assert_eq!((rewards.tie_sharers, rewards.claimed_count), (3, 2));
+        assert_eq!(escrow, 0);
+        assert_eq!(TokenClient::new(&env, &token).balance(&tied[2]), 150);
+    }
+
+    #[test]
+    fn test_tie_policy_expand_slots_waits_for_admin() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let first = Address::generate(&env);
+        let tied = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        let token = create_funded_token(&env, &creator, 900);
+        StellarAssetClient::new(&env, &token).mint(&admin, &900);
+
+        let (pending, before, added, claims, max_winners) = with_core_contract(&env, |env, cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            let hid =
+                create_tied_hunt(env, cid, &creator, &token, TiePolicy::ExpandSlots, &first, &tied);
+            let pending = HuntyCore::get_pending_ties(env.clone(), hid);
+            let before = try_claim(env, cid, hid, &tied[1]);
+            let added =
+                in_frame(env, cid, || HuntyCore::approve_tie_slots(env.clone(), hid).unwrap());
+            let mut claims = std::vec![try_claim(env, cid, hid, &first)];
+            for player in &tied {
+                claims.push(try_claim(env, cid, hid, player));
+            }
+            let max_winners = Storage::get_hunt(env, hid).unwrap().reward_config.max_winners;
+            (pending, before, added, claims, max_winners)
+        });
+
+        assert_eq!(pending, Vec::from_array(&env, [tied[1].clone(), tied[2].clone()]));
+        assert_eq!(before, Err(HuntErrorCode::InsufficientRewardPool));
+        assert_eq!(added, 2);
+        assert_eq!(claims, std::vec![Ok(450), Ok(450), Ok(450), Ok(450)]);
+        assert_eq!(max_winners, 4);
+        assert_eq!(TokenClient::new(&env, &token).balance(&admin), 0);
+    }
+
+    // ========== Registration Pause Tests ==========
+
+    #[test]
+    fn test_pause_registration_keeps_existing_players_playing() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let late = Address::generate(&env);
+
+        let (open, rejected, points, reopened, resumed) = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 2);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            in_frame(env, cid, || HuntyCore::pause_registration(env.clone(), hid).unwrap());
+            let open = HuntyCore::is_registration_open(env.clone(), hid);
+            let rejected = in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, late.clone())
+            });
+            let clue_id = HuntyCore::list_clues(env.clone(), hid).get(0).unwrap().clue_id;
+            let points = in_frame(env, cid, || {
+                HuntyCore::submit_answer(
+                    env.clone(),
+                    hid,
+                    clue_id,
+                    player.clone(),
+                    String::from_str(env, "answer"),
+                )
+                .unwrap()
+            });
+            in_frame(env, cid, || HuntyCore::resume_registration(env.clone(), hid).unwrap());
+            let reopened = HuntyCore::is_registration_open(env.clone(), hid);
+            let resumed = in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, late.clone())
+            });
+            let paused: std::vec::Vec<bool> = get_all_events_for_hunt(env, hid)
+                .into_iter()
+                .filter_map(|event| match event {
+                    HuntEvent::RegistrationPaused(e) => Some(e.paused),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(paused, std::vec![true, false]);
+            (open, rejected, points, reopened, resumed)
+        });
+
+        assert!(!open);
+        assert_eq!(rejected, Err(HuntErrorCode::RegistrationClosed));
+        assert_eq!(points, 10);
+        assert!(reopened);
+        assert_eq!(resumed, Ok(()));
+    }
+
+    #[test]
+    fn test_is_registration_open_false_for_draft_or_missing_hunt() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (draft, missing) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            (
+                HuntyCore::is_registration_open(env.clone(), hid),
+                HuntyCore::is_registration_open(env.clone(), 99),
+            )
+        });
+
+        assert!(!draft);
+        assert!(!missing);
+    }
+
+    // ========== Minimum Reward Tests ==========
+
+    #[test]
+    fn test_fund_reward_pool_rejects_pool_below_one_unit_per_winner() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 1_000);
+
+        let (too_small, exact, per_winner, too_many) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_max_winners(env.clone(), hid, 3).unwrap();
+            let too_small = in_frame(env, cid, || {
+                HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), 2)
+            });
+            let exact = in_frame(env, cid, || {
+                HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), 3)
+            });
+            let per_winner = Storage::get_hunt(env, hid).unwrap().reward_config.reward_per_winner();
+            // Raising the winner count past the pool is refused too.
+            let too_many = in_frame(env, cid, || {
+                HuntyCore::set_max_winners(env.clone(), hid, 4)
+            });
+            (too_small, exact, per_winner, too_many)
+        });
+
+        assert_eq!(too_small, Err(HuntErrorCode::RewardTooSmall));
+        assert_eq!(exact, Ok(3));
+        assert_eq!(per_winner, 1);
+        assert_eq!(too_many, Err(HuntErrorCode::RewardTooSmall));
+        assert_eq!(TokenClient::new(&env, &token).balance(&creator), 997);
+    }
+
+    #[test]
+    fn test_withdraw_reward_pool_rejects_leaving_less_than_one_unit_per_winner() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 10);
+
+        let (partial, full, pool) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_max_winners(env.clone(), hid, 5).unwrap();
+            HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), 10).unwrap();
+            // 4 left for 5 winners would pay each of them nothing.
+            let partial = HuntyCore::withdraw_reward_pool(env.clone(), hid, 6);
+            let full = in_frame(env, cid, || HuntyCore::withdraw_reward_pool(env.clone(), hid, 10));
+            (partial, full, Storage::get_hunt(env, hid).unwrap().reward_config.xlm_pool)
+        });
+
+        assert_eq!(partial, Err(HuntErrorCode::RewardTooSmall));
+        assert_eq!(full, Ok(0));
+        assert_eq!(pool, 0);
+        assert_eq!(TokenClient::new(&env, &token).balance(&creator), 10);
+    }
+
+    // ========== list_clues_filtered() Tests ==========
+
+    #[test]
+    fn test_list_clues_filtered_splits_required_and_optional() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (required, optional, second_page) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 0, true);
+            for required in [true, false, true, false, false] {
+                HuntyCore::add_clue(
+                    env.clone(),
+                    hid,
+                    creator.clone(),
+                    String::from_str(env, "Q"),
+                    String::from_str(env, "answer"),
+                    10,
+                    required,
+                )
+                .unwrap();
+            }
+            let ids = |clues: Vec<ClueInfo>| {
+                clues.iter().map(|clue| clue.clue_id).collect::<std::vec::Vec<_>>()
+            };
+            (
+                ids(HuntyCore::list_clues_filtered(env.clone(), hid, true, 0, 10)),
+                ids(HuntyCore::list_clues_filtered(env.clone(), hid, false, 0, 10)),
+                ids(HuntyCore::list_clues_filtered(env.clone(), hid, false, 1, 2)),
+            )
+        });
+
+        assert_eq!(required, std::vec![1, 3]);
+        assert_eq!(optional, std::vec![2, 4, 5]);
+        assert_eq!(second_page, std::vec![4, 5]);
+    }
+
+    // ========== sweep_expired_hunts() Tests ==========
+
+    #[test]
+    fn test_sweep_expired_hunts_closes_only_expired_hunts() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        let (swept, again, active, completed, status) = with_core_contract(&env, |env, cid| {
+            // (end_time, grace_period_secs); 0 means the hunt never ends.
+            let mut hunts = std::vec::Vec::new();
+            for (end_time, grace) in [(100, 0), (100, 50), (0, 0), (500, 0), (100, 200)] {
+                hunts.push(in_frame(env, cid, || {
+                    let hid = create_hunt_with_clues(env, &creator, 1, true);
+                    HuntyCore::set_end_time(env.clone(), hid, end_time).unwrap();
+                    HuntyCore::set_grace_period(env.clone(), hid, grace).unwrap();
+                    HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+                    hid
+                }));
+            }
+            env.ledger().set_timestamp(200);
+            let swept = HuntyCore::sweep_expired_hunts(env.clone(), 0, 10);
+            let again = HuntyCore::sweep_expired_hunts(env.clone(), 0, 10);
+            (
+                swept,
+                again,
+                HuntyCore::get_hunts_by_status(env.clone(), HuntStatus::Active, 0, 10),
+                HuntyCore::get_hunts_by_status(env.clone(), HuntStatus::Completed, 0, 10),
+                Storage::get_hunt(env, hunts[0]).unwrap().status,
+            )
+        });
+
+        assert_eq!(swept, 2);
+        assert_eq!(again, 0);
+        assert_eq!(active, Vec::from_array(&env, [3, 4, 5]));
+        assert_eq!(completed, Vec::from_array(&env, [1, 2]));
+        assert_eq!(status, HuntStatus::Completed);
+    }
+
+    #[test]
+    fn test_sweep_expired_hunts_only_scans_the_window() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        let (swept, active) = with_core_contract(&env, |env, cid| {
+            for _ in 0..3 {
+                in_frame(env, cid, || {
+                    let hid = create_hunt_with_clues(env, &creator, 1, true);
+                    HuntyCore::set_end_time(env.clone(), hid, 100).unwrap();
+                    HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+                });
+            }
+            env.ledger().set_timestamp(100);
+            (
+                HuntyCore::sweep_expired_hunts(env.clone(), 1, 1),
+                HuntyCore::get_hunts_by_status(env.clone(), HuntStatus::Active, 0, 10),
+            )
+        });
+
+        assert_eq!(swept, 1);
+        assert_eq!(active, Vec::from_array(&env, [1, 3]));
+    }
+
+    // ========== get_clue_set_commitment() Tests ==========
+
+    #[test]
+    fn test_clue_set_commitment_is_stable_and_tracks_clues() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        let (draft, first, reread, expected, reactivated) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 2, true);
+            let draft = HuntyCore::get_clue_set_commitment(env.clone(), hid);
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            let first = HuntyCore::get_clue_set_commitment(env.clone(), hid).unwrap();
+            let reread = HuntyCore::get_clue_set_commitment(env.clone(), hid).unwrap();
+
+            let mut preimage = Bytes::new(env);
+            for clue in Storage::list_clues_for_hunt(env, hid).iter() {
+                preimage.extend_from_array(&clue.clue_id.to_be_bytes());
+                preimage.extend_from_array(&clue.answer_hash.to_array());
+            }
+            let expected: BytesN<32> = env.crypto().sha256(&preimage).to_bytes();
+
+            // Swapping the clue set in Draft changes the commitment on reactivation.
+            in_frame(env, cid, || {
+                HuntyCore::deactivate_hunt(env.clone(), hid).unwrap();
+                HuntyCore::add_clue(
+                    env.clone(),
+                    hid,
+                    creator.clone(),
+                    String::from_str(env, "Q"),
+                    String::from_str(env, "other"),
+                    10,
+                    true,
+                )
+                .unwrap();
+                HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            });
+            let reactivated = HuntyCore::get_clue_set_commitment(env.clone(), hid).unwrap();
+            (draft, first, reread, expected, reactivated)
+        });
+
+        assert_eq!(draft, None);
+        assert_eq!(first, reread);
+        assert_eq!(first, expected);
+        assert_ne!(reactivated, first);
+    }
+
+    // ========== get_hunt_config() Tests ==========
+
+    #[test]
+    fn test_get_hunt_config_reports_every_flag() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+
+        let (config, missing) = with_core_contract(&env, |env, cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            let hid = create_hunt_with_clues(env, &creator, 2, true);
+            in_frame(env, cid, || {
+                HuntyCore::set_ordered(env.clone(), hid, true).unwrap();
+                HuntyCore::set_manual_judging(env.clone(), hid, true).unwrap();
+                HuntyCore::set_shuffle_clues(env.clone(), hid, true).unwrap();
+                HuntyCore::set_reward_mode(env.clone(), hid, RewardMode::Raffle).unwrap();
+                HuntyCore::set_tie_policy(env.clone(), hid, TiePolicy::SplitEqually).unwrap();
+                HuntyCore::pause_registration(env.clone(), hid).unwrap();
+            });
+            HuntyCore::freeze_hunt_rewards(env.clone(), hid).unwrap();
+            (
+                HuntyCore::get_hunt_config(env.clone(), hid).unwrap(),
+                HuntyCore::get_hunt_config(env.clone(), 99),
+            )
+        });
+
+        assert!(config.ordered);
+        assert!(config.manual_judging);
+        assert!(config.shuffle_clues);
+        assert!(config.registration_paused);
+        assert!(config.rewards_frozen);
+        assert!(!config.allow_creator_play);
+        assert!(!config.nft_enabled);
+        assert_eq!(config.reward_mode, RewardMode::Raffle);
+        assert_eq!(config.tie_policy, TiePolicy::SplitEqually);
+        assert_eq!(config.completion_policy, CompletionPolicy::RequiredCount);
+        assert_eq!(config.progress_durability, Durability::Persistent);
+        assert_eq!(config.answer_algo, HashAlgo::Sha256);
+        assert_eq!(missing, None);
+    }
+
+    // ========== claim_reward_for() Tests ==========
+
+    #[test]
+    fn test_claim_reward_for_requires_approved_helper() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let helper = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        let players = [Address::generate(&env), Address::generate(&env)];
+        let token = create_funded_token(&env, &creator, 900);
+
+        let (helped, rejected, by_creator, revoked) = with_core_contract(&env, |env, cid| {
+            let hid = create_funded_hunt(env, &creator, &token, 900, 2);
+            for player in &players {
+                register_and_complete(env, cid, hid, player);
+            }
+            in_frame(env, cid, || {
+                HuntyCore::add_claim_helper(env.clone(), hid, helper.clone()).unwrap()
+            });
+            let helped = in_frame(env, cid, || {
+                HuntyCore::claim_reward_for(env.clone(), hid, players[0].clone(), helper.clone())
+            });
+            let rejected = in_frame(env, cid, || {
+                HuntyCore::claim_reward_for(env.clone(), hid, players[1].clone(), stranger.clone())
+            });
+            in_frame(env, cid, || {
+                HuntyCore::remove_claim_helper(env.clone(), hid, helper.clone()).unwrap()
+            });
+            let revoked = HuntyCore::is_claim_helper(env.clone(), hid, helper.clone());
+            let by_creator = in_frame(env, cid, || {
+                HuntyCore::claim_reward_for(env.clone(), hid, players[1].clone(), creator.clone())
+            });
+            (helped, rejected, by_creator, revoked)
+        });
+
+        assert_eq!(helped, Ok(450));
+        assert_eq!(rejected, Err(HuntErrorCode::Unauthorized));
+        assert_eq!(by_creator, Ok(450));
+        assert!(!revoked);
+        let balance = |addr: &Address| TokenClient::new(&env, &token).balance(addr);
+        assert_eq!((balance(&players[0]), balance(&players[1])), (450, 450));
+        assert_eq!(balance(&helper), 0);
+    }
+
+    // ========== purge_cancelled_hunt() Tests ==========
+
+    #[test]
+    fn test_purge_cancelled_hunt_in_chunks() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let players = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+
+        let (first, left, second, purged, progress, history) = with_core_contract(&env, |env, cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            let hid = create_active_hunt(env, &creator, 3);
+            for player in &players {
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                });
+            }
+            in_frame(env, cid, || {
+                HuntyCore::cancel_hunt(env.clone(), hid, String::from_str(env, "")).unwrap()
+            });
+
+            let first = in_frame(env, cid, || {
+                HuntyCore::purge_cancelled_hunt(env.clone(), hid, creator.clone(), 2, 2).unwrap()
+            });
+            let left = (
+                Storage::get_clue_ids_for_hunt(env, hid).len(),
+                Storage::get_player_count(env, hid),
+            );
+            let second = in_frame(env, cid, || {
+                HuntyCore::purge_cancelled_hunt(env.clone(), hid, admin.clone(), 2, 2).unwrap()
+            });
+            (
+                first,
+                left,
+                second,
+                HuntyCore::is_hunt_purged(env.clone(), hid),
+                Storage::get_player_progress(env, hid, &players[0]).is_none(),
+                Storage::get_player_hunt_ids(env, &players[2]).len(),
+            )
+        });
+
+        assert!(!first);
+        assert_eq!(left, (1, 1));
+        assert!(second);
+        assert!(purged);
+        assert!(progress);
+        assert_eq!(history, 0);
+    }
+
+    #[test]
+    fn test_purge_removes_per_player_clue_data() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let leftovers = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 2);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            let commitment = AnswerCommitment {
+                commitment: BytesN::from_array(env, &[1; 32]),
+                committed_ledger: 0,
+            };
+            Storage::save_commitment(env, hid, 1, &player, &commitment);
+            let attempts = AttemptState { attempts: 2, ..AttemptState::new() };
+            Storage::save_attempt_state(env, hid, 2, &player, &attempts);
+            Storage::set_clue_viewed_at(env, hid, 2, &player, 5);
+            Storage::set_nonce(env, hid, &player, 3);
+            in_frame(env, cid, || {
+                HuntyCore::cancel_hunt(env.clone(), hid, String::from_str(env, "")).unwrap()
+            });
+
+            let purged = in_frame(env, cid, || {
+                HuntyCore::purge_cancelled_hunt(env.clone(), hid, creator.clone(), 10, 10)
+            });
+            assert_eq!(purged, Ok(true));
+            (
+                Storage::get_commitment(env, hid, 1, &player).is_some(),
+                Storage::get_attempt_state(env, hid, 2, &player).attempts,
+                Storage::get_clue_viewed_at(env, hid, 2, &player).is_some(),
+                Storage::get_nonce(env, hid, &player),
+            )
+        });
+
+        assert_eq!(leftovers, (false, 0, false, 0));
+    }
+
+    #[test]
+    fn test_purge_requires_cancelled_hunt_and_authorized_caller() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let stranger = Address::generate(&env);
+
+        let (active, unauthorized) = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 1);
+            let active = in_frame(env, cid, || {
+                HuntyCore::purge_cancelled_hunt(env.clone(), hid, creator.clone(), 10, 10)
+            });
+            in_frame(env, cid, || {
+                HuntyCore::cancel_hunt(env.clone(), hid, String::from_str(env, "")).unwrap()
+            });
+            let unauthorized = in_frame(env, cid, || {
+                HuntyCore::purge_cancelled_hunt(env.clone(), hid, stranger.clone(), 10, 10)
+            });
+            (active, unauthorized)
+        });
+
+        assert_eq!(active, Err(HuntErrorCode::InvalidHuntStatus));
+        assert_eq!(unauthorized, Err(HuntErrorCode::Unauthorized));
+    }
+
+    // ========== Point Cap Tests ==========
+
+    #[test]
+    fn test_max_points_per_clue_caps_new_clues() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        let (within, over, uncapped, cap) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 0, true);
+            let add = |points: u32| {
+                in_frame(env, cid, || {
+                    HuntyCore::add_clue(
+                        env.clone(),
+                        hid,
+                        creator.clone(),
+                        String::from_str(env, "Q"),
+                        String::from_str(env, "answer"),
+                        points,
+                        true,
+                    )
+                })
+            };
+            in_frame(env, cid, || {
+                HuntyCore::set_max_points_per_clue(env.clone(), hid, 50).unwrap()
+            });
+            let within = add(50);
+            let over = add(51);
+            let cap = HuntyCore::get_hunt_config(env.clone(), hid).unwrap().max_points_per_clue;
+            in_frame(env, cid, || {
+                HuntyCore::set_max_points_per_clue(env.clone(), hid, 0).unwrap()
+            });
+            (within, over, add(1_000), cap)
+        });
+
+        assert_eq!(within, Ok(1));
+        assert_eq!(over, Err(HuntErrorCode::PointsExceedCap));
+        assert_eq!(uncapped, Ok(2));
+        assert_eq!(cap, 50);
+    }
+
+    // ========== PlayerProgress Upgrade Tests ==========
+
+    #[test]
+    fn test_old_progress_layouts_are_upgraded_on_read() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let (v1_player, v2_player) = (Address::generate(&env), Address::generate(&env));
+
+        let (v1, v2, activity, kept_after_read, resaved_after_solve) =
+            with_core_contract(&env, |env, _| {
+                let hid = create_active_hunt(env, &creator, 2);
+                let completed_clues = Vec::from_array(env, [1u32, 2]);
+                let old_v1 = PlayerProgressV1 {
+                    player: v1_player.clone(),
+                    hunt_id: hid,
+                    completed_clues: completed_clues.clone(),
+                    total_score: 20,
+                    started_at: 10,
+                    completed_at: 40,
+                    is_completed: true,
+                    reward_claimed: false,
+                    winner_slot: true,
+                    disqualified: false,
+                };
+                let old_v2 = PlayerProgressV2 {
+                    player: v2_player.clone(),
+                    hunt_id: hid,
+                    completed_clues: Vec::from_array(env, [1u32]),
+                    total_score: 10,
+                    started_at: 10,
+                    completed_at: 0,
+                    is_completed: false,
+                    reward_claimed: false,
+                    winner_slot: false,
+                    disqualified: false,
+                    last_activity_at: 25,
+                };
+                let storage = env.storage().persistent();
+                let v2_key = Storage::progress_key(hid, &v2_player);
+                storage.set(&Storage::progress_key(hid, &v1_player), &old_v1);
+                storage.set(&v2_key, &old_v2);
+
+                let v1 = Storage::get_player_progress(env, hid, &v1_player).unwrap();
+                let v2 = Storage::get_player_progress(env, hid, &v2_player).unwrap();
+                let activity =
+                    HuntyCore::get_player_last_activity(env.clone(), hid, v1_player.clone());
+                // Reading leaves the old entry as it was...
+                let kept: Option<PlayerProgressV2> = storage.get(&v2_key);
+                // ...and the next save stores it in the current layout.
+                let answer = String::from_str(env, "answer");
+                HuntyCore::submit_answer(env.clone(), hid, 2, v2_player.clone(), answer).unwrap();
+                let resaved: Option<PlayerProgress> = storage.get(&v2_key);
+                (v1, v2, activity, kept.is_some(), resaved.is_some())
+            });
+
+        assert_eq!(v1.completed_clues.len(), 2);
+        assert_eq!((v1.total_score, v1.is_completed, v1.winner_slot), (20, true, true));
+        assert_eq!(v1.last_activity_at, 40);
+        assert!(!v1.shared_slot);
+        assert_eq!(activity, Some(40));
+        assert_eq!((v2.total_score, v2.last_activity_at), (10, 25));
+        assert!(v2.score_breakdown.is_empty());
+        assert!(kept_after_read);
+        assert!(resaved_after_solve);
+    }
+
+    #[test]
+    fn test_baseline_progress_layout_is_readable() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+        let (finisher, starter) = (Address::generate(&env), Address::generate(&env));
+
+        let (done, started, batch) = with_core_contract(&env, |env, _| {
+            let hid = create_active_hunt(env, &creator, 2);
+            // The 8-field layout of the first contract release.
+            let baseline = |player: &Address, clues: &[u32], completed_at: u64| PlayerProgressV0 {
+                player: player.clone(),
+                hunt_id: hid,
+                completed_clues: Vec::from_slice(env, clues),
+                total_score: 10 * clues.len() as u32,
+                started_at: 10,
+                completed_at,
+                is_completed: completed_at > 0,
+                reward_claimed: false,
+            };
+            let storage = env.storage().persistent();
+            storage.set(&Storage::progress_key(hid, &finisher), &baseline(&finisher, &[1, 2], 40));
+            storage.set(&Storage::progress_key(hid, &starter), &baseline(&starter, &[1], 0));
+
+            let batch = HuntyCore::get_progress_batch(
+                env.clone(),
+                hid,
+                soroban_sdk::vec![env, finisher.clone(), starter.clone()],
+            );
+            (
+                Storage::get_player_progress(env, hid, &finisher).unwrap(),
+                Storage::get_player_progress(env, hid, &starter).unwrap(),
+                batch,
+            )
+        });
+
+        assert_eq!((done.total_score, done.is_completed, done.winner_slot), (20, true, true));
+        assert_eq!((done.last_activity_at, done.disqualified), (40, false));
+        assert_eq!((started.total_score, started.winner_slot), (10, false));
+        assert_eq!(started.last_activity_at, 10);
+        assert_eq!(batch.len(), 2);
+        assert!(batch.iter().all(|progress| progress.is_some()));
+    }
+
+    // ========== Leaderboard Cache Tests ==========
+
+    /// Registers `players` on `hid` and has the i-th solve `solves[i]` clues in order.
+    fn solve_clues(env: &Env, cid: &Address, hid: u64, players: &[Address], solves: &[u32]) {
+        for (player, count) in players.iter().zip(solves) {
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            for clue_id in 1..=*count {
+                in_frame(env, cid, || {
+                    let answer = String::from_str(env, "answer");
+                    HuntyCore::submit_answer(env.clone(), hid, clue_id, player.clone(), answer)
+                        .unwrap()
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn test_cached_leaderboard_matches_fresh_scan() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let players = [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+
+        let (cached, fresh, stored, too_big) = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 5);
+            HuntyCore::set_leaderboard_cache_size(env.clone(), hid, 3).unwrap();
+            solve_clues(env, cid, hid, &players, &[1, 4, 0, 2]);
+            // Two more solves move a player up past another inside the cache.
+            for clue_id in [2, 3] {
+                in_frame(env, cid, || {
+                    let answer = String::from_str(env, "answer");
+                    let player = players[0].clone();
+                    HuntyCore::submit_answer(env.clone(), hid, clue_id, player, answer).unwrap()
+                });
+            }
+            let too_big = HuntyCore::set_leaderboard_cache_size(env.clone(), hid, 51);
+            (
+                HuntyCore::get_leaderboard(env.clone(), hid, 3),
+                HuntyCore::compute_leaderboard(env, hid),
+                Storage::get_leaderboard_cache(env, hid),
+                too_big,
+            )
+        });
+
+        assert_eq!(stored.len(), 3);
+        assert_eq!(cached, stored);
+        for (index, entry) in cached.iter().enumerate() {
+            assert_eq!(entry, fresh.get(index as u32).unwrap());
+        }
+        assert_eq!(cached.get(0).unwrap().player, players[1]);
+        assert_eq!(cached.get(1).unwrap().player, players[0]);
+        assert_eq!(fresh.len(), 4);
+        assert_eq!(too_big, Err(HuntErrorCode::InvalidAmount));
+    }
+
+    #[test]
+    fn test_recompute_leaderboard_cache_repairs_drift() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let players = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+
+        let (after_reset, drifted, rebuilt, count, fresh, missing) =
+            with_core_contract(&env, |env, cid| {
+                let hid = create_active_hunt(env, &creator, 3);
+                HuntyCore::set_leaderboard_cache_size(env.clone(), hid, 2).unwrap();
+                solve_clues(env, cid, hid, &players, &[3, 2, 1]);
+                // Resetting the leader drops them out of the cached top two.
+                HuntyCore::reset_player_progress(env.clone(), hid, players[0].clone()).unwrap();
+                let after_reset = HuntyCore::get_leaderboard(env.clone(), hid, 2);
+
+                Storage::set_leaderboard_cache(env, hid, &Vec::new(env));
+                let drifted = HuntyCore::get_leaderboard(env.clone(), hid, 2);
+                let count = HuntyCore::recompute_leaderboard_cache(env.clone(), hid).unwrap();
+                let rebuilt = HuntyCore::get_leaderboard(env.clone(), hid, 2);
+                let ranked = HuntyCore::compute_leaderboard(env, hid);
+                let fresh = HuntyCore::paginate(env, ranked, 0, 2);
+                let missing = HuntyCore::recompute_leaderboard_cache(env.clone(), 999);
+                (after_reset, drifted, rebuilt, count, fresh, missing)
+            });
+
+        assert_eq!(after_reset.len(), 2);
+        assert_eq!(after_reset.get(0).unwrap().player, players[1]);
+        assert_eq!(after_reset.get(1).unwrap().player, players[2]);
+        assert!(drifted.is_empty());
+        assert_eq!(count, 2);
+        assert_eq!(rebuilt, fresh);
+        assert_eq!(rebuilt, after_reset);
+        assert_eq!(missing, Err(HuntErrorCode::HuntNotFound));
+    }
+
+    // ========== AnswerRejected Event Tests ==========
+
+    #[test]
+    fn test_wrong_answer_publishes_answer_rejected_event() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (wrong, right, events) = with_core_contract(&env, |env, cid| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_attempt_limit(env.clone(), hid, 3, 60).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap();
+            let submit = |answer: &str| {
+                in_frame(env, cid, || {
+                    let answer = String::from_str(env, answer);
+                    HuntyCore::submit_answer(env.clone(), hid, 1, player.clone(), answer)
+                })
+            };
+            (submit("nope"), submit("answer"), get_all_events_for_hunt(env, hid))
+        });
+
+        assert_eq!((wrong, right), (Ok(0), Ok(10)));
+        let rejected: std::vec::Vec<AnswerRejectedEvent> = events
+            .iter()
+            .filter_map(|event| match event {
+                HuntEvent::AnswerRejected(e) => Some(e.clone()),
+                _ => None,
+            })
+            .collect();
+        // Only the wrong answer is reported; the correct one completes the clue.
+        assert_eq!(rejected.len(), 1);
+        assert_eq!((rejected[0].clue_id, rejected[0].player.clone()), (1, player));
+        assert!(events.iter().any(|e| matches!(e, HuntEvent::ClueCompleted(_))));
+    }
+
+    #[test]
+    fn test_answer_rejected_event_survives_client_call_only_with_attempt_limit() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (cid, limited, unlimited) = with_core_contract(&env, |env, cid| {
+            let limited = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_attempt_limit(env.clone(), limited, 3, 60).unwrap();
+            HuntyCore::activate_hunt(env.clone(), limited).unwrap();
+            let unlimited = in_frame(env, cid, || create_active_hunt(env, &creator, 1));
+            for hid in [limited, unlimited] {
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+                });
+            }
+            (cid.clone(), limited, unlimited)
+        });
+        // Through the client, so a failed call rolls its events back.
+        let client = crate::HuntyCoreClient::new(&env, &cid);
+        let nope = String::from_str(&env, "nope");
+        let rejections = |hid| {
+            get_all_events_for_hunt(&env, hid)
+                .into_iter()
+                .filter(|event| matches!(event, HuntEvent::AnswerRejected(_)))
+                .count()
+        };
+
+        assert_eq!(client.try_submit_answer(&limited, &1, &player, &nope), Ok(Ok(0)));
+        assert_eq!(rejections(limited), 1);
+        assert_eq!(
+            client.try_submit_answer(&unlimited, &1, &player, &nope),
+            Err(Ok(HuntErrorCode::InvalidAnswer))
+        );
+        assert_eq!(rejections(unlimited), 0);
+    }
+
+    #[test]
+    fn test_answer_rejected_event_skipped_at_minimal_level() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (wrong, events) = with_core_contract(&env, |env, cid| {
+            HuntyCore::initialize(env.clone(), admin.clone()).unwrap();
+            HuntyCore::set_event_level(env.clone(), EventLevel::Minimal).unwrap();
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_attempt_limit(env.clone(), hid, 3, 60).unwrap();
+            HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+            HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap();
+            let wrong = in_frame(env, cid, || {
+                let answer = String::from_str(env, "nope");
+                HuntyCore::submit_answer(env.clone(), hid, 1, player.clone(), answer)
+            });
+            (wrong, get_all_events_for_hunt(env, hid))
+        });
+
+        assert_eq!(wrong, Ok(0));
+        assert!(!events.iter().any(|e| matches!(e, HuntEvent::AnswerRejected(_))));
+    }
+
+    // ========== min_players_for_rewards Tests ==========
+
+    /// Creates a one-clue hunt paying its whole `pool` to a single winner once at least
+    /// `min_players` have registered, and activates it.
+    fn create_min_players_hunt(
+        env: &Env,
+        creator: &Address,
+        token: &Address,
+        pool: i128,
+        min_players: u32,
+    ) -> u64 {
+        let hid = create_hunt_with_clues(env, creator, 1, true);
+        HuntyCore::set_max_winners(env.clone(), hid, 1).unwrap();
+        HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), pool).unwrap();
+        HuntyCore::set_min_players_for_rewards(env.clone(), hid, min_players).unwrap();
+        HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+        hid
+    }
+
+    #[test]
+    fn test_hunt_meeting_min_players_pays_rewards() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let (first, second) = (Address::generate(&env), Address::generate(&env));
+        let token = create_funded_token(&env, &creator, 300);
+
+        let (early, active_before, active_after, paid, reclaimed, active_closed) =
+            with_core_contract(&env, |env, cid| {
+                let hid = create_min_players_hunt(env, &creator, &token, 300, 2);
+                register_and_complete(env, cid, hid, &first);
+                let active_before = HuntyCore::rewards_active(env.clone(), hid);
+                let claim = || {
+                    in_frame(env, cid, || {
+                        HuntyCore::claim_reward(env.clone(), hid, first.clone(), None)
+                    })
+                };
+                let early = claim();
+                in_frame(env, cid, || {
+                    HuntyCore::register_player(env.clone(), hid, second.clone()).unwrap()
+                });
+                let active_after = HuntyCore::rewards_active(env.clone(), hid);
+                let paid = claim().unwrap();
+                let reclaimed = HuntyCore::close_hunt(env.clone(), hid).unwrap();
+                let active_closed = HuntyCore::rewards_active(env.clone(), hid);
+                (early, active_before, active_after, paid, reclaimed, active_closed)
+            });
+
+        assert_eq!(early, Err(HuntErrorCode::TooFewPlayers));
+        assert!(!active_before);
+        assert!(active_after);
+        assert_eq!(paid, 300);
+        assert_eq!(reclaimed, 0);
+        assert!(active_closed);
+        assert_eq!(TokenClient::new(&env, &token).balance(&first), 300);
+    }
+
+    #[test]
+    fn test_hunt_missing_min_players_refunds_creator() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let (first, second) = (Address::generate(&env), Address::generate(&env));
+        let token = create_funded_token(&env, &creator, 300);
+
+        let (reclaimed, active, claim, config) = with_core_contract(&env, |env, cid| {
+            let hid = create_min_players_hunt(env, &creator, &token, 300, 3);
+            register_and_complete(env, cid, hid, &first);
+            register_and_complete(env, cid, hid, &second);
+            let reclaimed = HuntyCore::close_hunt(env.clone(), hid).unwrap();
+            let claim = in_frame(env, cid, || {
+                HuntyCore::claim_reward(env.clone(), hid, first.clone(), None)
+            });
+            let config = HuntyCore::get_hunt_config(env.clone(), hid).unwrap();
+            (reclaimed, HuntyCore::rewards_active(env.clone(), hid), claim, config)
+        });
+
+        // The first finisher's reserved slot is not honoured: the whole pool comes back.
+        assert_eq!(reclaimed, 300);
+        assert_eq!(TokenClient::new(&env, &token).balance(&creator), 300);
+        assert!(!active);
+        assert_eq!(claim, Err(HuntErrorCode::TooFewPlayers));
+        assert_eq!(config.min_players_for_rewards, 3);
+    }
+
+    #[test]
+    fn test_min_players_cannot_exceed_max_players() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let creator = Address::generate(&env);
+
+        let (min_over_cap, cap_under_min, hunt) = with_core_contract(&env, |env, _| {
+            let hid = create_hunt_with_clues(env, &creator, 1, true);
+            HuntyCore::set_max_players(env.clone(), hid, 3).unwrap();
+            let min_over_cap = HuntyCore::set_min_players_for_rewards(env.clone(), hid, 4);
+            HuntyCore::set_min_players_for_rewards(env.clone(), hid, 3).unwrap();
+            let cap_under_min = HuntyCore::set_max_players(env.clone(), hid, 2);
+            // Lifting the cap altogether is always allowed.
+            HuntyCore::set_max_players(env.clone(), hid, 0).unwrap();
+            (min_over_cap, cap_under_min, Storage::get_hunt(env, hid).unwrap())
+        });
+
+        assert_eq!(min_over_cap, Err(HuntErrorCode::InvalidAmount));
+        assert_eq!(cap_under_min, Err(HuntErrorCode::InvalidAmount));
+        assert_eq!((hunt.max_players, hunt.min_players_for_rewards), (0, 3));
+    }
+
+    // ========== get_clue_funnel() Tests ==========
+
+    #[test]
+    fn test_clue_funnel_counts_solves_per_clue() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let players = [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+
+        let (funnel, page) = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 4);
+            // Each player stops after a different prefix of the clues.
+            solve_clues(env, cid, hid, &players, &[4, 2, 1, 2]);
+            (
+                HuntyCore::get_clue_funnel(env.clone(), hid, 0, 10),
+                HuntyCore::get_clue_funnel(env.clone(), hid, 1, 2),
+            )
+        });
+
+        assert_eq!(funnel, Vec::from_array(&env, [(1, 4), (2, 3), (3, 1), (4, 1)]));
+        assert_eq!(page, Vec::from_array(&env, [(2, 3), (3, 1)]));
+    }
+
+    // ========== Reward Vesting Tests ==========
+
+    /// Creates a one-clue hunt paying `pool` to a single winner, vesting over 1000s from
+    /// timestamp 1_000_100, and activates it.
+    fn create_vesting_hunt(env: &Env, creator: &Address, token: &Address, pool: i128) -> u64 {
+        let hid = create_hunt_with_clues(env, creator, 1, true);
+        HuntyCore::set_max_winners(env.clone(), hid, 1).unwrap();
+        HuntyCore::fund_reward_pool(env.clone(), hid, token.clone(), pool).unwrap();
+        HuntyCore::set_reward_vesting(env.clone(), hid, 1_000_100, 1000).unwrap();
+        HuntyCore::activate_hunt(env.clone(), hid).unwrap();
+        hid
+    }
+
+    #[test]
+    fn test_vesting_reward_claimed_partially_then_fully() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+        let token = create_funded_token(&env, &creator, 1000);
+
+        let (claims, record) = with_core_contract(&env, |env, cid| {
+            let hid = create_vesting_hunt(env, &creator, &token, 1000);
+            register_and_complete(env, cid, hid, &player);
+            let mut claims = std::vec::Vec::new();
+            for now in [1_000_000, 1_000_350, 1_000_350, 1_000_600, 1_001_100, 1_001_200] {
+                env.ledger().set_timestamp(now);
+                let vested = HuntyCore::get_vested_amount(env.clone(), hid, player.clone());
+                let claim = in_frame(env, cid, || {
+                    HuntyCore::claim_reward(env.clone(), hid, player.clone(), None)
+                });
+                claims.push((vested, claim));
+            }
+            (claims, HuntyCore::get_distribution_record(env.clone(), hid, 0, 10))
+        });
+
+        assert_eq!(
+            claims,
+            std::vec![
+                (0, Err(HuntErrorCode::NothingVested)),
+                (250, Ok(250)),
+                (250, Err(HuntErrorCode::NothingVested)),
+                (500, Ok(250)),
+                (1000, Ok(500)),
+                (1000, Err(HuntErrorCode::RewardAlreadyClaimed)),
+            ]
+        );
+        assert_eq!(TokenClient::new(&env, &token).balance(&player), 1000);
+        // Each installment is its own row in the payout record.
+        let amounts: std::vec::Vec<i128> = record.iter().map(|(_, amount)| amount).collect();
+        assert_eq!(amounts, std::vec![250, 250, 500]);
+    }
+
+    #[test]
+    fn test_close_hunt_keeps_unvested_share_for_winner() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_000_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let (winner, cheat) = (Address::generate(&env), Address::generate(&env));
+        let token = create_funded_token(&env, &creator, 2000);
+
+        let (kept, dropped) = with_core_contract(&env, |env, cid| {
+            let claim = |hid: u64, player: &Address| {
+                in_frame(env, cid, || {
+                    HuntyCore::claim_reward(env.clone(), hid, player.clone(), None).unwrap()
+                })
+            };
+            let kept_hid = in_frame(env, cid, || create_vesting_hunt(env, &creator, &token, 1000));
+            let dropped_hid =
+                in_frame(env, cid, || create_vesting_hunt(env, &creator, &token, 1000));
+            register_and_complete(env, cid, kept_hid, &winner);
+            register_and_complete(env, cid, dropped_hid, &cheat);
+            env.ledger().set_timestamp(1_000_350);
+            claim(kept_hid, &winner);
+            claim(dropped_hid, &cheat);
+
+            // The winner's unvested 750 stays in escrow for them.
+            let kept = in_frame(env, cid, || HuntyCore::close_hunt(env.clone(), kept_hid));
+            env.ledger().set_timestamp(1_001_100);
+            let rest = claim(kept_hid, &winner);
+
+            // A disqualified winner keeps what they took; the remainder is reclaimed.
+            in_frame(env, cid, || {
+                HuntyCore::disqualify_player(env.clone(), dropped_hid, cheat.clone()).unwrap()
+            });
+            let dropped = in_frame(env, cid, || HuntyCore::close_hunt(env.clone(), dropped_hid));
+            ((kept, rest), dropped)
+        });
+
+        assert_eq!(kept, (Ok(0), 750));
+        assert_eq!(dropped, Ok(750));
+        let balance = |who: &Address| TokenClient::new(&env, &token).balance(who);
+        assert_eq!((balance(&winner), balance(&cheat), balance(&creator)), (1000, 250, 750));
+    }
+
+    // ========== abort_activation() Tests ==========
+
+    #[test]
+    fn test_abort_activation_returns_empty_hunt_to_draft() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+
+        let (hunt, commitment, active_hunts, drafts, again, events) =
+            with_core_contract(&env, |env, cid| {
+                let hid = create_active_hunt(env, &creator, 2);
+                assert!(HuntyCore::get_clue_set_commitment(env.clone(), hid).is_some());
+                HuntyCore::abort_activation(env.clone(), hid).unwrap();
+                let again = in_frame(env, cid, || HuntyCore::abort_activation(env.clone(), hid));
+                (
+                    Storage::get_hunt(env, hid).unwrap(),
+                    HuntyCore::get_clue_set_commitment(env.clone(), hid),
+                    HuntyCore::get_global_stats(env.clone()).active_hunts,
+                    HuntyCore::get_hunts_by_status(env.clone(), HuntStatus::Draft, 0, 10),
+                    again,
+                    get_all_events_for_hunt(env, hid),
+                )
+            });
+
+        assert_eq!((hunt.status, hunt.activated_at), (HuntStatus::Draft, 0));
+        assert_eq!(commitment, None);
+        assert_eq!(active_hunts, 0);
+        assert_eq!(drafts, soroban_sdk::vec![&env, hunt.hunt_id]);
+        assert_eq!(again, Err(HuntErrorCode::InvalidHuntStatus));
+        // The abort is reported on its own, not as a status change back to Draft.
+        let aborted = events.iter().find_map(|event| match event {
+            HuntEvent::ActivationAborted(e) => Some(e.activated_at),
+            _ => None,
+        });
+        assert_eq!(aborted, Some(1_000));
+        assert!(!events.iter().any(|event| matches!(
+            event,
+            HuntEvent::HuntStatusChanged(e) if e.new_status == HuntStatus::Draft
+        )));
+    }
+
+    #[test]
+    fn test_abort_activation_rejected_once_player_registers() {
+        let env = Env::default();
+        env.ledger().set_timestamp(1_000);
+        env.mock_all_auths_allowing_non_root_auth();
+        let creator = Address::generate(&env);
+        let player = Address::generate(&env);
+
+        let (result, hunt, commitment) = with_core_contract(&env, |env, cid| {
+            let hid = create_active_hunt(env, &creator, 1);
+            in_frame(env, cid, || {
+                HuntyCore::register_player(env.clone(), hid, player.clone()).unwrap()
+            });
+            let result = in_frame(env, cid, || HuntyCore::abort_activation(env.clone(), hid));
+            (
+                result,
+                Storage::get_hunt(env, hid).unwrap(),
+                HuntyCore::get_clue_set_commitment(env.clone(), hid),
+            )
+        });
+
+        assert_eq!(result, Err(HuntErrorCode::InvalidHuntStatus));
+        assert_eq!((hunt.status, hunt.activated_at), (HuntStatus::Active, 1_000));
+        assert!(commitment.is_some());
+    }
+}
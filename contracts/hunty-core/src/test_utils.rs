@@ -0,0 +1,169 @@
+//! Test-only helpers for asserting on the contract's event log.
+
+extern crate std;
+
+use soroban_sdk::testutils::Events as _;
+use soroban_sdk::xdr::ContractEventType;
+use soroban_sdk::{Env, Symbol, TryFromVal, Val};
+
+use crate::types::{
+    ActivationAbortedEvent, AnswerRejectedEvent, ClueAddedEvent, ClueCompletedEvent,
+    CountersRecomputedEvent, EmergencyWithdrawEvent, ErrorEvent, HuntCancelledEvent,
+    HuntClosedEvent, HuntCompletedEvent, HuntCreatedEvent, IndexesRebuiltEvent,
+    HuntStatusChangedEvent, PlayerDisqualifiedEvent, PlayerRegisteredEvent, PlayerUnregisteredEvent,
+    ProgressResetEvent, RegistrationMilestoneEvent, RegistrationPausedEvent, RewardClaimedEvent,
+    RewardPoolFundedEvent, RewardPoolWithdrawnEvent, RewardTokenSwappedEvent, RewardsExhaustedEvent,
+    RewardsFrozenEvent,
+};
+
+/// A decoded hunt event, named after the event's first topic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HuntEvent {
+    HuntCreated(HuntCreatedEvent),
+    ClueAdded(ClueAddedEvent),
+    AnswerRejected(AnswerRejectedEvent),
+    HuntStatusChanged(HuntStatusChangedEvent),
+    ActivationAborted(ActivationAbortedEvent),
+    HuntCancelled(HuntCancelledEvent),
+    HuntClosed(HuntClosedEvent),
+    PlayerRegistered(PlayerRegisteredEvent),
+    PlayerUnregistered(PlayerUnregisteredEvent),
+    PlayerDisqualified(PlayerDisqualifiedEvent),
+    RegistrationMilestone(RegistrationMilestoneEvent),
+    RegistrationPaused(RegistrationPausedEvent),
+    ProgressReset(ProgressResetEvent),
+    ClueCompleted(ClueCompletedEvent),
+    HuntCompleted(HuntCompletedEvent),
+    RewardClaimed(RewardClaimedEvent),
+    RewardsExhausted(RewardsExhaustedEvent),
+    RewardPoolFunded(RewardPoolFundedEvent),
+    RewardPoolWithdrawn(RewardPoolWithdrawnEvent),
+    RewardTokenSwapped(RewardTokenSwappedEvent),
+    RewardsFrozen(RewardsFrozenEvent),
+    EmergencyWithdraw(EmergencyWithdrawEvent),
+    CountersRecomputed(CountersRecomputedEvent),
+    IndexesRebuilt(IndexesRebuiltEvent),
+    Error(ErrorEvent),
+}
+
+impl HuntEvent {
+    /// The hunt the event belongs to. Error events carry it as their context.
+    pub fn hunt_id(&self) -> u64 {
+        match self {
+            HuntEvent::HuntCreated(e) => e.hunt_id,
+            HuntEvent::ClueAdded(e) => e.hunt_id,
+            HuntEvent::AnswerRejected(e) => e.hunt_id,
+            HuntEvent::HuntStatusChanged(e) => e.hunt_id,
+            HuntEvent::ActivationAborted(e) => e.hunt_id,
+            HuntEvent::HuntCancelled(e) => e.hunt_id,
+            HuntEvent::HuntClosed(e) => e.hunt_id,
+            HuntEvent::PlayerRegistered(e) => e.hunt_id,
+            HuntEvent::PlayerUnregistered(e) => e.hunt_id,
+            HuntEvent::PlayerDisqualified(e) => e.hunt_id,
+            HuntEvent::RegistrationMilestone(e) => e.hunt_id,
+            HuntEvent::RegistrationPaused(e) => e.hunt_id,
+            HuntEvent::ProgressReset(e) => e.hunt_id,
+            HuntEvent::ClueCompleted(e) => e.hunt_id,
+            HuntEvent::HuntCompleted(e) => e.hunt_id,
+            HuntEvent::RewardClaimed(e) => e.hunt_id,
+            HuntEvent::RewardsExhausted(e) => e.hunt_id,
+            HuntEvent::RewardPoolFunded(e) => e.hunt_id,
+            HuntEvent::RewardPoolWithdrawn(e) => e.hunt_id,
+            HuntEvent::RewardTokenSwapped(e) => e.hunt_id,
+            HuntEvent::RewardsFrozen(e) => e.hunt_id,
+            HuntEvent::EmergencyWithdraw(e) => e.hunt_id,
+            HuntEvent::CountersRecomputed(e) => e.hunt_id,
+            HuntEvent::IndexesRebuilt(e) => e.hunt_id,
+            HuntEvent::Error(e) => e.context,
+        }
+    }
+
+    fn decode(env: &Env, name: &Symbol, data: &Val) -> Option<Self> {
+        let is = |event_name: &str| *name == Symbol::new(env, event_name);
+        let event = if is("HuntCreated") {
+            HuntEvent::HuntCreated(from_val(env, data)?)
+        } else if is("ClueAdded") {
+            HuntEvent::ClueAdded(from_val(env, data)?)
+        } else if is("AnswerRejected") {
+            HuntEvent::AnswerRejected(from_val(env, data)?)
+        } else if is("HuntStatusChanged") {
+            HuntEvent::HuntStatusChanged(from_val(env, data)?)
+        } else if is("ActivationAborted") {
+            HuntEvent::ActivationAborted(from_val(env, data)?)
+        } else if is("HuntCancelled") {
+            HuntEvent::HuntCancelled(from_val(env, data)?)
+        } else if is("HuntClosed") {
+            HuntEvent::HuntClosed(from_val(env, data)?)
+        } else if is("PlayerRegistered") {
+            HuntEvent::PlayerRegistered(from_val(env, data)?)
+        } else if is("PlayerUnregistered") {
+            HuntEvent::PlayerUnregistered(from_val(env, data)?)
+        } else if is("PlayerDisqualified") {
+            HuntEvent::PlayerDisqualified(from_val(env, data)?)
+        } else if is("RegistrationMilestone") {
+            HuntEvent::RegistrationMilestone(from_val(env, data)?)
+        } else if is("RegistrationPaused") {
+            HuntEvent::RegistrationPaused(from_val(env, data)?)
+        } else if is("ProgressReset") {
+            HuntEvent::ProgressReset(from_val(env, data)?)
+        } else if is("ClueCompleted") {
+            HuntEvent::ClueCompleted(from_val(env, data)?)
+        } else if is("HuntCompleted") {
+            HuntEvent::HuntCompleted(from_val(env, data)?)
+        } else if is("RewardClaimed") {
+            HuntEvent::RewardClaimed(from_val(env, data)?)
+        } else if is("RewardsExhausted") {
+            HuntEvent::RewardsExhausted(from_val(env, data)?)
+        } else if is("RewardPoolFunded") {
+            HuntEvent::RewardPoolFunded(from_val(env, data)?)
+        } else if is("RewardPoolWithdrawn") {
+            HuntEvent::RewardPoolWithdrawn(from_val(env, data)?)
+        } else if is("RewardTokenSwapped") {
+            HuntEvent::RewardTokenSwapped(from_val(env, data)?)
+        } else if is("RewardsFrozen") {
+            HuntEvent::RewardsFrozen(from_val(env, data)?)
+        } else if is("EmergencyWithdraw") {
+            HuntEvent::EmergencyWithdraw(from_val(env, data)?)
+        } else if is("CountersRecomputed") {
+            HuntEvent::CountersRecomputed(from_val(env, data)?)
+        } else if is("IndexesRebuilt") {
+            HuntEvent::IndexesRebuilt(from_val(env, data)?)
+        } else if is("Error") {
+            HuntEvent::Error(from_val(env, data)?)
+        } else {
+            return None;
+        };
+        Some(event)
+    }
+}
+
+/// Returns every hunt event published for `hunt_id`, decoded and in publish order.
+/// Events without a hunt (such as `Paused`) and token contract events are skipped, as are
+/// events of invocations that failed, which the ledger would roll back.
+pub fn get_all_events_for_hunt(env: &Env, hunt_id: u64) -> std::vec::Vec<HuntEvent> {
+    // `all()` lists the host's contract events in order but drops their rollback flag.
+    let failed: std::vec::Vec<bool> = env
+        .host()
+        .get_events()
+        .unwrap()
+        .0
+        .into_iter()
+        .filter(|e| e.event.type_ == ContractEventType::Contract && e.event.contract_id.is_some())
+        .map(|e| e.failed_call)
+        .collect();
+    env.events()
+        .all()
+        .iter()
+        .zip(failed)
+        .filter(|(_, failed_call)| !failed_call)
+        .filter_map(|((_, topics, data), _)| {
+            let name = Symbol::try_from_val(env, &topics.get(0)?).ok()?;
+            let event = HuntEvent::decode(env, &name, &data)?;
+            (event.hunt_id() == hunt_id).then_some(event)
+        })
+        .collect()
+}
+
+fn from_val<T: TryFromVal<Env, Val>>(env: &Env, data: &Val) -> Option<T> {
+    T::try_from_val(env, data).ok()
+}
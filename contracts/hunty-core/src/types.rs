@@ -9,6 +9,71 @@ pub enum HuntStatus {
     Cancelled,
 }
 
+/// How a player's progress is judged complete. Exactly one model applies per hunt.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompletionPolicy {
+    /// Complete once every `is_required` clue is solved.
+    RequiredCount,
+    /// Complete once `completed * 100 / total_clues >= percent` (1..=100).
+    RequiredPercent(u32),
+    /// Complete once every clue is solved, required or not.
+    AllClues,
+    /// Players only collect points; nobody ever completes. Opts out of the
+    /// required-clue check at activation.
+    Participation,
+}
+
+/// Where a hunt keeps player progress. Temporary entries cost less rent but expire
+/// a little after the hunt's `end_time`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Durability {
+    Persistent,
+    Temporary,
+}
+
+/// Hash function applied to normalized answers, so front-ends can match their own hashing.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HashAlgo {
+    Sha256,
+    Keccak256,
+}
+
+/// How many events the contract publishes. `Minimal` keeps hunt-level events (lifecycle,
+/// funding, admin actions) and drops per-player and per-clue ones; `Full` publishes all.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventLevel {
+    None,
+    Minimal,
+    Full,
+}
+
+/// How winner slots are handed out in a hunt with limited winners.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RewardMode {
+    /// Slots go to the first `max_winners` players to complete.
+    FirstCome,
+    /// After `end_time`, `draw_winners` picks `max_winners` completers at random.
+    Raffle,
+}
+
+/// Who gets the last winner slot when several players complete at the same ledger
+/// timestamp as the player who took it. Only applies to `RewardMode::FirstCome`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TiePolicy {
+    /// The player who completed first within the ledger keeps the slot; the rest miss out.
+    FirstCome,
+    /// Everyone tied at the cutoff shares the last slot's reward equally.
+    SplitEqually,
+    /// Tied players wait for the admin to fund extra slots with `approve_tie_slots`.
+    ExpandSlots,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RewardConfig {
@@ -17,6 +82,21 @@ pub struct RewardConfig {
     pub nft_contract: Option<Address>,
     pub max_winners: u32,
     pub claimed_count: u32,
+    pub reward_token: Option<Address>,
+    /// Winner slots handed out to players at completion time (at most `max_winners`).
+    pub reserved_winners: u32,
+    /// Decimals reported by `reward_token`, recorded when the pool is first funded.
+    pub reward_decimals: u32,
+    /// Completion time of the player who took the last winner slot (0 while slots remain).
+    pub cutoff_at: u64,
+    /// Players sharing the last slot under `TiePolicy::SplitEqually` (0 if not shared).
+    pub tie_sharers: u32,
+    /// How many of the `tie_sharers` have claimed their share.
+    pub tie_claims: u32,
+    /// Timestamp from which token rewards start to vest.
+    pub vesting_start: u64,
+    /// Seconds over which rewards vest linearly from `vesting_start` (0 = paid at once).
+    pub vesting_duration: u64,
 }
 
 #[contracttype]
@@ -29,10 +109,51 @@ pub struct Hunt {
     pub status: HuntStatus,
     pub created_at: u64,
     pub activated_at: u64,
+    /// Play opens at this timestamp (0 = as soon as the hunt is Active).
+    pub start_time: u64,
     pub end_time: u64,
+    /// Seconds after `end_time` during which answers are still accepted (0 = none).
+    pub grace_period_secs: u64,
     pub reward_config: RewardConfig,
     pub total_clues: u32,
     pub required_clues: u32,
+    pub completion_policy: CompletionPolicy,
+    /// When true, clues must be solved in clue-index order.
+    pub ordered: bool,
+    /// Paid in the reward token on registration and added to the pool (0 = free).
+    pub entry_fee: i128,
+    /// Maximum number of registered players (0 = unlimited).
+    pub max_players: u32,
+    /// Storage used for this hunt's player progress entries.
+    pub progress_durability: Durability,
+    pub reward_mode: RewardMode,
+    /// When true, the creator can award custom points per clue with `award_points`.
+    pub manual_judging: bool,
+    /// Why the creator cancelled the hunt (empty if no reason was given or not cancelled).
+    pub cancel_reason: String,
+    /// When false, the creator and collaborators, who know the answers, cannot register.
+    pub allow_creator_play: bool,
+    /// When true, each player sees the clues in their own order (see `get_player_clue_order`).
+    pub shuffle_clues: bool,
+    /// When the creator closed the hunt with `close_hunt` (0 if not closed).
+    pub closed_at: u64,
+    /// Hash function for this hunt's answers; fixed once the first clue is added.
+    pub answer_algo: HashAlgo,
+    /// Wrong answers a player may give per clue before a cooldown (0 = unlimited).
+    pub max_attempts: u32,
+    /// Length of the first cooldown; it doubles with each further lockout.
+    pub attempt_cooldown_secs: u64,
+    /// Shortest normalized answer accepted when adding answers (0 = no minimum).
+    pub min_answer_length: u32,
+    /// Hunt players must have completed before they can register for this one.
+    pub prerequisite_hunt: Option<u64>,
+    pub tie_policy: TiePolicy,
+    /// Most base points a clue may award (0 = no cap).
+    pub max_points_per_clue: u32,
+    /// Entries kept in the cached leaderboard `get_leaderboard` reads (0 = no cache).
+    pub leaderboard_cache_size: u32,
+    /// Players the hunt needs before any reward is paid (0 = no minimum).
+    pub min_players_for_rewards: u32,
 }
 
 /// Stored clue with SHA256 answer hash. The hash is never exposed via get_clue/list_clues or events.
@@ -44,6 +165,68 @@ pub struct Clue {
     pub answer_hash: BytesN<32>,
     pub points: u32,
     pub is_required: bool,
+    /// Seconds after the player's start within which a solve earns `time_bonus` (0 = none).
+    pub time_limit_secs: u32,
+    pub time_bonus: u32,
+    /// Extra points for the first player to solve this clue (0 = none).
+    pub first_solve_bonus: u32,
+    /// Hashes of alternative answers (synonyms, spellings) also accepted for this clue.
+    pub alt_answer_hashes: Vec<BytesN<32>>,
+    /// Whether `location` holds a map pin for this clue.
+    pub has_location: bool,
+    pub location: Location,
+}
+
+impl Clue {
+    /// Returns true if `answer_hash` matches the main answer or any alternative.
+    pub fn accepts(&self, answer_hash: &BytesN<32>) -> bool {
+        self.answer_hash == *answer_hash || self.alt_answer_hashes.contains(answer_hash)
+    }
+
+    /// Public view of the clue, without the answer hash.
+    pub fn to_info(&self) -> ClueInfo {
+        ClueInfo {
+            clue_id: self.clue_id,
+            question: self.question.clone(),
+            points: self.points,
+            is_required: self.is_required,
+            time_limit_secs: self.time_limit_secs,
+            time_bonus: self.time_bonus,
+            first_solve_bonus: self.first_solve_bonus,
+        }
+    }
+}
+
+/// One clue passed to `create_hunt_with_clues`; same fields as `add_clue`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClueInput {
+    pub question: String,
+    pub answer: String,
+    pub points: u32,
+    pub is_required: bool,
+}
+
+/// Points to a hunt description kept off-chain (e.g. on IPFS), for descriptions longer
+/// than the inline limit. Clients fetch the content and check it against `content_hash`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescriptionRef {
+    /// sha256 of the full off-chain description.
+    pub content_hash: BytesN<32>,
+    /// Short on-chain summary shown before the full description is fetched.
+    pub summary: String,
+}
+
+/// A clue saved in a template by `save_template`. Only the answer hash is kept, never the
+/// plaintext answer.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemplateClue {
+    pub question: String,
+    pub answer_hash: BytesN<32>,
+    pub points: u32,
+    pub is_required: bool,
 }
 
 /// Clue info returned by get_clue/list_clues. Excludes answer hash.
@@ -54,26 +237,27 @@ pub struct ClueInfo {
     pub question: String,
     pub points: u32,
     pub is_required: bool,
+    pub time_limit_secs: u32,
+    pub time_bonus: u32,
+    pub first_solve_bonus: u32,
 }
 
+/// A clue as a given player sees it, returned by `get_hunt_view`.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClueWithStatus {
+    pub clue: ClueInfo,
+    pub solved: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Location {
     pub latitude: i64,  // Degrees * 1_000_000
     pub longitude: i64, // Degrees * 1_000_000
     pub radius: u32,
 }
 
-impl Default for Location {
-    fn default() -> Self {
-        Self {
-            latitude: 0,
-            longitude: 0,
-            radius: 0,
-        }
-    }
-}
-
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct PlayerProgress {
@@ -85,6 +269,98 @@ pub struct PlayerProgress {
     pub completed_at: u64,
     pub is_completed: bool,
     pub reward_claimed: bool,
+    /// True when the player reserved a winner slot on completion. A completed player
+    /// without a slot finished after every slot was taken and is not rewarded.
+    pub winner_slot: bool,
+    /// True when `winner_slot` is the last slot, split with players tied at the cutoff.
+    pub shared_slot: bool,
+    /// Set by the creator to block a cheating player from solving or claiming.
+    pub disqualified: bool,
+    /// Time of the player's latest correct answer, or of registration before any.
+    pub last_activity_at: u64,
+    /// `(clue_id, points_earned)` for each solve, in solve order.
+    pub score_breakdown: Vec<(u32, u32)>,
+}
+
+// Earlier `PlayerProgress` layouts, still found in storage written by older contract
+// versions. `Storage::get_player_progress` upgrades them on read; none is exported in the
+// contract spec.
+
+/// The original layout, before winner slots and disqualification were added.
+#[contracttype(export = false)]
+#[derive(Clone, Debug)]
+pub struct PlayerProgressV0 {
+    pub player: Address,
+    pub hunt_id: u64,
+    pub completed_clues: Vec<u32>,
+    pub total_score: u32,
+    pub started_at: u64,
+    pub completed_at: u64,
+    pub is_completed: bool,
+    pub reward_claimed: bool,
+}
+
+/// Layout before `last_activity_at` was added.
+#[contracttype(export = false)]
+#[derive(Clone, Debug)]
+pub struct PlayerProgressV1 {
+    pub player: Address,
+    pub hunt_id: u64,
+    pub completed_clues: Vec<u32>,
+    pub total_score: u32,
+    pub started_at: u64,
+    pub completed_at: u64,
+    pub is_completed: bool,
+    pub reward_claimed: bool,
+    pub winner_slot: bool,
+    pub disqualified: bool,
+}
+
+/// Layout before `score_breakdown` was added.
+#[contracttype(export = false)]
+#[derive(Clone, Debug)]
+pub struct PlayerProgressV2 {
+    pub player: Address,
+    pub hunt_id: u64,
+    pub completed_clues: Vec<u32>,
+    pub total_score: u32,
+    pub started_at: u64,
+    pub completed_at: u64,
+    pub is_completed: bool,
+    pub reward_claimed: bool,
+    pub winner_slot: bool,
+    pub disqualified: bool,
+    pub last_activity_at: u64,
+}
+
+/// Layout before `shared_slot` was added.
+#[contracttype(export = false)]
+#[derive(Clone, Debug)]
+pub struct PlayerProgressV3 {
+    pub player: Address,
+    pub hunt_id: u64,
+    pub completed_clues: Vec<u32>,
+    pub total_score: u32,
+    pub started_at: u64,
+    pub completed_at: u64,
+    pub is_completed: bool,
+    pub reward_claimed: bool,
+    pub winner_slot: bool,
+    pub disqualified: bool,
+    pub last_activity_at: u64,
+    pub score_breakdown: Vec<(u32, u32)>,
+}
+
+/// One row of `get_leaderboard`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub player: Address,
+    pub total_score: u32,
+    /// Sum of the squared points of every solved clue; favours harder clues on a tie.
+    pub difficulty_score: u64,
+    /// 0 if the player has not completed the hunt.
+    pub completed_at: u64,
 }
 
 impl PlayerProgress {
@@ -98,6 +374,11 @@ impl PlayerProgress {
             completed_at: 0,
             is_completed: false,
             reward_claimed: false,
+            winner_slot: false,
+            shared_slot: false,
+            disqualified: false,
+            last_activity_at: current_time,
+            score_breakdown: Vec::new(env),
         }
     }
 
@@ -110,23 +391,147 @@ impl PlayerProgress {
         false
     }
 
+    /// Fills in the fields a `PlayerProgressV3` entry predates: its slot was never shared.
+    pub fn from_v3(old: PlayerProgressV3) -> Self {
+        Self {
+            player: old.player,
+            hunt_id: old.hunt_id,
+            completed_clues: old.completed_clues,
+            total_score: old.total_score,
+            started_at: old.started_at,
+            completed_at: old.completed_at,
+            is_completed: old.is_completed,
+            reward_claimed: old.reward_claimed,
+            winner_slot: old.winner_slot,
+            shared_slot: false,
+            disqualified: old.disqualified,
+            last_activity_at: old.last_activity_at,
+            score_breakdown: old.score_breakdown,
+        }
+    }
+
+    /// Upgrades a `PlayerProgressV2` entry. Per-solve points were not recorded, so the
+    /// score breakdown starts empty.
+    pub fn from_v2(env: &Env, old: PlayerProgressV2) -> Self {
+        Self::from_v3(PlayerProgressV3 {
+            player: old.player,
+            hunt_id: old.hunt_id,
+            completed_clues: old.completed_clues,
+            total_score: old.total_score,
+            started_at: old.started_at,
+            completed_at: old.completed_at,
+            is_completed: old.is_completed,
+            reward_claimed: old.reward_claimed,
+            winner_slot: old.winner_slot,
+            disqualified: old.disqualified,
+            last_activity_at: old.last_activity_at,
+            score_breakdown: Vec::new(env),
+        })
+    }
+
+    /// Upgrades a `PlayerProgressV1` entry. Its latest activity is taken to be completion,
+    /// or registration for players who never completed.
+    pub fn from_v1(env: &Env, old: PlayerProgressV1) -> Self {
+        let last_activity_at = old.completed_at.max(old.started_at);
+        Self::from_v2(
+            env,
+            PlayerProgressV2 {
+                player: old.player,
+                hunt_id: old.hunt_id,
+                completed_clues: old.completed_clues,
+                total_score: old.total_score,
+                started_at: old.started_at,
+                completed_at: old.completed_at,
+                is_completed: old.is_completed,
+                reward_claimed: old.reward_claimed,
+                winner_slot: old.winner_slot,
+                disqualified: old.disqualified,
+                last_activity_at,
+            },
+        )
+    }
+
+    /// Upgrades a `PlayerProgressV0` entry. That layout rewarded any player who completed
+    /// while the pool lasted, so completion counts as holding a winner slot.
+    pub fn from_v0(env: &Env, old: PlayerProgressV0) -> Self {
+        Self::from_v1(
+            env,
+            PlayerProgressV1 {
+                player: old.player,
+                hunt_id: old.hunt_id,
+                completed_clues: old.completed_clues,
+                total_score: old.total_score,
+                started_at: old.started_at,
+                completed_at: old.completed_at,
+                is_completed: old.is_completed,
+                reward_claimed: old.reward_claimed,
+                winner_slot: old.is_completed,
+                disqualified: false,
+            },
+        )
+    }
+
     pub fn complete_clue(&mut self, _env: &Env, clue_id: u32, points: u32) {
         if !self.has_completed_clue(clue_id) {
             self.completed_clues.push_back(clue_id);
-            self.total_score += points;
+            self.total_score = self.total_score.saturating_add(points);
+            self.score_breakdown.push_back((clue_id, points));
         }
     }
 }
 
 impl Hunt {
     pub fn is_active(&self, current_time: u64) -> bool {
-        self.status == HuntStatus::Active 
+        self.status == HuntStatus::Active
+            && self.has_started(current_time)
             && (self.end_time == 0 || current_time < self.end_time)
     }
 
+    /// When answers stop being accepted: `end_time` plus the grace period, or 0 if the
+    /// hunt has no end time.
+    pub fn closes_at(&self) -> u64 {
+        if self.end_time == 0 {
+            return 0;
+        }
+        self.end_time.saturating_add(self.grace_period_secs)
+    }
+
+    /// Like `is_active`, but open until `closes_at` so late answers still land.
+    pub fn accepts_answers(&self, current_time: u64) -> bool {
+        self.status == HuntStatus::Active
+            && self.has_started(current_time)
+            && (self.end_time == 0 || current_time < self.closes_at())
+    }
+
+    /// Returns true once `current_time` has reached the hunt's start time.
+    pub fn has_started(&self, current_time: u64) -> bool {
+        current_time >= self.start_time
+    }
+
     pub fn has_rewards_available(&self) -> bool {
         self.reward_config.claimed_count < self.reward_config.max_winners
     }
+
+    /// Returns true while completing players can still reserve a winner slot.
+    pub fn has_unreserved_winner_slots(&self) -> bool {
+        self.reward_config.reserved_winners < self.reward_config.max_winners
+    }
+
+    /// Returns true when a player who solved `completed` clues, `required_completed` of
+    /// them required, satisfies this hunt's completion policy.
+    pub fn is_completion_met(&self, completed: u32, required_completed: u32) -> bool {
+        match self.completion_policy {
+            CompletionPolicy::RequiredCount => {
+                self.required_clues > 0 && required_completed >= self.required_clues
+            }
+            CompletionPolicy::RequiredPercent(percent) => {
+                self.total_clues > 0
+                    && (completed as u64) * 100 / (self.total_clues as u64) >= percent as u64
+            }
+            CompletionPolicy::AllClues => self.total_clues > 0 && completed >= self.total_clues,
+            CompletionPolicy::Participation => false,
+        }
+    }
 }
 
 impl RewardConfig {
@@ -142,9 +547,37 @@ impl RewardConfig {
             nft_contract,
             max_winners,
             claimed_count: 0,
+            reward_token: None,
+            reserved_winners: 0,
+            reward_decimals: 0,
+            cutoff_at: 0,
+            tie_sharers: 0,
+            tie_claims: 0,
+            vesting_start: 0,
+            vesting_duration: 0,
         }
     }
 
+    /// Returns whether rewards are fully vested at `now`.
+    pub fn is_fully_vested(&self, now: u64) -> bool {
+        let vested_at = self.vesting_start.saturating_add(self.vesting_duration);
+        self.vesting_duration == 0 || now >= vested_at
+    }
+
+    /// Returns the part of a `total` reward vested at `now`, rounded down.
+    pub fn vested_amount(&self, total: i128, now: u64) -> i128 {
+        if self.is_fully_vested(now) {
+            return total;
+        }
+        if now <= self.vesting_start {
+            return 0;
+        }
+        let elapsed = (now - self.vesting_start) as i128;
+        let duration = self.vesting_duration as i128;
+        // Split so `total * elapsed` cannot overflow for large pools.
+        total / duration * elapsed + total % duration * elapsed / duration
+    }
+
     pub fn reward_per_winner(&self) -> i128 {
         if self.max_winners == 0 {
             0
@@ -152,55 +585,109 @@ impl RewardConfig {
             self.xlm_pool / (self.max_winners as i128)
         }
     }
+
+    /// Returns false when a funded pool would round every winner's share down to zero.
+    pub fn pays_every_winner(&self) -> bool {
+        self.xlm_pool == 0 || self.max_winners == 0 || self.reward_per_winner() > 0
+    }
 }
 
 // Events
+//
+// Every hunt event ends with `event_seq`, the hunt's event counter after the event (see
+// `get_event_seq`), so indexers can spot gaps.
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HuntCreatedEvent {
     pub hunt_id: u64,
     pub creator: Address,
     pub title: String,
+    pub event_seq: u64,
 }
 
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HuntStatusChangedEvent {
     pub hunt_id: u64,
     pub old_status: HuntStatus,
     pub new_status: HuntStatus,
+    pub event_seq: u64,
 }
 
+/// Emitted by `abort_activation` in place of a status change: the hunt is back in Draft
+/// and the activation at `activated_at` should be treated as never having happened.
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActivationAbortedEvent {
+    pub hunt_id: u64,
+    pub activated_at: u64,
+    pub event_seq: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlayerRegisteredEvent {
+    pub hunt_id: u64,
+    pub player: Address,
+    pub event_seq: u64,
+}
+
+/// Emitted when a player withdraws from a hunt before completing it.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlayerUnregisteredEvent {
+    pub hunt_id: u64,
+    pub player: Address,
+    pub event_seq: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ClueCompletedEvent {
     pub hunt_id: u64,
     pub player: Address,
     pub clue_id: u32,
     pub points_earned: u32,
+    pub event_seq: u64,
 }
 
+/// Emitted for each wrong answer to a clue, so creators can see which clues are hardest.
+/// Only hunts with an attempt limit keep it: elsewhere a wrong answer fails the call with
+/// `InvalidAnswer`, and the event is rolled back with it.
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnswerRejectedEvent {
+    pub hunt_id: u64,
+    pub clue_id: u32,
+    pub player: Address,
+    pub event_seq: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HuntCompletedEvent {
     pub hunt_id: u64,
     pub player: Address,
     pub total_score: u32,
     pub completion_time: u64,
+    pub event_seq: u64,
 }
 
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RewardClaimedEvent {
     pub hunt_id: u64,
     pub player: Address,
+    /// Where the reward was sent: the player, or the payout address they chose.
+    pub payout_address: Address,
     pub xlm_amount: i128,
     pub nft_awarded: bool,
+    pub event_seq: u64,
 }
 
 /// Emitted when a clue is added. Does not expose the answer hash.
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ClueAddedEvent {
     pub hunt_id: u64,
     pub clue_id: u32,
@@ -208,4 +695,273 @@ pub struct ClueAddedEvent {
     pub question: String,
     pub points: u32,
     pub is_required: bool,
-}
\ No newline at end of file
+    pub event_seq: u64,
+}
+/// Emitted alongside HuntStatusChanged when a creator cancels a hunt.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HuntCancelledEvent {
+    pub hunt_id: u64,
+    pub reason: String,
+    pub event_seq: u64,
+}
+
+/// Emitted alongside HuntStatusChanged when a creator closes a finished hunt.
+/// `reclaimed` is the escrow returned to the creator.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HuntClosedEvent {
+    pub hunt_id: u64,
+    pub closed_at: u64,
+    pub reclaimed: i128,
+    pub event_seq: u64,
+}
+
+/// Emitted by `register_player` the first time a hunt's player count reaches one of
+/// its registration milestones.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegistrationMilestoneEvent {
+    pub hunt_id: u64,
+    pub count: u32,
+    pub event_seq: u64,
+}
+
+/// Emitted when a creator pauses (`paused` true) or resumes registration for a hunt.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegistrationPausedEvent {
+    pub hunt_id: u64,
+    pub paused: bool,
+    pub event_seq: u64,
+}
+
+/// Emitted when a creator disqualifies a player. `reward_claimed` is true when the
+/// player had already been paid, which cannot be reversed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlayerDisqualifiedEvent {
+    pub hunt_id: u64,
+    pub player: Address,
+    pub reward_claimed: bool,
+    pub event_seq: u64,
+}
+
+/// Emitted when a creator clears a player's progress so they can retry the hunt.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgressResetEvent {
+    pub hunt_id: u64,
+    pub player: Address,
+    pub event_seq: u64,
+}
+
+/// Emitted alongside RewardClaimed when a claim fills the hunt's last winner slot.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RewardsExhaustedEvent {
+    pub hunt_id: u64,
+    pub final_claimer: Address,
+    pub event_seq: u64,
+}
+
+/// Emitted when `recompute_required_clues` rewrites a hunt's clue counters.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CountersRecomputedEvent {
+    pub hunt_id: u64,
+    pub total_clues: u32,
+    pub required_clues: u32,
+    pub event_seq: u64,
+}
+
+/// Emitted when the admin overwrites a hunt's clue and player indexes with `rebuild_indexes`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexesRebuiltEvent {
+    pub hunt_id: u64,
+    pub clue_count: u32,
+    pub player_count: u32,
+    pub event_seq: u64,
+}
+
+/// Emitted when a creator escrows tokens into a hunt's reward pool.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RewardPoolFundedEvent {
+    pub hunt_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub total_pool: i128,
+    pub event_seq: u64,
+}
+
+/// Emitted when a creator pulls escrowed tokens back out of a Draft hunt's reward pool.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RewardPoolWithdrawnEvent {
+    pub hunt_id: u64,
+    pub token: Address,
+    pub amount: i128,
+    pub total_pool: i128,
+    pub event_seq: u64,
+}
+
+/// Emitted when a creator switches a Draft hunt's reward token. `refunded` is the old
+/// pool returned to the creator in `old_token`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RewardTokenSwappedEvent {
+    pub hunt_id: u64,
+    pub old_token: Option<Address>,
+    pub new_token: Address,
+    pub refunded: i128,
+    pub event_seq: u64,
+}
+
+/// Emitted when the admin drains a hunt's escrow while the contract is paused.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmergencyWithdrawEvent {
+    pub hunt_id: u64,
+    pub to: Address,
+    pub amount: i128,
+    pub event_seq: u64,
+}
+
+/// Emitted when the admin freezes (`frozen` true) or unfreezes a hunt's reward claims.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RewardsFrozenEvent {
+    pub hunt_id: u64,
+    pub frozen: bool,
+    pub event_seq: u64,
+}
+
+/// A player's sealed answer for a clue, stored until it is revealed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnswerCommitment {
+    pub commitment: BytesN<32>,
+    pub committed_ledger: u32,
+}
+
+/// A player's wrong-answer count for one clue of a hunt with an attempt limit.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttemptState {
+    /// Wrong answers since the last lockout ended.
+    pub attempts: u32,
+    /// How many times the player has been locked out of this clue.
+    pub lockouts: u32,
+    /// When the current lockout ends (0 if never locked out).
+    pub next_allowed_at: u64,
+}
+
+impl AttemptState {
+    pub fn new() -> Self {
+        AttemptState {
+            attempts: 0,
+            lockouts: 0,
+            next_allowed_at: 0,
+        }
+    }
+
+    /// Returns true while a lockout is in force at `now`.
+    pub fn is_locked(&self, max_attempts: u32, now: u64) -> bool {
+        self.attempts >= max_attempts && now < self.next_allowed_at
+    }
+
+    /// Starts a fresh round of attempts once a lockout has run out. The lockout count is
+    /// kept so the next cooldown is longer.
+    pub fn refresh(&mut self, max_attempts: u32, now: u64) {
+        if self.attempts >= max_attempts && now >= self.next_allowed_at {
+            self.attempts = 0;
+        }
+    }
+
+    /// Counts a wrong answer, locking the player out for `base_cooldown` doubled once per
+    /// earlier lockout when the limit is reached.
+    pub fn record_wrong(&mut self, max_attempts: u32, base_cooldown: u64, now: u64) {
+        self.attempts += 1;
+        if self.attempts >= max_attempts {
+            let factor = 1u64.checked_shl(self.lockouts).unwrap_or(u64::MAX);
+            self.lockouts += 1;
+            self.next_allowed_at = now.saturating_add(base_cooldown.saturating_mul(factor));
+        }
+    }
+}
+
+impl Default for AttemptState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Contract-wide headline numbers, maintained as counters rather than scanned.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GlobalStats {
+    pub total_hunts: u64,
+    pub active_hunts: u64,
+    pub total_players: u64,
+    pub total_rewards_distributed: i128,
+}
+
+/// Totals across every hunt a creator has created.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorStats {
+    pub hunts: u32,
+    pub total_players: u64,
+    pub total_completions: u64,
+    pub total_rewards_distributed: i128,
+}
+
+/// Card-sized view of a hunt for listings, returned by `get_hunt_summaries`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HuntSummary {
+    pub hunt_id: u64,
+    pub title: String,
+    pub status: HuntStatus,
+    pub player_count: u32,
+    pub total_clues: u32,
+    /// The configured pool, `reward_config.xlm_pool`.
+    pub reward_pool: i128,
+}
+
+/// Every on/off and mode setting of a hunt, returned by `get_hunt_config`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HuntConfig {
+    pub ordered: bool,
+    pub completion_policy: CompletionPolicy,
+    pub reward_mode: RewardMode,
+    pub tie_policy: TiePolicy,
+    pub progress_durability: Durability,
+    pub answer_algo: HashAlgo,
+    pub manual_judging: bool,
+    pub allow_creator_play: bool,
+    pub shuffle_clues: bool,
+    pub nft_enabled: bool,
+    /// Set by `pause_registration`.
+    pub registration_paused: bool,
+    /// Set by the admin with `freeze_hunt_rewards`.
+    pub rewards_frozen: bool,
+    /// 0 when clue points are not capped.
+    pub max_points_per_clue: u32,
+    /// 0 when rewards are paid regardless of turnout.
+    pub min_players_for_rewards: u32,
+}
+
+/// Published when a mutating call fails (behind the `error-events` feature). Rolled back
+/// with the failed call, so only seen in simulation and diagnostic traces, never on the
+/// ledger. `code` is the `HuntErrorCode` value; `context` is the hunt ID, or 0 if none
+/// applies.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ErrorEvent {
+    pub code: u32,
+    pub context: u64,
+}
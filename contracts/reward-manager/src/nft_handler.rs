@@ -1,7 +1,6 @@
 // TODO: Implement NFT coordination and distribution
 
-use soroban_sdk::Env;
-
+#[allow(dead_code)]
 pub struct NftHandler;
 
 impl NftHandler {
@@ -1,7 +1,6 @@
 // TODO: Implement XLM token handling and distribution
 
-use soroban_sdk::Env;
-
+#[allow(dead_code)]
 pub struct XlmHandler;
 
 impl XlmHandler {